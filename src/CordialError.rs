@@ -186,6 +186,94 @@ quick_error!
 			display("JPEG could not be perceptually encoded because: '{}'", cause)
 			from()
 		}
+
+		ImageTooLargeForWebP(path: PathBuf, width: u32, height: u32)
+		{
+			description("Image is too large to encode as WebP")
+			display("Image in {:?} is {} x {} pixels but WebP images can not exceed 16,383 x 16,383 pixels", path, width, height)
+		}
+		
+		// libwebp does not provide a reason
+		CouldNotEncodeWebP(path: PathBuf)
+		{
+			description("Could not encode WebP")
+			display("Image in {:?} could not be encoded as WebP", path)
+		}
+
+		CouldNotEncodeAvif(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("Image in {:?} could not be encoded as AVIF because: '{}'", path, reason)
+		}
+		
+		ImageDimensionsInvalidForJpeg(path: PathBuf, width: u32, height: u32)
+		{
+			description("Image dimensions are invalid for JPEG")
+			display("Image in {:?} is {} x {} pixels but JPEG images must be between 1 x 1 and 65,500 x 65,500 pixels", path, width, height)
+		}
+		
+		// mozjpeg does not provide a reason
+		CouldNotEncodeJpegWithMozjpeg(path: PathBuf)
+		{
+			description("Could not encode JPEG with mozjpeg")
+			display("Image in {:?} could not be encoded as JPEG with mozjpeg", path)
+		}
+		
+		CouldNotQuantizePng(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("PNG in {:?} could not be quantized because: '{}'", path, reason)
+		}
+		
+		CouldNotConvertColorProfile(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("Image in {:?} could not be converted between ICC color profiles because: '{}'", path, reason)
+		}
+		
+		CouldNotRasterizeSvg(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("SVG in {:?} could not be rasterized because: '{}'", path, reason)
+		}
+		
+		CouldNotSubsetFont(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("Font in {:?} could not be subset because: '{}'", path, reason)
+		}
+		
+		CouldNotDecodeWebFont(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("Web font in {:?} could not be decoded because: '{}'", path, reason)
+		}
+		
+		CouldNotInstanceFont(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("Variable font in {:?} could not be instanced because: '{}'", path, reason)
+		}
+		
+		CouldNotParseAudio(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("Audio in {:?} could not be parsed because: '{}'", path, reason)
+		}
+		
+		CouldNotParseWebM(path: PathBuf, cause: self::webm::WebMError)
+		{
+			cause(cause)
+			description(cause.description())
+			display("WebM in {:?} could not be parsed because: '{}'", path, cause)
+			context(path: &'a Path, cause: self::webm::WebMError) -> (path.to_path_buf(), cause)
+		}
+		
+		CouldNotReadCsv(path: PathBuf, reason: String)
+		{
+			description(&reason)
+			display("CSV in {:?} could not be read because: '{}'", path, reason)
+		}
 		
 		CouldNotRenderHandlebars(cause: ::handlebars::RenderError)
 		{
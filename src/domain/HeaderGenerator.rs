@@ -18,40 +18,56 @@ impl<'a> HeaderGenerator<'a>
 		self.generateHeaders(false, canBeCompressed, maximumAgeInSeconds, isDownloadable, url)
 	}
 	
+	/// For assets whose response body depends on the `Accept` header, eg raster images negotiated as WebP or AVIF.
+	#[inline(always)]
+	pub(crate) fn generateHeadersForNegotiatedAsset(&mut self, canBeCompressed: bool, maximumAgeInSeconds: u32, isDownloadable: bool, url: &Url) -> Result<Vec<(String, String)>, CordialError>
+	{
+		self.generateHeadersWithContentNegotiation(false, true, canBeCompressed, maximumAgeInSeconds, isDownloadable, url)
+	}
+	
 	#[inline(always)]
 	pub(crate) fn generateHeaders(&mut self, isPjax: bool, canBeCompressed: bool, maximumAgeInSeconds: u32, isDownloadable: bool, url: &Url) -> Result<Vec<(String, String)>, CordialError>
+	{
+		self.generateHeadersWithContentNegotiation(isPjax, false, canBeCompressed, maximumAgeInSeconds, isDownloadable, url)
+	}
+	
+	/// Equivalent to `<link rel="preload" as="font" type="font/woff2" crossorigin>` elements, but seen by browsers before any HTML is parsed.
+	#[inline(always)]
+	pub(crate) fn addFontPreloadLinkHeader(headers: &mut Vec<(String, String)>, fontUrls: &[Rc<Url>])
+	{
+		if fontUrls.is_empty()
+		{
+			return;
+		}
+		
+		let links = fontUrls.iter().map(|fontUrl| format!("<{}>; rel=preload; as=font; type=\"font/woff2\"; crossorigin", fontUrl.as_str())).collect::<Vec<_>>();
+		headers.push(("Link".to_owned(), links.join(", ")));
+	}
+	
+	fn generateHeadersWithContentNegotiation(&mut self, isPjax: bool, isNegotiatedByAccept: bool, canBeCompressed: bool, maximumAgeInSeconds: u32, isDownloadable: bool, url: &Url) -> Result<Vec<(String, String)>, CordialError>
 	{
 		let deploymentVersion = &self.configuration.deploymentVersion;
 		
 		let mut headers = Vec::with_capacity(self.headerTemplates.len() * 2);
 		
-		let vary = if isPjax
+		let mut vary = Vec::with_capacity(3);
+		if isNegotiatedByAccept
+		{
+			vary.push("accept");
+		}
+		if canBeCompressed
+		{
+			vary.push("content-encoding");
+		}
+		if isPjax
 		{
 			headers.push(("X-PJAX-Version".to_owned(), format!("{}", deploymentVersion)));
 			
-			if canBeCompressed
-			{
-				Some("content-encoding,x-pjax")
-			}
-			else
-			{
-				Some("x-pjax")
-			}
+			vary.push("x-pjax");
 		}
-		else
-		{
-			if canBeCompressed
-			{
-				Some("content-encoding")
-			}
-			else
-			{
-				None
-			}
-		};
-		if let Some(vary) = vary
+		if !vary.is_empty()
 		{
-			headers.push(("Vary".to_owned(), vary.to_owned()));
+			headers.push(("Vary".to_owned(), vary.join(",")));
 		}
 		
 		if maximumAgeInSeconds == 0
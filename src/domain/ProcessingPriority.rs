@@ -15,5 +15,5 @@ pub(crate) enum ProcessingPriority
 
 impl ProcessingPriority
 {
-	pub const All: [ProcessingPriority; 4] = [ProcessingPriority::NoDependenciesEgImage, ProcessingPriority::DependsOnOthersEgStylesheetOrVideo, ProcessingPriority::LinksToSubResourcesEgHtmlPage, ProcessingPriority::IndexesEgSiteMap];
+	pub const All: [ProcessingPriority; 5] = [ProcessingPriority::NoDependenciesEgImage, ProcessingPriority::CompositesEgCssSpriteImages, ProcessingPriority::DependsOnOthersEgStylesheetOrVideo, ProcessingPriority::LinksToSubResourcesEgHtmlPage, ProcessingPriority::IndexesEgSiteMap];
 }
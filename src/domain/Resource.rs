@@ -13,8 +13,12 @@ pub(crate) struct Resource
 	#[serde(default)] font: FontPipeline,
 	#[serde(default)] gif_animation: GifAnimationPipeline,
 	#[serde(default)] html: HtmlPipeline,
+	#[serde(default)] icon_set: IconSetPipeline,
 	#[serde(default)] raster_image: RasterImagePipeline,
+	#[serde(default)] social_card: SocialCardPipeline,
+	#[serde(default)] sprite: SpritePipeline,
 	#[serde(default)] svg: SvgPipeline,
+	#[serde(default)] svg_sprite: SvgSpritePipeline,
 	#[serde(default)] template: TemplatePipeline,
 	#[serde(default)] video: VideoPipeline,
 	#[serde(default)] web_app_manifest: WebAppManifestPipeline,
@@ -73,7 +77,7 @@ impl Resource
 					{
 						match resourceTag
 						{
-							&ResourceTag::width_height_image(width, height) =>
+							&ResourceTag::width_height_image(width, height) | &ResourceTag::png_width_height_image(width, height) =>
 							{
 								if width > idealImageWidth && width >= 160 && width <= 1920 && height >= 90 && height <= 1080
 								{
@@ -117,7 +121,7 @@ impl Resource
 					{
 						match resourceTag
 						{
-							&ResourceTag::width_height_image(width, height) =>
+							&ResourceTag::width_height_image(width, height) | &ResourceTag::png_width_height_image(width, height) =>
 							{
 								if width > idealImageWidth && width >= 1200 && width <= 7000 && height >= 1200 && height <= 7000
 								{
@@ -164,7 +168,7 @@ impl Resource
 					{
 						match resourceTag
 						{
-							&ResourceTag::width_height_image(width, height) =>
+							&ResourceTag::width_height_image(width, height) | &ResourceTag::png_width_height_image(width, height) =>
 							{
 								if width > idealImageWidth && width >= 1400 && width <= 3000 && height >= 1400 && height <= 3000
 								{
@@ -220,7 +224,7 @@ impl Resource
 					{
 						match resourceTag
 						{
-							&ResourceTag::width_height_image(width, height) =>
+							&ResourceTag::width_height_image(width, height) | &ResourceTag::png_width_height_image(width, height) =>
 							{
 								if urlData.size() < twitterCardImageMatch.maximumSize && idealImageWidth < width
 								{
@@ -270,7 +274,7 @@ impl Resource
 					{
 						match resourceTag
 						{
-							&ResourceTag::width_height_image(width, height) =>
+							&ResourceTag::width_height_image(width, height) | &ResourceTag::png_width_height_image(width, height) =>
 							{
 								if urlData.size() < FiveMegabytesExclusive && idealImageWidth < width && (width as u64 * height as u64) >= MinimumPixelsInclusive
 								{
@@ -318,7 +322,7 @@ impl Resource
 					{
 						match resourceTag
 						{
-							&ResourceTag::width_height_image(width, height) =>
+							&ResourceTag::width_height_image(width, height) | &ResourceTag::png_width_height_image(width, height) =>
 							{
 								if urlData.size() <= EightMegabytes
 								{
@@ -387,6 +391,55 @@ impl Resource
 		}
 	}
 	
+	#[inline(always)]
+	pub(crate) fn svgSpritePipeline(&self) -> Result<&SvgSpritePipeline, CordialError>
+	{
+		match self.pipeline
+		{
+			ResourcePipeline::svg_sprite => Ok(&self.svg_sprite),
+			_ => Err(CordialError::Configuration("Not a svg_sprite resource".to_owned())),
+		}
+	}
+	
+	/// The abstract, author's name and publication date of a HTML resource, used to fill in a social card.
+	#[inline(always)]
+	pub(crate) fn socialCardData(&self, resources: &Resources, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<(Rc<HtmlAbstract>, Option<Rc<String>>, Option<DateTime<Utc>>), CordialError>
+	{
+		let htmlPipeline = self.htmlPipeline()?;
+		
+		let author = match htmlPipeline.author()
+		{
+			None => None,
+			Some(authorResourceUrl) =>
+			{
+				let authorResource = authorResourceUrl.resourceMandatory(resources)?;
+				match authorResource.htmlPipeline()
+				{
+					Ok(authorHtmlPipeline) => Some(authorHtmlPipeline.htmlAbstract(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?.title.clone()),
+					Err(_) => authorResource.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?,
+				}
+			}
+		};
+		
+		Ok((htmlPipeline.htmlAbstract(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?.clone(), author, htmlPipeline.publicationDate()))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn iconSetPipeline(&self) -> Result<&IconSetPipeline, CordialError>
+	{
+		match self.pipeline
+		{
+			ResourcePipeline::icon_set => Ok(&self.icon_set),
+			_ => Err(CordialError::Configuration("Not an icon set resource".to_owned())),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn registerSocialCard(&self, socialCardResourceUrl: &ResourceUrl) -> Result<(), CordialError>
+	{
+		self.htmlPipeline()?.registerSocialCard(socialCardResourceUrl)
+	}
+	
 	#[inline(always)]
 	fn htmlPipeline(&self) -> Result<&HtmlPipeline, CordialError>
 	{
@@ -480,13 +533,24 @@ impl Resource
 				{
 					let (ifLanguageAwareLanguageData, inputContentFilePath) = if isLanguageAware
 					{
-						(Some(languageData), self.inputContentFilePath(primaryLanguage, Some(languageData.iso639Dash1Alpha2Language))?)
+						(Some(languageData), self.inputContentFilePath(primaryLanguage, Some(languageData.iso639Dash1Alpha2Language)))
 					}
 					else
 					{
-						(None, self.languageNeutralInputContentFilePath(primaryLanguage, None)?)
+						(None, self.languageNeutralInputContentFilePath(primaryLanguage, None))
 					};
 					
+					let inputContentFilePath = match inputContentFilePath
+					{
+						Err(CordialError::CouldNotFindResourceContentFile(_)) if !self.requiresInputContentFile() => None,
+						inputContentFilePath => Some(inputContentFilePath?),
+					};
+					
+					if let Some(ref inputContentFilePath) = inputContentFilePath
+					{
+						self.importEmbeddedImageMetaData(inputContentFilePath, resources, &configuration.localization, iso639Dash1Alpha2Language)?;
+					}
+					
 					let mut rssChannelsToRssItems = rssChannelsByLanguage.get_mut(&iso639Dash1Alpha2Language).unwrap();
 					let mut siteMapWebPages = getOrDefault(siteMapWebPagesByLanguage, iso639Dash1Alpha2Language);
 					
@@ -498,10 +562,30 @@ impl Resource
 						configuration,
 					};
 					
-					self.execute(resources, &inputContentFilePath, resourceUrl, handlebars, &mut headerGenerator, languageData, configuration, &mut rssChannelsToRssItems, &mut siteMapWebPages)?
+					match inputContentFilePath
+					{
+						Some(ref inputContentFilePath) => self.execute(resources, inputContentFilePath, resourceUrl, handlebars, &mut headerGenerator, languageData, configuration, &mut rssChannelsToRssItems, &mut siteMapWebPages)?,
+						None => self.executeWithoutInputContentFile(resources, resourceUrl, handlebars, &mut headerGenerator, languageData, configuration, &mut rssChannelsToRssItems, &mut siteMapWebPages)?,
+					}
+				};
+				
+				let negotiatesAlternativeImageFormats = self.negotiatesAlternativeImageFormats();
+				
+				// Alternative image formats must be rendered before the original image they are negotiated for.
+				let result = if negotiatesAlternativeImageFormats
+				{
+					let (alternativeImageFormats, others): (Vec<PipelineResponse>, Vec<PipelineResponse>) = result.into_iter().partition(|pipelineResponse| Self::alternativeImageFormatWidth(&pipelineResponse.1).is_some());
+					let mut result = alternativeImageFormats;
+					result.extend(others);
+					result
+				}
+				else
+				{
+					result
 				};
+				let mut alternativeImageFormatsByWidth: HashMap<u32, Vec<(String, Arc<StaticResponse>)>> = HashMap::new();
 				
-				// Always inserts, as this language code will only occur once.
+				// Only inserts when first rendered, as this language code will only occur once; if rendered a second time, the same URLs are replaced.
 				let urls = self.urlData.entry(iso639Dash1Alpha2Language).or_insert(HashMap::with_capacity(result.len()));
 				
 				for (mut url, mut resourceTagsWithJsonValues, statusCode, contentType, regularHeaders, regularBody, pjax, canBeCompressed) in result
@@ -540,7 +624,29 @@ impl Resource
 					{
 						if statusCode == StatusCode::Ok
 						{
-							RegularAndPjaxStaticResponse::regular(StaticResponse::new(statusCode, contentType, regularHeaders, regularBody, regularCompressed))
+							let response = StaticResponse::new(statusCode, contentType, regularHeaders, regularBody, regularCompressed);
+							
+							if negotiatesAlternativeImageFormats
+							{
+								if let Some(width) = Self::alternativeImageFormatWidth(&resourceTagsWithJsonValues)
+								{
+									let response = Arc::new(response);
+									alternativeImageFormatsByWidth.entry(width).or_insert_with(Vec::new).push((format!("{}/{}", mimeType.type_(), mimeType.subtype()), response.clone()));
+									RegularAndPjaxStaticResponse::shared(response)
+								}
+								else if let Some(width) = Self::originalImageWidth(&resourceTagsWithJsonValues)
+								{
+									RegularAndPjaxStaticResponse::withAlternativeImageFormats(response, alternativeImageFormatsByWidth.remove(&width).unwrap_or_default())
+								}
+								else
+								{
+									RegularAndPjaxStaticResponse::regular(response)
+								}
+							}
+							else
+							{
+								RegularAndPjaxStaticResponse::regular(response)
+							}
 						}
 						else
 						{
@@ -554,7 +660,7 @@ impl Resource
 					{
 						if isVersioned
 						{
-							url.set_query(Some(&format!("v={}", newResponse.entityTag())));
+							Self::versionUrl(&mut url, newResponse.entityTag());
 						}
 						
 						newResponses.addResponse(url.clone(), newResponse, oldResponses.clone());
@@ -579,6 +685,37 @@ impl Resource
 		Ok(())
 	}
 	
+	#[inline(always)]
+	fn alternativeImageFormatWidth(resourceTags: &HashMap<ResourceTag, Rc<UrlDataDetails>>) -> Option<u32>
+	{
+		use self::ResourceTag::*;
+		
+		for resourceTag in resourceTags.keys()
+		{
+			match *resourceTag
+			{
+				webp_image(width) | avif_image(width) => return Some(width),
+				_ => (),
+			}
+		}
+		None
+	}
+	
+	#[inline(always)]
+	fn originalImageWidth(resourceTags: &HashMap<ResourceTag, Rc<UrlDataDetails>>) -> Option<u32>
+	{
+		use self::ResourceTag::*;
+		
+		for resourceTag in resourceTags.keys()
+		{
+			if let width_image(width) = *resourceTag
+			{
+				return Some(width);
+			}
+		}
+		None
+	}
+	
 	/// if language is some, then searches for resource by language, primary language or language-neutral name in descending order
 	/// if language is none, the searches by language-neutral name
 	#[inline(always)]
@@ -628,6 +765,51 @@ impl Resource
 		CordialError::couldNotFindResourceContentFile(self, primaryLanguage, language)
 	}
 	
+	#[inline(always)]
+	fn negotiatesAlternativeImageFormats(&self) -> bool
+	{
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			raster_image => self.raster_image.negotiatesAlternativeImageFormats(),
+			_ => false,
+		}
+	}
+	
+	/// Validates configuration which refers to other fields, and so can not be validated as it is deserialized.
+	#[inline(always)]
+	pub(crate) fn validateConfiguration(&self) -> Result<(), CordialError>
+	{
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			font => self.font.validateCriticalUnicodeRanges(),
+			_ => Ok(()),
+		}
+	}
+	
+	#[inline(always)]
+	fn importEmbeddedImageMetaData(&mut self, inputContentFilePath: &Path, resources: &Resources, localization: &Localization, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<(), CordialError>
+	{
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			raster_image => self.raster_image.importCreditAndLicenseUrlFromEmbeddedMetaData(inputContentFilePath, resources, localization, iso639Dash1Alpha2Language),
+			_ => Ok(()),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn animationMutedVideo(&self) -> Option<(&ResourceUrl, bool)>
+	{
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			gif_animation => self.gif_animation.mutedVideo(),
+			_ => None,
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn processedImageSourceSet(&self) -> Result<&RefCell<ProcessedImageSourceSet>, CordialError>
 	{
@@ -640,6 +822,207 @@ impl Resource
 		}
 	}
 	
+	/// A versioned URL has its response's entity tag as the query string `v`.
+	/// Pipelines that need a versioned URL before this resource has finished rendering (eg a font's `@font-face` stylesheet) use this so that the URLs match.
+	#[inline(always)]
+	pub(crate) fn versionUrl(url: &mut Url, entityTag: &str)
+	{
+		url.set_query(Some(&format!("v={}", entityTag)));
+	}
+	
+	/// An image that overlays itself, directly or through other images, would otherwise recurse without end when rendered.
+	pub(crate) fn validateImageOverlaysAreAcyclic(resources: &Resources) -> Result<(), CordialError>
+	{
+		fn visit(resourceUrl: &ResourceUrl, resources: &Resources, path: &mut Vec<ResourceUrl>, acyclic: &mut HashSet<ResourceUrl>) -> Result<(), CordialError>
+		{
+			if acyclic.contains(resourceUrl)
+			{
+				return Ok(());
+			}
+			
+			if let Some(index) = path.iter().position(|visited| visited == resourceUrl)
+			{
+				let mut cycle: Vec<String> = path[index..].iter().map(|resourceUrl| resourceUrl.to_string()).collect();
+				cycle.push(resourceUrl.to_string());
+				return Err(CordialError::Configuration(format!("image overlays form a cycle '{}'", cycle.join("' -> '"))));
+			}
+			
+			let overlayResourceUrls = match resourceUrl.resource(resources)
+			{
+				None => return Ok(()),
+				Some(resource) => resource.try_borrow()?.overlayResourceUrls(),
+			};
+			
+			path.push(resourceUrl.clone());
+			for overlayResourceUrl in overlayResourceUrls.iter()
+			{
+				visit(overlayResourceUrl, resources, path, acyclic)?;
+			}
+			path.pop();
+			
+			acyclic.insert(resourceUrl.clone());
+			Ok(())
+		}
+		
+		let mut path = Vec::new();
+		let mut acyclic = HashSet::with_capacity(resources.len());
+		for resourceUrl in resources.keys()
+		{
+			visit(resourceUrl, resources, &mut path, &mut acyclic)?;
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn overlayResourceUrls(&self) -> Vec<ResourceUrl>
+	{
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			raster_image => self.raster_image.overlayResourceUrls(),
+			_ => Vec::new(),
+		}
+	}
+	
+	/// Loads this resource as a raster image so that it can be composited into another, eg a sprite sheet.
+	#[inline(always)]
+	pub(crate) fn imageForCompositing(&self, resourceUrl: &ResourceUrl, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<::image::DynamicImage, CordialError>
+	{
+		let inputContentFilePath = self.inputContentFilePathForCompositing(configuration, iso639Dash1Alpha2Language)?;
+		
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			raster_image => self.raster_image.transformedImage(&inputContentFilePath, resources, configuration, iso639Dash1Alpha2Language),
+			svg => self.svg.rasterizedImage(&inputContentFilePath, resourceUrl, configuration, iso639Dash1Alpha2Language),
+			_ => Err(CordialError::Configuration(format!("resource '{:?}' is not a raster_image or svg", self.name()))),
+		}
+	}
+	
+	/// This resource's `viewBox` and children, so that it can be composited into a SVG sprite as a `<symbol>`.
+	#[inline(always)]
+	pub(crate) fn svgSymbol(&self, resourceUrl: &ResourceUrl, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, symbolId: &str) -> Result<(String, String), CordialError>
+	{
+		let inputContentFilePath = self.inputContentFilePathForCompositing(configuration, iso639Dash1Alpha2Language)?;
+		
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			svg => self.svg.symbol(&inputContentFilePath, resourceUrl, configuration, iso639Dash1Alpha2Language, symbolId),
+			_ => Err(CordialError::Configuration(format!("resource '{:?}' is not a svg", self.name()))),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn isSvg(&self) -> bool
+	{
+		match self.pipeline
+		{
+			ResourcePipeline::svg => true,
+			_ => false,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn isSvgSprite(&self) -> bool
+	{
+		match self.pipeline
+		{
+			ResourcePipeline::svg_sprite => true,
+			_ => false,
+		}
+	}
+	
+	/// For a font marked critical, the WOFF2 URLs in its `@font-face` SCSS partial that should be preloaded.
+	#[inline(always)]
+	pub(crate) fn criticalFontUrls(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Vec<Rc<Url>>
+	{
+		match self.pipeline
+		{
+			ResourcePipeline::font => self.font.criticalFontUrls(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
+			_ => Vec::new(),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn stylesheetCriticalFontUrls(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Vec<Rc<Url>>, CordialError>
+	{
+		match self.pipeline
+		{
+			ResourcePipeline::css => Ok(self.css.criticalFontUrls(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)),
+			_ => Err(CordialError::Configuration(format!("resource '{:?}' is not a stylesheet", self.name()))),
+		}
+	}
+	
+	/// The text of this resource that a font subset for `iso639Dash1Alpha2Language` needs to be able to render, if any.
+	/// For a HTML page, this is the characters of its rendered HTML if it has been rendered, otherwise its markdown and abstract.
+	#[inline(always)]
+	pub(crate) fn textForFontSubsetting(&self, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<String>, CordialError>
+	{
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			html =>
+			{
+				if let Some(renderedCharacters) = self.html.renderedCharacters(iso639Dash1Alpha2Language)?
+				{
+					return Ok(Some(renderedCharacters));
+				}
+				
+				let inputContentFilePath = self.inputContentFilePathForCompositing(configuration, iso639Dash1Alpha2Language)?;
+				let mut text = inputContentFilePath.fileContentsAsString().context(&inputContentFilePath)?;
+				
+				let htmlAbstract = self.html.htmlAbstract(configuration.fallbackIso639Dash1Alpha2Language(), iso639Dash1Alpha2Language)?;
+				text.push_str(&htmlAbstract.title_html);
+				text.push_str(&htmlAbstract.title);
+				text.push_str(&htmlAbstract.description);
+				
+				Ok(Some(text))
+			}
+			_ => Ok(None),
+		}
+	}
+	
+	/// True if this is a font which was subset to fewer characters than the HTML pages, once rendered, use.
+	#[inline(always)]
+	pub(crate) fn fontSubsetLacksRenderedCharacters(&self, resources: &Resources, configuration: &Configuration) -> Result<bool, CordialError>
+	{
+		match self.pipeline
+		{
+			ResourcePipeline::font => self.font.subsetLacksRenderedCharacters(resources, configuration),
+			_ => Ok(false),
+		}
+	}
+	
+	/// Loads this resource's font file as TrueType or OpenType (decoding WOFF and WOFF2) so that text can be drawn with it into an image.
+	#[inline(always)]
+	pub(crate) fn fontForCompositing(&self, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Vec<u8>, CordialError>
+	{
+		let inputContentFilePath = self.inputContentFilePathForCompositing(configuration, iso639Dash1Alpha2Language)?;
+		
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			font => self.font.sfntBytes(&inputContentFilePath),
+			_ => Err(CordialError::Configuration(format!("resource '{:?}' is not a font", self.name()))),
+		}
+	}
+	
+	#[inline(always)]
+	fn inputContentFilePathForCompositing(&self, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<PathBuf, CordialError>
+	{
+		let primaryLanguage = configuration.fallbackIso639Dash1Alpha2Language();
+		let (_isVersioned, isLanguageAware) = self.is();
+		if isLanguageAware
+		{
+			self.inputContentFilePath(primaryLanguage, Some(iso639Dash1Alpha2Language))
+		}
+		else
+		{
+			self.languageNeutralInputContentFilePath(primaryLanguage, None)
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn anchorTitleAttribute(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<Rc<String>>, CordialError>
 	{
@@ -652,8 +1035,12 @@ impl Resource
 			font => self.font.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
 			gif_animation => self.gif_animation.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
 			html => self.html.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
+			icon_set => self.icon_set.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
 			raster_image => self.raster_image.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
+			social_card => self.social_card.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
+			sprite => self.sprite.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
 			svg => self.svg.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
+			svg_sprite => self.svg_sprite.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
 			template => self.template.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
 			video => self.video.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
 			web_app_manifest => self.web_app_manifest.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language),
@@ -672,8 +1059,12 @@ impl Resource
 			font => self.font.addToImgAttributes(attributes),
 			gif_animation => self.gif_animation.addToImgAttributes(attributes),
 			html => self.html.addToImgAttributes(attributes),
+			icon_set => self.icon_set.addToImgAttributes(attributes),
 			raster_image => self.raster_image.addToImgAttributes(attributes),
+			social_card => self.social_card.addToImgAttributes(attributes),
+			sprite => self.sprite.addToImgAttributes(attributes),
 			svg => self.svg.addToImgAttributes(attributes),
+			svg_sprite => self.svg_sprite.addToImgAttributes(attributes),
 			template => self.template.addToImgAttributes(attributes),
 			video => self.video.addToImgAttributes(attributes),
 			web_app_manifest => self.web_app_manifest.addToImgAttributes(attributes),
@@ -692,8 +1083,12 @@ impl Resource
 			font => self.font.imageMetaData(),
 			gif_animation => self.gif_animation.imageMetaData(),
 			html => self.html.imageMetaData(),
+			icon_set => self.icon_set.imageMetaData(),
 			raster_image => self.raster_image.imageMetaData(),
+			social_card => self.social_card.imageMetaData(),
+			sprite => self.sprite.imageMetaData(),
 			svg => self.svg.imageMetaData(),
+			svg_sprite => self.svg_sprite.imageMetaData(),
 			template => self.template.imageMetaData(),
 			video => self.video.imageMetaData(),
 			web_app_manifest => self.web_app_manifest.imageMetaData(),
@@ -712,8 +1107,12 @@ impl Resource
 			font => self.font.processingPriority(),
 			gif_animation => self.gif_animation.processingPriority(),
 			html => self.html.processingPriority(),
+			icon_set => self.icon_set.processingPriority(),
 			raster_image => self.raster_image.processingPriority(),
+			social_card => self.social_card.processingPriority(),
+			sprite => self.sprite.processingPriority(),
 			svg => self.svg.processingPriority(),
+			svg_sprite => self.svg_sprite.processingPriority(),
 			template => self.template.processingPriority(),
 			video => self.video.processingPriority(),
 			web_app_manifest => self.web_app_manifest.processingPriority(),
@@ -732,14 +1131,42 @@ impl Resource
 			font => self.font.resourceInputContentFileNamesWithExtension(resourceInputName),
 			gif_animation => self.gif_animation.resourceInputContentFileNamesWithExtension(resourceInputName),
 			html => self.html.resourceInputContentFileNamesWithExtension(resourceInputName),
+			icon_set => self.icon_set.resourceInputContentFileNamesWithExtension(resourceInputName),
 			raster_image => self.raster_image.resourceInputContentFileNamesWithExtension(resourceInputName),
+			social_card => self.social_card.resourceInputContentFileNamesWithExtension(resourceInputName),
+			sprite => self.sprite.resourceInputContentFileNamesWithExtension(resourceInputName),
 			svg => self.svg.resourceInputContentFileNamesWithExtension(resourceInputName),
+			svg_sprite => self.svg_sprite.resourceInputContentFileNamesWithExtension(resourceInputName),
 			template => self.template.resourceInputContentFileNamesWithExtension(resourceInputName),
 			video => self.video.resourceInputContentFileNamesWithExtension(resourceInputName),
 			web_app_manifest => self.web_app_manifest.resourceInputContentFileNamesWithExtension(resourceInputName),
 		}
 	}
 	
+	#[inline(always)]
+	fn requiresInputContentFile(&self) -> bool
+	{
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			audio => self.audio.requiresInputContentFile(),
+			browser_config => self.browser_config.requiresInputContentFile(),
+			css => self.css.requiresInputContentFile(),
+			font => self.font.requiresInputContentFile(),
+			gif_animation => self.gif_animation.requiresInputContentFile(),
+			html => self.html.requiresInputContentFile(),
+			icon_set => self.icon_set.requiresInputContentFile(),
+			raster_image => self.raster_image.requiresInputContentFile(),
+			social_card => self.social_card.requiresInputContentFile(),
+			sprite => self.sprite.requiresInputContentFile(),
+			svg => self.svg.requiresInputContentFile(),
+			svg_sprite => self.svg_sprite.requiresInputContentFile(),
+			template => self.template.requiresInputContentFile(),
+			video => self.video.requiresInputContentFile(),
+			web_app_manifest => self.web_app_manifest.requiresInputContentFile(),
+		}
+	}
+	
 	#[inline(always)]
 	fn is<'a>(&self) -> (bool, bool)
 	{
@@ -752,8 +1179,12 @@ impl Resource
 			font => self.font.is(),
 			gif_animation => self.gif_animation.is(),
 			html => self.html.is(),
+			icon_set => self.icon_set.is(),
 			raster_image => self.raster_image.is(),
+			social_card => self.social_card.is(),
+			sprite => self.sprite.is(),
 			svg => self.svg.is(),
+			svg_sprite => self.svg_sprite.is(),
 			template => self.template.is(),
 			video => self.video.is(),
 			web_app_manifest => self.web_app_manifest.is(),
@@ -772,11 +1203,39 @@ impl Resource
 			font => self.font.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
 			gif_animation => self.gif_animation.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
 			html => self.html.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			icon_set => self.icon_set.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
 			raster_image => self.raster_image.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			social_card => self.social_card.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			sprite => self.sprite.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
 			svg => self.svg.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			svg_sprite => self.svg_sprite.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
 			template => self.template.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
 			video => self.video.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
 			web_app_manifest => self.web_app_manifest.execute(resources, inputContentFilePath, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
 		}
 	}
+	
+	#[inline(always)]
+	fn executeWithoutInputContentFile(&self, resources: &Resources, resourceUrl: &ResourceUrl, handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		use self::ResourcePipeline::*;
+		match self.pipeline
+		{
+			audio => self.audio.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			browser_config => self.browser_config.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			css => self.css.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			font => self.font.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			gif_animation => self.gif_animation.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			html => self.html.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			icon_set => self.icon_set.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			raster_image => self.raster_image.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			social_card => self.social_card.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			sprite => self.sprite.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			svg => self.svg.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			svg_sprite => self.svg_sprite.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			template => self.template.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			video => self.video.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+			web_app_manifest => self.web_app_manifest.executeWithoutInputContentFile(resources, resourceUrl, handlebars, headerGenerator, languageData, configuration, rssChannelsToRssItems, siteMapWebPages),
+		}
+	}
 }
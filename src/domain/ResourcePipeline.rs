@@ -12,9 +12,13 @@ pub(crate) enum ResourcePipeline
 	font,
 	gif_animation,
 	html,
+	icon_set,
 	raster_image,
+	social_card,
+	sprite,
 	template,
 	svg,
+	svg_sprite,
 	web_app_manifest,
 	video,
 }
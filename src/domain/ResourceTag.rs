@@ -16,9 +16,27 @@ pub(crate) enum ResourceTag
 	height_image(u32),
 	width_height_image(u32, u32),
 	
+	// A PNG rasterized from a SVG
+	png_width_height_image(u32, u32),
+	
+	// Value is width
+	webp_image(u32),
+	avif_image(u32),
+	display_p3_image(u32),
+	
+	// Values are index in art direction, then width
+	art_direction_image(usize, u32),
+	
+	// Values are index in art direction, index in alternative formats, then width
+	art_direction_alternative_format_image(usize, usize, u32),
+	
 	// Value is index in source set
 	animation_placeholder(usize),
 	
+	// Icon sets
+	favicon_ico,
+	maskable_width_height_image(u32, u32),
+	
 	redirect,
 	amp,
 	amp_redirect,
@@ -27,7 +45,29 @@ pub(crate) enum ResourceTag
 	audio_video_track(AudioVideoTrackKind, Iso639Dash1Alpha2Language),
 	
 	audio_mp4,
-
+	audio_mp3,
+	audio_ogg_vorbis,
+	audio_ogg_opus,
+	audio_flac,
+	
+	font_woff,
+	font_woff2,
+	font_ttf,
+	
+	// Value is index in unicode ranges
+	font_woff_unicode_range(usize),
+	font_woff2_unicode_range(usize),
+	
+	// Value is index in static instances
+	font_woff_static_instance(usize),
+	font_woff2_static_instance(usize),
+	
+	// Values are index in static instances, then index in unicode ranges
+	font_woff_static_instance_unicode_range(usize, usize),
+	font_woff2_static_instance_unicode_range(usize, usize),
+	
+	font_face_css,
+	
 	video_mp4,
 	video_webm,
 }
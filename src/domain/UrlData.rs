@@ -48,12 +48,24 @@ impl UrlData
 		self.urlDataDetails.image()
 	}
 	
+	#[inline(always)]
+	pub(crate) fn placeholder(&self) -> Option<&Rc<ImagePlaceholder>>
+	{
+		self.urlDataDetails.placeholder()
+	}
+	
 	#[inline(always)]
 	pub(crate) fn durationInSeconds(&self) -> Result<u64, CordialError>
 	{
 		self.urlDataDetails.durationInSeconds()
 	}
 	
+	#[inline(always)]
+	pub(crate) fn optionalAudioBitRateInKilobitsPerSecond(&self) -> Option<u64>
+	{
+		self.urlDataDetails.optionalAudioBitRateInKilobitsPerSecond()
+	}
+	
 	#[inline(always)]
 	pub(crate) fn optionalVideoWidthHeight(&self) -> Option<(u16, u16)>
 	{
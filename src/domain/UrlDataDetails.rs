@@ -15,6 +15,7 @@ pub(crate) enum UrlDataDetails
 		size: u64,
 		width: u32,
 		height: u32,
+		placeholder: Option<Rc<ImagePlaceholder>>,
 	},
 
 	Video
@@ -29,6 +30,7 @@ pub(crate) enum UrlDataDetails
 	{
 		size: u64,
 		durationInSeconds: u64,
+		bitRateInBitsPerSecond: u64,
 	}
 }
 
@@ -56,12 +58,13 @@ impl UrlDataDetails
 	}
 	
 	#[inline(always)]
-	pub(crate) fn audio(body: &[u8], durationInSeconds: u64) -> Self
+	pub(crate) fn audio(body: &[u8], durationInSeconds: u64, bitRateInBitsPerSecond: u64) -> Self
 	{
 		UrlDataDetails::Audio
 		{
 			size: body.len() as u64,
 			durationInSeconds,
+			bitRateInBitsPerSecond,
 		}
 	}
 	
@@ -88,6 +91,17 @@ impl UrlDataDetails
 		}
 	}
 	
+	#[inline(always)]
+	fn placeholder(&self) -> Option<&Rc<ImagePlaceholder>>
+	{
+		match *self
+		{
+			UrlDataDetails::Image { ref placeholder, .. } => placeholder.as_ref(),
+			
+			_ => None,
+		}
+	}
+	
 	#[inline(always)]
 	fn durationInSeconds(&self) -> Result<u64, CordialError>
 	{
@@ -114,6 +128,17 @@ impl UrlDataDetails
 		}
 	}
 	
+	#[inline(always)]
+	pub(crate) fn optionalAudioBitRateInKilobitsPerSecond(&self) -> Option<u64>
+	{
+		match *self
+		{
+			UrlDataDetails::Audio { bitRateInBitsPerSecond, .. } if bitRateInBitsPerSecond != 0 => Some(bitRateInBitsPerSecond / 1000),
+			
+			_ => None,
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn optionalVideoWidthHeight(&self) -> Option<(u16, u16)>
 	{
@@ -0,0 +1,201 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AudioDetails
+{
+	pub(crate) durationInSeconds: u64,
+	pub(crate) bitRateInBitsPerSecond: u64,
+	pub(crate) tags: AudioTags,
+}
+
+impl AudioDetails
+{
+	#[inline(always)]
+	pub(crate) fn mp4(bytes: &[u8]) -> Result<Self, CordialError>
+	{
+		let durationInSeconds = onlyAudioTrackDuration(bytes)?;
+		Ok(Self::new(bytes, durationInSeconds, None, AudioTags::default()))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn mp3(bytes: &[u8], inputContentFilePath: &Path) -> Result<Self, CordialError>
+	{
+		let metadata = match ::mp3_metadata::read_from_slice(bytes)
+		{
+			Err(error) => return Err(Self::error(inputContentFilePath, format!("{:?}", error))),
+			Ok(metadata) => metadata,
+		};
+		
+		// Variable bit rate MP3s have a different bit rate (in kilobits per second) for each frame.
+		let bitRateInBitsPerSecond = if metadata.frames.is_empty()
+		{
+			None
+		}
+		else
+		{
+			let totalBitRate = metadata.frames.iter().map(|frame| frame.bitrate as u64).sum::<u64>();
+			Some(totalBitRate * 1000 / metadata.frames.len() as u64)
+		};
+		
+		// ID3v2 tags are read first as ID3v1 tags truncate values to 30 bytes; the first value for a tag is kept.
+		let mut tags = AudioTags::default();
+		for optionalInfo in metadata.optional_info.iter()
+		{
+			if let Some(ref title) = optionalInfo.title
+			{
+				tags.add("TITLE", title);
+			}
+			tags.add("ARTIST", &optionalInfo.performers.join(", "));
+			if let Some(ref album) = optionalInfo.album_movie_show
+			{
+				tags.add("ALBUM", album);
+			}
+		}
+		if let Some(ref tag) = metadata.tag
+		{
+			tags.add("TITLE", &tag.title);
+			tags.add("ARTIST", &tag.artist);
+			tags.add("ALBUM", &tag.album);
+		}
+		
+		Ok(Self::new(bytes, metadata.duration.as_secs(), bitRateInBitsPerSecond, tags))
+	}
+	
+	/// Ogg Vorbis and Opus are parsed from their identification and comment header packets; duration comes from the granule position of the last page.
+	#[inline(always)]
+	pub(crate) fn ogg(bytes: &[u8], audioCodec: AudioCodec, inputContentFilePath: &Path) -> Result<Self, CordialError>
+	{
+		let mut packetReader = ::ogg::PacketReader::new(Cursor::new(bytes));
+		
+		let mut identificationHeader = None;
+		let mut commentHeader = None;
+		let mut lastGranulePosition = 0;
+		let mut packetIndex = 0;
+		loop
+		{
+			let packet = match packetReader.read_packet()
+			{
+				Err(error) => return Err(Self::error(inputContentFilePath, format!("{:?}", error))),
+				Ok(None) => break,
+				Ok(Some(packet)) => packet,
+			};
+			
+			match packetIndex
+			{
+				0 => identificationHeader = Some(packet.data),
+				1 => commentHeader = Some(packet.data),
+				_ => (),
+			}
+			packetIndex += 1;
+			
+			// Pages on which no packet finishes have a granule position of -1.
+			if packet.absgp_page != ::std::u64::MAX
+			{
+				lastGranulePosition = packet.absgp_page;
+			}
+		}
+		
+		let (identificationHeader, commentHeader) = match (identificationHeader, commentHeader)
+		{
+			(Some(identificationHeader), Some(commentHeader)) => (identificationHeader, commentHeader),
+			_ => return Err(Self::error(inputContentFilePath, "missing identification or comment header".to_owned())),
+		};
+		
+		#[inline(always)]
+		fn u32LittleEndian(bytes: &[u8]) -> u32
+		{
+			(bytes[0] as u32) | (bytes[1] as u32) << 8 | (bytes[2] as u32) << 16 | (bytes[3] as u32) << 24
+		}
+		
+		match audioCodec
+		{
+			AudioCodec::vorbis =>
+			{
+				if identificationHeader.len() < 30 || !identificationHeader.starts_with(b"\x01vorbis") || !commentHeader.starts_with(b"\x03vorbis")
+				{
+					return Err(Self::error(inputContentFilePath, "not Vorbis".to_owned()));
+				}
+				
+				let sampleRate = u32LittleEndian(&identificationHeader[12 .. 16]) as u64;
+				if sampleRate == 0
+				{
+					return Err(Self::error(inputContentFilePath, "Vorbis sample rate is zero".to_owned()));
+				}
+				
+				// Zero (or negative) if the encoder did not set it.
+				let nominalBitRate = u32LittleEndian(&identificationHeader[20 .. 24]) as i32;
+				let bitRateInBitsPerSecond = if nominalBitRate > 0
+				{
+					Some(nominalBitRate as u64)
+				}
+				else
+				{
+					None
+				};
+				
+				Ok(Self::new(bytes, lastGranulePosition / sampleRate, bitRateInBitsPerSecond, AudioTags::parseVorbisComments(&commentHeader[7..])))
+			}
+			
+			AudioCodec::opus =>
+			{
+				if identificationHeader.len() < 19 || !identificationHeader.starts_with(b"OpusHead") || !commentHeader.starts_with(b"OpusTags")
+				{
+					return Err(Self::error(inputContentFilePath, "not Opus".to_owned()));
+				}
+				
+				// Opus granule positions are always at 48 kHz, whatever the input sample rate was.
+				const OpusGranuleRate: u64 = 48_000;
+				let preSkip = (identificationHeader[10] as u64) | (identificationHeader[11] as u64) << 8;
+				let durationInSeconds = lastGranulePosition.saturating_sub(preSkip) / OpusGranuleRate;
+				
+				Ok(Self::new(bytes, durationInSeconds, None, AudioTags::parseVorbisComments(&commentHeader[8..])))
+			}
+			
+			_ => Err(Self::error(inputContentFilePath, format!("Ogg audio codec {:?} is not supported", audioCodec))),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn flac(bytes: &[u8], inputContentFilePath: &Path) -> Result<Self, CordialError>
+	{
+		let flacReader = match ::claxon::FlacReader::new(Cursor::new(bytes))
+		{
+			Err(error) => return Err(Self::error(inputContentFilePath, format!("{}", error))),
+			Ok(flacReader) => flacReader,
+		};
+		
+		let streamInfo = flacReader.streaminfo();
+		let durationInSeconds = match streamInfo.samples
+		{
+			Some(samples) if streamInfo.sample_rate != 0 => samples / streamInfo.sample_rate as u64,
+			_ => 0,
+		};
+		
+		Ok(Self::new(bytes, durationInSeconds, None, AudioTags::fromVorbisComments(flacReader.tags())))
+	}
+	
+	/// If a format does not record its bit rate, an average is calculated from the size of the file.
+	#[inline(always)]
+	fn new(bytes: &[u8], durationInSeconds: u64, bitRateInBitsPerSecond: Option<u64>, tags: AudioTags) -> Self
+	{
+		Self
+		{
+			durationInSeconds,
+			bitRateInBitsPerSecond: match bitRateInBitsPerSecond
+			{
+				Some(bitRateInBitsPerSecond) => bitRateInBitsPerSecond,
+				None if durationInSeconds == 0 => 0,
+				None => (bytes.len() as u64) * 8 / durationInSeconds,
+			},
+			tags,
+		}
+	}
+	
+	#[inline(always)]
+	fn error(inputContentFilePath: &Path, reason: String) -> CordialError
+	{
+		CordialError::CouldNotParseAudio(inputContentFilePath.to_path_buf(), reason)
+	}
+}
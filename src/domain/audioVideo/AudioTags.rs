@@ -0,0 +1,106 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Tags embedded in an audio file, from ID3v2 or ID3v1 (MP3) or Vorbis comments (Ogg Vorbis, Opus and FLAC).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AudioTags
+{
+	pub(crate) title: Option<String>,
+	pub(crate) artist: Option<String>,
+	pub(crate) album: Option<String>,
+}
+
+impl AudioTags
+{
+	#[inline(always)]
+	pub(crate) fn fromVorbisComments<'a, I: Iterator<Item=(&'a str, &'a str)>>(comments: I) -> Self
+	{
+		let mut tags = Self::default();
+		for (name, value) in comments
+		{
+			tags.add(name, value);
+		}
+		tags
+	}
+	
+	/// Vorbis comments are a vendor string followed by `NAME=value` pairs, all length prefixed; truncated comments are ignored.
+	#[inline(always)]
+	pub(crate) fn parseVorbisComments(data: &[u8]) -> Self
+	{
+		#[inline(always)]
+		fn lengthPrefixed(data: &[u8], offset: &mut usize) -> Option<&[u8]>
+		{
+			if data.len() < *offset + 4
+			{
+				return None;
+			}
+			let length = (data[*offset] as usize) | (data[*offset + 1] as usize) << 8 | (data[*offset + 2] as usize) << 16 | (data[*offset + 3] as usize) << 24;
+			*offset += 4;
+			
+			if data.len() < *offset + length
+			{
+				return None;
+			}
+			let bytes = &data[*offset .. *offset + length];
+			*offset += length;
+			Some(bytes)
+		}
+		
+		let mut tags = Self::default();
+		
+		let mut offset = 0;
+		if lengthPrefixed(data, &mut offset).is_none()
+		{
+			return tags;
+		}
+		
+		if data.len() < offset + 4
+		{
+			return tags;
+		}
+		let numberOfComments = (data[offset] as u32) | (data[offset + 1] as u32) << 8 | (data[offset + 2] as u32) << 16 | (data[offset + 3] as u32) << 24;
+		offset += 4;
+		
+		for _ in 0 .. numberOfComments
+		{
+			match lengthPrefixed(data, &mut offset)
+			{
+				None => break,
+				Some(comment) => if let Ok(comment) = from_utf8(comment)
+				{
+					let mut nameAndValue = comment.splitn(2, '=');
+					if let (Some(name), Some(value)) = (nameAndValue.next(), nameAndValue.next())
+					{
+						tags.add(name, value);
+					}
+				},
+			}
+		}
+		
+		tags
+	}
+	
+	#[inline(always)]
+	pub(crate) fn add(&mut self, name: &str, value: &str)
+	{
+		let value = value.trim_matches(|character: char| character.is_whitespace() || character == '\0');
+		if value.is_empty()
+		{
+			return;
+		}
+		
+		let tag = match name.to_ascii_uppercase().as_str()
+		{
+			"TITLE" => &mut self.title,
+			"ARTIST" => &mut self.artist,
+			"ALBUM" => &mut self.album,
+			_ => return,
+		};
+		
+		if tag.is_none()
+		{
+			*tag = Some(value.to_owned());
+		}
+	}
+}
@@ -56,28 +56,35 @@ pub(crate) struct AudioVideoMetaData
 	#[serde(default)] pub(crate) licence: Option<ResourceUrl>,
 	
 	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) orderedMapOfWebVttUrls: RefCell<OrderMap<(AudioVideoTrackKind, Iso639Dash1Alpha2Language), Url>>,
+	
+	// Used as fallbacks for artist and album
+	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) embeddedAudioTags: RefCell<AudioTags>,
 }
 
 impl AudioVideoMetaData
 {
+	/// The most compatible format is also the `default`, which is used for podcast enclosures.
 	#[inline(always)]
-	pub(crate) fn createAudioMp4(&self, durationInSeconds: u64, mp4Url: Url, headerGenerator: &mut HeaderGenerator, mp4Body: Vec<u8>, result: &mut Vec<PipelineResponse>, max_age_in_seconds: u32) -> Result<(), CordialError>
+	pub(crate) fn createAudio(&self, audioInputFormat: AudioInputFormat, audioDetails: &AudioDetails, isMostCompatible: bool, url: Url, headerGenerator: &mut HeaderGenerator, body: Vec<u8>, result: &mut Vec<PipelineResponse>, max_age_in_seconds: u32) -> Result<(), CordialError>
 	{
 		const Incompressible: bool = false;
 		
-		let mp4Headers = headerGenerator.generateHeadersForAsset(Incompressible, max_age_in_seconds, self.isDownloadable(), &mp4Url)?;
-		let urlDataDetails = Rc::new(UrlDataDetails::audio(&mp4Body, durationInSeconds));
-		let mp4Tags = hashmap!
+		let headers = headerGenerator.generateHeadersForAsset(Incompressible, max_age_in_seconds, self.isDownloadable(), &url)?;
+		let urlDataDetails = Rc::new(UrlDataDetails::audio(&body, audioDetails.durationInSeconds, audioDetails.bitRateInBitsPerSecond));
+		let mut tags = hashmap!
 		{
-			ResourceTag::audio_mp4 => urlDataDetails.clone(),
-			ResourceTag::default => urlDataDetails,
+			audioInputFormat.resourceTag() => urlDataDetails.clone(),
 		};
-		result.push((mp4Url, mp4Tags, StatusCode::Ok, audioMp4TwitterContentType(), mp4Headers, ResponseBody::binary(mp4Body), None, Incompressible));
+		if isMostCompatible
+		{
+			tags.insert(ResourceTag::default, urlDataDetails);
+		}
+		result.push((url, tags, StatusCode::Ok, audioInputFormat.contentType(), headers, ResponseBody::binary(body), None, Incompressible));
 		Ok(())
 	}
 	
 	#[inline(always)]
-	pub(crate) fn createVideoMp4(&self, width: u16, height: u16, durationInSeconds: u64, mp4Url: Url, headerGenerator: &mut HeaderGenerator, mp4Body: Vec<u8>, result: &mut Vec<PipelineResponse>, max_age_in_seconds: u32) -> Result<(), CordialError>
+	pub(crate) fn createVideoMp4(&self, width: u16, height: u16, durationInSeconds: u64, mp4Url: Url, mp4ContentType: ContentType, headerGenerator: &mut HeaderGenerator, mp4Body: Vec<u8>, result: &mut Vec<PipelineResponse>, max_age_in_seconds: u32) -> Result<(), CordialError>
 	{
 		const Incompressible: bool = false;
 		
@@ -89,23 +96,21 @@ impl AudioVideoMetaData
 			ResourceTag::default => urlDataDetails,
 		};
 		
-		result.push((mp4Url, mp4Tags, StatusCode::Ok, videoMp4TwitterContentType(), mp4Headers, ResponseBody::binary(mp4Body), None, Incompressible));
+		result.push((mp4Url, mp4Tags, StatusCode::Ok, mp4ContentType, mp4Headers, ResponseBody::binary(mp4Body), None, Incompressible));
 		Ok(())
 	}
 	
 	#[inline(always)]
-	pub(crate) fn createWebm(&self, width: u16, height: u16, durationInSeconds: u64, webmUrl: Url, headerGenerator: &mut HeaderGenerator, inputContentFilePath: &Path, result: &mut Vec<PipelineResponse>, max_age_in_seconds: u32) -> Result<(), CordialError>
+	pub(crate) fn createWebm(&self, width: u16, height: u16, durationInSeconds: u64, webmUrl: Url, webmContentType: ContentType, headerGenerator: &mut HeaderGenerator, webmBody: Vec<u8>, result: &mut Vec<PipelineResponse>, max_age_in_seconds: u32) -> Result<(), CordialError>
 	{
 		const Incompressible: bool = false;
 		
-		let webmInputContentFilePath = inputContentFilePath.with_extension("webm");
-		let webmBody = ResponseBody::binary(webmInputContentFilePath.fileContentsAsBytes().context(webmInputContentFilePath)?);
 		let webmHeaders = headerGenerator.generateHeadersForAsset(Incompressible, max_age_in_seconds, self.isDownloadable(), &webmUrl)?;
 		let webmTags = hashmap!
 		{
 			ResourceTag::video_webm => Rc::new(UrlDataDetails::video(&webmBody, width, height, durationInSeconds))
 		};
-		result.push((webmUrl, webmTags, StatusCode::Ok, webm8ContentType(), webmHeaders, webmBody, None, Incompressible));
+		result.push((webmUrl, webmTags, StatusCode::Ok, webmContentType, webmHeaders, ResponseBody::binary(webmBody), None, Incompressible));
 		Ok(())
 	}
 	
@@ -176,7 +181,7 @@ impl AudioVideoMetaData
 	
 	//noinspection SpellCheckingInspection
 	#[inline(always)]
-	pub(crate) fn createAmpAudioNode(&self, resources: &Resources, configuration: &Configuration, languageData: &LanguageData, mp4Url: &Url, durationInSeconds: u64, volume: AudioVolume, placeHolderWidth: u16, placeHolderHeight: u16) -> Result<UnattachedNode, CordialError>
+	pub(crate) fn createAmpAudioNode(&self, resources: &Resources, configuration: &Configuration, languageData: &LanguageData, sources: &[(AudioInputFormat, Url)], durationInSeconds: u64, volume: AudioVolume, placeHolderWidth: u16, placeHolderHeight: u16) -> Result<UnattachedNode, CordialError>
 	{
 		let iso639Dash1Alpha2Language = languageData.iso639Dash1Alpha2Language;
 		
@@ -221,12 +226,14 @@ impl AudioVideoMetaData
 			ampAudioNode = ampAudioNode.with_empty_attribute("disableremoteplayback");
 		}
 		
-		if let Some(ref artist) = self.artist
+		let embeddedAudioTags = self.embeddedAudioTags.borrow();
+		
+		if let Some(artist) = self.artist.as_ref().or(embeddedAudioTags.artist.as_ref())
 		{
 			ampAudioNode = ampAudioNode.with_attribute("artist".str_attribute(artist));
 		}
 		
-		if let Some(ref album) = self.album
+		if let Some(album) = self.album.as_ref().or(embeddedAudioTags.album.as_ref())
 		{
 			ampAudioNode = ampAudioNode.with_attribute("album".str_attribute(album));
 		}
@@ -253,7 +260,7 @@ impl AudioVideoMetaData
 		.with_child_element
 		(
 			"noscript"
-			.with_child_element(self.createAudioNode(configuration, languageData, mp4Url, durationInSeconds, volume)?)
+			.with_child_element(self.createAudioNode(configuration, languageData, sources, durationInSeconds, volume)?)
 		)
 		.with_child_element(self.createAmpImgPlaceHolderUrlData(placeHolderUrlData.as_ref(), resources)?)
 		.with_child_element
@@ -263,11 +270,11 @@ impl AudioVideoMetaData
 			.with_child_text(languageData.requiredTranslation(RequiredTranslation::missing_audio_fallback)?.as_str())
 		);
 		
-		self.addSources(ampAudioNode, mp4Url, None, audioMp4TwitterContentType())
+		self.addAudioSources(ampAudioNode, sources)
 	}
 	
 	#[inline(always)]
-	pub(crate) fn createAudioNode(&self, configuration: &Configuration, languageData: &LanguageData, mp4Url: &Url, durationInSeconds: u64, volume: AudioVolume) -> Result<UnattachedNode, CordialError>
+	pub(crate) fn createAudioNode(&self, configuration: &Configuration, languageData: &LanguageData, sources: &[(AudioInputFormat, Url)], durationInSeconds: u64, volume: AudioVolume) -> Result<UnattachedNode, CordialError>
 	{
 		let iso639Dash1Alpha2Language = languageData.iso639Dash1Alpha2Language;
 		
@@ -308,7 +315,7 @@ impl AudioVideoMetaData
 			}
 		}
 		
-		audioNode = self.addSources(audioNode, mp4Url, None, audioMp4TwitterContentType())?;
+		audioNode = self.addAudioSources(audioNode, sources)?;
 		
 		let translation = languageData.requiredTranslation(RequiredTranslation::your_browser_does_not_support_audio)?;
 		audioNode = audioNode.with_child_text(translation.deref().as_str());
@@ -348,7 +355,7 @@ impl AudioVideoMetaData
 	
 	//noinspection SpellCheckingInspection
 	#[inline(always)]
-	pub(crate) fn createAmpVideoNode(&self, resources: &Resources, configuration: &Configuration, languageData: &LanguageData, width: u16, height: u16, mp4Url: &Url, webmUrl: &Url, durationInSeconds: u64, plays_inline: bool) -> Result<UnattachedNode, CordialError>
+	pub(crate) fn createAmpVideoNode(&self, resources: &Resources, configuration: &Configuration, languageData: &LanguageData, width: u16, height: u16, mp4Url: &Url, mp4ContentType: &ContentType, webmUrl: &Url, webmContentType: &ContentType, durationInSeconds: u64, plays_inline: bool) -> Result<UnattachedNode, CordialError>
 	{
 		let iso639Dash1Alpha2Language = languageData.iso639Dash1Alpha2Language;
 		
@@ -423,7 +430,7 @@ impl AudioVideoMetaData
 			.with_child_element
 			(
 				"noscript"
-				.with_child_element(self.createVideoNode(resources, configuration, languageData, width, height, mp4Url, webmUrl, durationInSeconds, plays_inline)?)
+				.with_child_element(self.createVideoNode(resources, configuration, languageData, width, height, mp4Url, mp4ContentType, webmUrl, webmContentType, durationInSeconds, plays_inline)?)
 			)
 			.with_child_element(self.createAmpImgPlaceHolderUrlData(&placeHolderUrlData, resources)?)
 			.with_child_element
@@ -433,12 +440,12 @@ impl AudioVideoMetaData
 				.with_child_text(languageData.requiredTranslation(RequiredTranslation::missing_video_fallback)?.as_str())
 			);
 		
-		self.addVideoSourcesAndTracks(ampVideoNode, mp4Url, webmUrl, iso639Dash1Alpha2Language, configuration)
+		self.addVideoSourcesAndTracks(ampVideoNode, mp4Url, mp4ContentType, webmUrl, webmContentType, iso639Dash1Alpha2Language, configuration)
 	}
 	
 	//noinspection SpellCheckingInspection
 	#[inline(always)]
-	pub(crate) fn createVideoNode(&self, resources: &Resources, configuration: &Configuration, languageData: &LanguageData, width: u16, height: u16, mp4Url: &Url, webmUrl: &Url, durationInSeconds: u64, plays_inline: bool) -> Result<UnattachedNode, CordialError>
+	pub(crate) fn createVideoNode(&self, resources: &Resources, configuration: &Configuration, languageData: &LanguageData, width: u16, height: u16, mp4Url: &Url, mp4ContentType: &ContentType, webmUrl: &Url, webmContentType: &ContentType, durationInSeconds: u64, plays_inline: bool) -> Result<UnattachedNode, CordialError>
 	{
 		let iso639Dash1Alpha2Language = languageData.iso639Dash1Alpha2Language;
 		
@@ -488,7 +495,7 @@ impl AudioVideoMetaData
 			}
 		}
 		
-		videoNode = self.addVideoSourcesAndTracks(videoNode, mp4Url, webmUrl, iso639Dash1Alpha2Language, configuration)?;
+		videoNode = self.addVideoSourcesAndTracks(videoNode, mp4Url, mp4ContentType, webmUrl, webmContentType, iso639Dash1Alpha2Language, configuration)?;
 		
 		let translation = languageData.requiredTranslation(RequiredTranslation::your_browser_does_not_support_video)?;
 		videoNode = videoNode.with_child_text(translation.deref().as_str());
@@ -496,13 +503,35 @@ impl AudioVideoMetaData
 		Ok(videoNode)
 	}
 	
-	fn addVideoSourcesAndTracks(&self, videoNode: UnattachedNode, mp4Url: &Url, webmUrl: &Url, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, configuration: &Configuration) -> Result<UnattachedNode, CordialError>
+	fn addVideoSourcesAndTracks(&self, videoNode: UnattachedNode, mp4Url: &Url, mp4ContentType: &ContentType, webmUrl: &Url, webmContentType: &ContentType, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, configuration: &Configuration) -> Result<UnattachedNode, CordialError>
 	{
-		let videoNode = self.addSources(videoNode, mp4Url, Some(webmUrl), videoMp4TwitterContentType())?;
+		let videoNode = self.addSources(videoNode, vec![(webmContentType.clone(), webmUrl), (mp4ContentType.clone(), mp4Url)].into_iter())?;
 		self.addTracks(videoNode, iso639Dash1Alpha2Language, configuration)
 	}
 	
-	fn addSources(&self, mut audioVideoNode: UnattachedNode, mp4Url: &Url, webmUrl: Option<&Url>, mp4Type: ContentType) -> Result<UnattachedNode, CordialError>
+	fn addAudioSources(&self, audioNode: UnattachedNode, sources: &[(AudioInputFormat, Url)]) -> Result<UnattachedNode, CordialError>
+	{
+		self.addSources(audioNode, sources.iter().map(|&(audioInputFormat, ref url)| (audioInputFormat.contentType(), url)))
+	}
+	
+	fn addSources<'a, I: Iterator<Item=(ContentType, &'a Url)>>(&self, mut audioVideoNode: UnattachedNode, sources: I) -> Result<UnattachedNode, CordialError>
+	{
+		let mediaTimeFragment = self.mediaTimeFragment()?;
+		
+		for (contentType, url) in sources
+		{
+			audioVideoNode = audioVideoNode.with_child_element
+			(
+				"source"
+				.with_type_attribute(contentType.0.as_ref())
+				.with_attribute("src".string_attribute(format!("{}{}", url.as_ref(), &mediaTimeFragment)))
+			);
+		}
+		
+		Ok(audioVideoNode)
+	}
+	
+	fn mediaTimeFragment(&self) -> Result<String, CordialError>
 	{
 		let mediaTimeFragment = match self.ends_at_seconds_exclusive
 		{
@@ -530,25 +559,7 @@ impl AudioVideoMetaData
 				}
 			},
 		};
-		
-		if let Some(webmUrl) = webmUrl
-		{
-			audioVideoNode = audioVideoNode.with_child_element
-			(
-				"source"
-				.with_type_attribute(webm8ContentType().0.as_ref())
-				.with_attribute("src".string_attribute(format!("{}{}", webmUrl.as_ref(), &mediaTimeFragment)))
-			);
-		}
-		
-		audioVideoNode = audioVideoNode.with_child_element
-		(
-			"source"
-			.with_type_attribute(mp4Type.0.as_ref())
-			.with_attribute("src".string_attribute(format!("{}{}", mp4Url.as_ref(), &mediaTimeFragment)))
-		);
-		
-		Ok(audioVideoNode)
+		Ok(mediaTimeFragment)
 	}
 	
 	fn addTracks(&self, videoNode: UnattachedNode, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, configuration: &Configuration) -> Result<UnattachedNode, CordialError>
@@ -900,6 +911,10 @@ impl AudioVideoMetaData
 			{
 				"video"
 			}
+			else if podcastUrlData.mimeType().type_() == AUDIO
+			{
+				"audio"
+			}
 			else
 			{
 				"image"
@@ -914,6 +929,8 @@ impl AudioVideoMetaData
 				None
 			};
 			
+			let bitRateAttribute = podcastUrlData.optionalAudioBitRateInKilobitsPerSecond().map(|bitRateInKilobitsPerSecond| "bitrate".xml_u64_attribute(bitRateInKilobitsPerSecond));
+			
 			let fileSizeAttribute = "fileSize".xml_u64_attribute(podcastUrlData.size());
 			let durationAttribute = "duration".xml_u64_attribute(podcastUrlData.durationInSeconds()?);
 			let mut contentAttributes = vec!
@@ -942,6 +959,11 @@ impl AudioVideoMetaData
 				contentAttributes.push(heightAttribute.borrow());
 			}
 			
+			if let Some(ref bitRateAttribute) = bitRateAttribute
+			{
+				contentAttributes.push(bitRateAttribute.borrow());
+			}
+			
 			self.writeVideoMRssMediaContent(eventWriter, namespace, emptyAttributes, resources, fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language, iFrameUrl, contentAttributes)?;
 		}
 		
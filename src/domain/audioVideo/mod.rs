@@ -6,8 +6,12 @@ use super::*;
 use super::googleplay::*;
 use super::itunes::*;
 use super::super::webserver::headers::X_Robots_Tag_Data;
+use ::std::io::Cursor;
+use ::std::str::from_utf8;
 
 
+include!("AudioDetails.rs");
+include!("AudioTags.rs");
 include!("AudioVideoAbstract.rs");
 include!("AudioVideoCountryRestriction.rs");
 include!("AudioVideoDisabledControl.rs");
@@ -19,3 +23,6 @@ include!("AudioVideoStarRating.rs");
 include!("AudioVideoTrack.rs");
 include!("AudioVideoTrackKind.rs");
 include!("AudioVolume.rs");
+
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,223 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+use super::*;
+use ::ogg::PacketWriteEndInfo;
+use ::ogg::PacketWriter;
+
+
+const Serial: u32 = 0x636F7264;
+
+fn u32LittleEndian(value: u32) -> Vec<u8>
+{
+	vec![value as u8, (value >> 8) as u8, (value >> 16) as u8, (value >> 24) as u8]
+}
+
+fn vorbisComments(vendor: &str, comments: &[&str]) -> Vec<u8>
+{
+	let mut data = u32LittleEndian(vendor.len() as u32);
+	data.extend_from_slice(vendor.as_bytes());
+	data.extend(u32LittleEndian(comments.len() as u32));
+	for comment in comments.iter()
+	{
+		data.extend(u32LittleEndian(comment.len() as u32));
+		data.extend_from_slice(comment.as_bytes());
+	}
+	data
+}
+
+fn vorbisIdentificationHeader(sampleRate: u32, nominalBitRate: u32) -> Vec<u8>
+{
+	let mut header = b"\x01vorbis".to_vec();
+	header.extend(u32LittleEndian(0));
+	header.push(2);
+	header.extend(u32LittleEndian(sampleRate));
+	header.extend(u32LittleEndian(0));
+	header.extend(u32LittleEndian(nominalBitRate));
+	header.extend(u32LittleEndian(0));
+	header.push(0xB8);
+	header.push(0x01);
+	header
+}
+
+fn vorbisCommentHeader(comments: &[&str]) -> Vec<u8>
+{
+	let mut header = b"\x03vorbis".to_vec();
+	header.extend(vorbisComments("cordial", comments));
+	header.push(0x01);
+	header
+}
+
+fn opusIdentificationHeader(preSkip: u16) -> Vec<u8>
+{
+	let mut header = b"OpusHead".to_vec();
+	header.push(1);
+	header.push(2);
+	header.push(preSkip as u8);
+	header.push((preSkip >> 8) as u8);
+	header.extend(u32LittleEndian(44_100));
+	header.push(0);
+	header.push(0);
+	header.push(0);
+	header
+}
+
+fn opusCommentHeader(comments: &[&str]) -> Vec<u8>
+{
+	let mut header = b"OpusTags".to_vec();
+	header.extend(vorbisComments("cordial", comments));
+	header
+}
+
+/// Each header packet is on a page of its own, as Vorbis and Opus require.
+fn ogg(packets: Vec<(Vec<u8>, u64)>) -> Vec<u8>
+{
+	let mut bytes = Vec::new();
+	{
+		let mut packetWriter = PacketWriter::new(&mut bytes);
+		let lastIndex = packets.len() - 1;
+		for (index, (packet, granulePosition)) in packets.into_iter().enumerate()
+		{
+			let endInfo = if index == lastIndex
+			{
+				PacketWriteEndInfo::EndStream
+			}
+			else
+			{
+				PacketWriteEndInfo::EndPage
+			};
+			packetWriter.write_packet(packet.into_boxed_slice(), Serial, endInfo, granulePosition).unwrap();
+		}
+	}
+	bytes
+}
+
+fn flacMetadataBlock(isLast: bool, blockType: u8, data: Vec<u8>) -> Vec<u8>
+{
+	let length = data.len() as u32;
+	let mut block = vec![if isLast { 0x80 | blockType } else { blockType }, (length >> 16) as u8, (length >> 8) as u8, length as u8];
+	block.extend(data);
+	block
+}
+
+fn flacStreamInfo(sampleRate: u32, samples: u64) -> Vec<u8>
+{
+	const Channels: u8 = 2;
+	const BitsPerSample: u8 = 16;
+	
+	let mut streamInfo = vec![0x10, 0x00, 0x10, 0x00, 0, 0, 0, 0, 0, 0];
+	streamInfo.push((sampleRate >> 12) as u8);
+	streamInfo.push((sampleRate >> 4) as u8);
+	streamInfo.push(((sampleRate as u8) << 4) | ((Channels - 1) << 1) | ((BitsPerSample - 1) >> 4));
+	streamInfo.push(((BitsPerSample - 1) << 4) | ((samples >> 32) as u8 & 0x0F));
+	streamInfo.extend_from_slice(&[(samples >> 24) as u8, (samples >> 16) as u8, (samples >> 8) as u8, samples as u8]);
+	streamInfo.extend_from_slice(&[0; 16]);
+	streamInfo
+}
+
+fn flac(sampleRate: u32, samples: u64, comments: &[&str]) -> Vec<u8>
+{
+	let mut bytes = b"fLaC".to_vec();
+	bytes.extend(flacMetadataBlock(false, 0, flacStreamInfo(sampleRate, samples)));
+	bytes.extend(flacMetadataBlock(true, 4, vorbisComments("cordial", comments)));
+	bytes
+}
+
+fn path() -> &'static Path
+{
+	Path::new("test.ogg")
+}
+
+#[test]
+fn vorbisCommentNamesAreCaseInsensitiveAndTheFirstValueIsKept()
+{
+	let tags = AudioTags::parseVorbisComments(&vorbisComments("cordial", &["title=First", "TITLE=Second", "Artist=Someone", "ALBUM=", "GENRE=Ignored"]));
+	
+	assert_eq!(tags.title, Some("First".to_owned()));
+	assert_eq!(tags.artist, Some("Someone".to_owned()));
+	assert_eq!(tags.album, None);
+}
+
+#[test]
+fn truncatedVorbisCommentsAreIgnored()
+{
+	let mut data = vorbisComments("cordial", &["TITLE=Kept", "ARTIST=Truncated"]);
+	let length = data.len();
+	data.truncate(length - 3);
+	
+	let tags = AudioTags::parseVorbisComments(&data);
+	
+	assert_eq!(tags.title, Some("Kept".to_owned()));
+	assert_eq!(tags.artist, None);
+}
+
+#[test]
+fn vorbisDurationComesFromTheLastGranulePositionAndBitRateFromTheIdentificationHeader()
+{
+	let bytes = ogg(vec![(vorbisIdentificationHeader(44_100, 128_000), 0), (vorbisCommentHeader(&["TITLE=Song"]), 0), (vec![0; 16], 44_100 * 3)]);
+	
+	let audioDetails = AudioDetails::ogg(&bytes, AudioCodec::vorbis, path()).unwrap();
+	
+	assert_eq!(audioDetails.durationInSeconds, 3);
+	assert_eq!(audioDetails.bitRateInBitsPerSecond, 128_000);
+	assert_eq!(audioDetails.tags.title, Some("Song".to_owned()));
+}
+
+#[test]
+fn vorbisWithAZeroSampleRateIsAnError()
+{
+	let bytes = ogg(vec![(vorbisIdentificationHeader(0, 128_000), 0), (vorbisCommentHeader(&[]), 0), (vec![0; 16], 44_100)]);
+	
+	assert!(AudioDetails::ogg(&bytes, AudioCodec::vorbis, path()).is_err());
+}
+
+#[test]
+fn opusDurationExcludesThePreSkipAndBitRateIsAnAverage()
+{
+	const PreSkip: u16 = 312;
+	let bytes = ogg(vec![(opusIdentificationHeader(PreSkip), 0), (opusCommentHeader(&["ARTIST=Someone"]), 0), (vec![0; 16], 48_000 * 2 + PreSkip as u64)]);
+	
+	let audioDetails = AudioDetails::ogg(&bytes, AudioCodec::opus, path()).unwrap();
+	
+	assert_eq!(audioDetails.durationInSeconds, 2);
+	assert_eq!(audioDetails.bitRateInBitsPerSecond, (bytes.len() as u64) * 8 / 2);
+	assert_eq!(audioDetails.tags.artist, Some("Someone".to_owned()));
+}
+
+#[test]
+fn headersOfAnotherCodecAreAnError()
+{
+	let bytes = ogg(vec![(vorbisIdentificationHeader(44_100, 128_000), 0), (vorbisCommentHeader(&[]), 0), (vec![0; 16], 44_100)]);
+	
+	assert!(AudioDetails::ogg(&bytes, AudioCodec::opus, path()).is_err());
+}
+
+#[test]
+fn missingCommentHeaderIsAnError()
+{
+	let bytes = ogg(vec![(opusIdentificationHeader(0), 0)]);
+	
+	assert!(AudioDetails::ogg(&bytes, AudioCodec::opus, path()).is_err());
+}
+
+#[test]
+fn flacDurationComesFromTheStreamInfoAndTagsFromTheVorbisComment()
+{
+	let bytes = flac(44_100, 44_100 * 4, &["TITLE=Song", "ALBUM=Record"]);
+	
+	let audioDetails = AudioDetails::flac(&bytes, path()).unwrap();
+	
+	assert_eq!(audioDetails.durationInSeconds, 4);
+	assert_eq!(audioDetails.bitRateInBitsPerSecond, (bytes.len() as u64) * 8 / 4);
+	assert_eq!(audioDetails.tags.title, Some("Song".to_owned()));
+	assert_eq!(audioDetails.tags.album, Some("Record".to_owned()));
+}
+
+#[test]
+fn flacWithoutItsStreamMarkerIsAnError()
+{
+	let bytes = ogg(vec![(vorbisIdentificationHeader(44_100, 128_000), 0), (vorbisCommentHeader(&[]), 0), (vec![0; 16], 44_100)]);
+	
+	assert!(AudioDetails::flac(&bytes, path()).is_err());
+}
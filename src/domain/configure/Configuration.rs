@@ -24,9 +24,11 @@ pub(crate) struct Configuration
 	#[serde(default, skip_deserializing)] pub(crate) deploymentVersion: String,
 	#[serde(default, skip_deserializing)] pub(crate) luaFolderPath: Arc<PathBuf>,
 	#[serde(default, skip)] pub(crate) sassImportPaths: Vec<PathBuf>,
+	#[serde(default, skip)] pub(crate) generatedSassImportsFolderPath: PathBuf,
 	#[serde(default, skip)] pub(crate) sassFunctions: Rc<SassFunctionList>,
 	#[serde(default, skip)] pub(crate) sassImporters: Rc<SassImporterList>,
 	#[serde(default, skip)] pub(crate) sassHeaders: Rc<SassImporterList>,
+	#[serde(default, skip)] svgSpriteUrls: RefCell<HashMap<Iso639Dash1Alpha2Language, Rc<HashMap<String, String>>>>,
 }
 
 impl Default for Configuration
@@ -54,9 +56,11 @@ impl Default for Configuration
 			deploymentVersion: String::default(),
 			luaFolderPath: Default::default(),
 			sassImportPaths: Default::default(),
+			generatedSassImportsFolderPath: Default::default(),
 			sassFunctions: Default::default(),
 			sassImporters: Default::default(),
 			sassHeaders: Default::default(),
+			svgSpriteUrls: Default::default(),
 		}
 	}
 }
@@ -93,6 +97,36 @@ impl Configuration
 		self.localization.fallbackIso639Dash1Alpha2Language()
 	}
 	
+	/// Versioned URLs of SVG sprites by resource URL, for the `svg_symbol` handlebars helper.
+	/// Computed once for each language, as SVG sprites are always rendered before the HTML pages that use them.
+	pub(crate) fn svgSpriteUrls(&self, resources: &Resources, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Rc<HashMap<String, String>>, CordialError>
+	{
+		let mut svgSpriteUrlsByLanguage = self.svgSpriteUrls.try_borrow_mut()?;
+		if let Some(svgSpriteUrls) = svgSpriteUrlsByLanguage.get(&iso639Dash1Alpha2Language)
+		{
+			return Ok(svgSpriteUrls.clone());
+		}
+		
+		let mut svgSpriteUrls = HashMap::new();
+		for (resourceUrl, resource) in resources.iter()
+		{
+			if let Ok(resource) = resource.try_borrow()
+			{
+				if resource.isSvgSprite()
+				{
+					if let Some(urlData) = resource.urlData(self.fallbackIso639Dash1Alpha2Language(), Some(iso639Dash1Alpha2Language), &ResourceTag::default)
+					{
+						svgSpriteUrls.insert(resourceUrl.to_string(), urlData.url_str().to_owned());
+					}
+				}
+			}
+		}
+		
+		let svgSpriteUrls = Rc::new(svgSpriteUrls);
+		svgSpriteUrlsByLanguage.insert(iso639Dash1Alpha2Language, svgSpriteUrls.clone());
+		Ok(svgSpriteUrls)
+	}
+	
 	#[inline(always)]
 	pub(crate) fn sassOptions<'p>(&'p self, precision: u8, input_syntax: InputSyntax) -> SassOptions<'p, PathBuf>
 	{
@@ -211,6 +245,16 @@ impl Configuration
 			
 			self.renderResources(&mut newResponses, oldResponses, &handlebars, &resources, &mut rssChannelsByLanguage, &mut siteMapWebPages)?;
 			
+			// Fonts are subset before the HTML pages that use them are rendered; if the rendered pages use characters a subset lacks, everything is rendered again, as stylesheets and pages use the fonts' versioned URLs.
+			if self.fontSubsetsLackRenderedCharacters(&resources)?
+			{
+				newResponses = Responses::new(self.deploymentDate, &serverHostNames);
+				rssChannelsByLanguage = self.rssChannelsByLanguage();
+				siteMapWebPages = self.languagesHashMap();
+				
+				self.renderResources(&mut newResponses, oldResponses, &handlebars, &resources, &mut rssChannelsByLanguage, &mut siteMapWebPages)?;
+			}
+			
 			self.renderRssFeeds(&mut newResponses, oldResponses, &handlebars, &resources, &rssChannelsByLanguage)?;
 			
 			self.renderSiteMapsAndRobotsTxt(&mut newResponses, oldResponses, &handlebars, &resources, &siteMapWebPages)?;
@@ -254,6 +298,8 @@ impl Configuration
 	#[inline(always)]
 	fn renderResources(&self, newResponses: &mut Responses, oldResponses: &Arc<Responses>, handlebars: &HandlebarsWrapper, resources: &Resources, rssChannelsByLanguage: &mut HashMap<Iso639Dash1Alpha2Language, HashMap<Rc<RssChannelName>, Vec<RssItem>>>, siteMapWebPages: &mut HashMap<Iso639Dash1Alpha2Language, Vec<SiteMapWebPage>>) -> Result<(), CordialError>
 	{
+		Resource::validateImageOverlaysAreAcyclic(resources)?;
+		
 		for processingPriority in ProcessingPriority::All.iter()
 		{
 			for (resourceUrl, resource) in resources.iter()
@@ -268,6 +314,19 @@ impl Configuration
 		Ok(())
 	}
 	
+	#[inline(always)]
+	fn fontSubsetsLackRenderedCharacters(&self, resources: &Resources) -> Result<bool, CordialError>
+	{
+		for resource in resources.values()
+		{
+			if resource.try_borrow()?.fontSubsetLacksRenderedCharacters(resources, self)?
+			{
+				return Ok(true);
+			}
+		}
+		Ok(false)
+	}
+	
 	#[inline(always)]
 	fn renderRssFeeds(&self, newResponses: &mut Responses, oldResponses: &Arc<Responses>, handlebars: &HandlebarsWrapper, resources: &Resources, rssChannelsByLanguage: &HashMap<Iso639Dash1Alpha2Language, HashMap<Rc<RssChannelName>, Vec<RssItem>>>) -> Result<(), CordialError>
 	{
@@ -360,6 +419,10 @@ impl Configuration
 		}
 		configuration.sassImportPaths = findSassImportPaths(inputFolderPath)?;
 		
+		// Populated by resources such as sprites which are processed before stylesheets
+		configuration.generatedSassImportsFolderPath = outputFolderPath.recreateSubFolder("sass-imports").context(outputFolderPath)?;
+		configuration.sassImportPaths.push(configuration.generatedSassImportsFolderPath.clone());
+		
 		Ok(configuration)
 	}
 	
@@ -65,6 +65,7 @@ impl DiscoverResources
 		let rootResourceFilePath = inputFolderPath.join("root.resource.hjson");
 		let configurationHjson = loadHjsonIfExtantAndMerge(&rootResourceFilePath, self.resourceTemplates.resourceTemplate.clone())?;
 		let mut resource: Resource = deserializeHjson(configurationHjson)?;
+		resource.validateConfiguration()?;
 		let resourceUrl = resource.finishInitialization(Vec::new(), "root", inputFolderPath.to_path_buf());
 		self.insertResource(resourceUrl, resource);
 		Ok(())
@@ -97,6 +98,7 @@ impl DiscoverResources
 			};
 			
 			let mut resource: Resource = deserializeHjson(hjsonConfiguration)?;
+			resource.validateConfiguration()?;
 			
 			let resourceUrl = resource.finishInitialization(parentHierarchy.clone(), resourceInputName, filePath.parent().unwrap().to_path_buf());
 			
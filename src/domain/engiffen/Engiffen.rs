@@ -9,15 +9,18 @@ pub(crate) struct Engiffen<'a>
 	engiffenSources: &'a [EngiffenSource],
 	quantizer: Quantizer,
 	loops: EngiffenLoops,
+	animatedWebP: Option<(u8, bool)>,
 	inputFormat: Option<ImageInputFormat>,
 	resourceRelativeUrlWithoutFileNameExtension: &'a str,
 	languageData: &'a LanguageData<'a>,
+	resources: &'a Resources,
+	configuration: &'a Configuration,
 }
 
 impl<'a> Engiffen<'a>
 {
 	#[inline(always)]
-	pub(crate) fn new(inputContentFolderPath: &'a Path, engiffenSources: &'a [EngiffenSource], quantizer: &EngiffenQuantizer, loops: EngiffenLoops, inputFormat: Option<ImageInputFormat>, resourceUrl: &'a ResourceUrl, languageData: &'a LanguageData) -> Result<Self, CordialError>
+	pub(crate) fn new(inputContentFolderPath: &'a Path, engiffenSources: &'a [EngiffenSource], quantizer: &EngiffenQuantizer, loops: EngiffenLoops, animatedWebP: Option<(u8, bool)>, inputFormat: Option<ImageInputFormat>, resourceUrl: &'a ResourceUrl, languageData: &'a LanguageData, resources: &'a Resources, configuration: &'a Configuration) -> Result<Self, CordialError>
 	{
 		if engiffenSources.is_empty()
 		{
@@ -33,9 +36,12 @@ impl<'a> Engiffen<'a>
 					engiffenSources,
 					quantizer: quantizer.toQuantizer(),
 					loops,
+					animatedWebP,
 					inputFormat,
 					resourceRelativeUrlWithoutFileNameExtension: resourceUrl.withoutFileNameExtension(),
 					languageData,
+					resources,
+					configuration,
 				}
 			)
 		}
@@ -43,12 +49,24 @@ impl<'a> Engiffen<'a>
 	
 	/// Outputs (Url, Width) pairs
 	/// Use as say (note commas MATTER): srcset="/url/elva-fairy-320w.gif 320w, /url/elva-fairy-480w.gif 480w, /url/elva-fairy.gif 800w, /url/elva-fairy-1000w.gif 1000w"
+	/// Any animated WebP equivalents are added as an alternative format.
 	#[inline(always)]
-	pub(crate) fn processedImageSourceSet(&self, imageSourceSet: &mut ProcessedImageSourceSet) -> Result<(), CordialError>
+	pub(crate) fn processedImageSourceSet(&self, processedImageSourceSet: &mut ProcessedImageSourceSet) -> Result<(), CordialError>
 	{
-		let imageSourceSet = &mut imageSourceSet.2;
+		processedImageSourceSet.imageSourceSet = self.imageSourceSet(Self::GifFileExtension)?;
 		
-		imageSourceSet.reserve_exact(self.engiffenSources.len());
+		if self.animatedWebP.is_some()
+		{
+			processedImageSourceSet.alternativeImageSourceSets = vec![("image/webp", self.imageSourceSet(Self::WebPFileExtension)?)];
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn imageSourceSet(&self, fileExtension: &str) -> Result<Vec<(Url, u32)>, CordialError>
+	{
+		let mut imageSourceSet = Vec::with_capacity(self.engiffenSources.len());
 		
 		let mut isAfterFirst = false;
 		for engiffenSource in self.engiffenSources
@@ -56,17 +74,17 @@ impl<'a> Engiffen<'a>
 			let width = engiffenSource.width as u32;
 			let url = if isAfterFirst
 			{
-				ResourceUrl::widthUrl(self.resourceRelativeUrlWithoutFileNameExtension, Self::GifFileExtension, self.languageData, width)?
+				ResourceUrl::widthUrl(self.resourceRelativeUrlWithoutFileNameExtension, fileExtension, self.languageData, width)?
 			}
 			else
 			{
 				isAfterFirst = true;
-				ResourceUrl::primaryUrl(self.resourceRelativeUrlWithoutFileNameExtension, Self::GifFileExtension, self.languageData)?
+				ResourceUrl::primaryUrl(self.resourceRelativeUrlWithoutFileNameExtension, fileExtension, self.languageData)?
 			};
 			imageSourceSet.push((url, width));
 		}
 		
-		Ok(())
+		Ok(imageSourceSet)
 	}
 	
 	#[inline(always)]
@@ -91,14 +109,14 @@ impl<'a> Engiffen<'a>
 				{
 					Some(ref engiffenSource) =>
 					{
-						engiffenSource.transform(mutableBorrowOfSourceSets, 0, imageIndex, &mut image, &mut frameWidthBySourceSet, &mut frameHeightBySourceSet)?;
+						engiffenSource.transform(mutableBorrowOfSourceSets, 0, imageIndex, &mut image, &mut frameWidthBySourceSet, &mut frameHeightBySourceSet, self.resources, self.configuration, self.languageData.iso639Dash1Alpha2Language)?;
 					}
 					None =>
 					{
 						let mut sourceSetIndex = 0;
 						for engiffenSource in self.engiffenSources.iter()
 						{
-							engiffenSource.transform(mutableBorrowOfSourceSets, sourceSetIndex, imageIndex, &mut image, &mut frameWidthBySourceSet, &mut frameHeightBySourceSet)?;
+							engiffenSource.transform(mutableBorrowOfSourceSets, sourceSetIndex, imageIndex, &mut image, &mut frameWidthBySourceSet, &mut frameHeightBySourceSet, self.resources, self.configuration, self.languageData.iso639Dash1Alpha2Language)?;
 							sourceSetIndex += 1;
 						}
 					}
@@ -111,6 +129,10 @@ impl<'a> Engiffen<'a>
 		for sourceSetIndex in 0 .. length
 		{
 			result.push(self.createGifResource(&sourceSets, &frameWidthBySourceSet, &frameHeightBySourceSet, &mut headerGenerator, sourceSetIndex)?);
+			if let Some((quality, lossless)) = self.animatedWebP
+			{
+				result.push(self.createWebPResource(&sourceSets, &frameWidthBySourceSet, &frameHeightBySourceSet, &mut headerGenerator, sourceSetIndex, quality, lossless)?);
+			}
 			result.push(self.createPlaceholderResource(&sourceSets, &frameWidthBySourceSet, &frameHeightBySourceSet, &mut headerGenerator, sourceSetIndex)?);
 		}
 		Ok(result)
@@ -135,6 +157,7 @@ impl<'a> Engiffen<'a>
 				width,
 				height,
 				size: body.len() as u64,
+				placeholder: None,
 			}
 		);
 		
@@ -160,6 +183,57 @@ impl<'a> Engiffen<'a>
 		Ok((url, resourceTags, StatusCode::Ok, content_type_image_gif(), headers, ResponseBody::binary(body), None, false))
 	}
 	
+	#[inline(always)]
+	fn createWebPResource<'b, HeaderGenerator: for<'r> FnMut(&'r Url) -> Result<Vec<(String, String)>, CordialError>>(&self, sourceSets: &SourceSets<'b>, frameWidthBySourceSet: &HashMap<usize, u16>, frameHeightBySourceSet: &HashMap<usize, u16>, headerGenerator: &mut HeaderGenerator, sourceSetIndex: usize, quality: u8, lossless: bool) -> Result<(Url, HashMap<ResourceTag, Rc<UrlDataDetails>>, StatusCode, ContentType, ResponseHeaders, ResponseBody, Option<(ResponseHeaders, Utf8Body)>, bool), CordialError>
+	{
+		let &(ref engiffenImages, ref engiffenFrames) = sourceSets.get(sourceSetIndex).unwrap();
+		
+		let width = Self::frameDimension(&frameWidthBySourceSet, sourceSetIndex);
+		let height = Self::frameDimension(&frameHeightBySourceSet, sourceSetIndex);
+		
+		let webPFrames: Vec<_> = engiffenImages.iter().zip(engiffenFrames.iter()).map(|(engiffenImage, engiffenFrame)| engiffenFrame.toWebPFrame(engiffenImage)).collect();
+		let loopCount = if webPFrames.len() > 1
+		{
+			self.loops.toWebPLoopCount()
+		}
+		else
+		{
+			1
+		};
+		
+		let body = match encodeAnimatedWebP(width, height, &webPFrames, loopCount, min(quality, 100), lossless)
+		{
+			None => return Err(CordialError::CouldNotEncodeWebP(self.inputContentFolderPath.to_path_buf())),
+			Some(body) => body,
+		};
+		
+		let resourceTags = hashmap!
+		{
+			ResourceTag::webp_image(width) => Rc::new
+			(
+				UrlDataDetails::Image
+				{
+					width,
+					height,
+					size: body.len() as u64,
+					placeholder: None,
+				}
+			),
+		};
+		
+		let url = if sourceSetIndex == 0
+		{
+			ResourceUrl::primaryUrl(self.resourceRelativeUrlWithoutFileNameExtension, Self::WebPFileExtension, self.languageData)?
+		}
+		else
+		{
+			ResourceUrl::widthUrl(self.resourceRelativeUrlWithoutFileNameExtension, Self::WebPFileExtension, self.languageData, width)?
+		};
+		
+		let headers = headerGenerator(&url)?;
+		Ok((url, resourceTags, StatusCode::Ok, content_type_image_webp(), headers, ResponseBody::binary(body), None, false))
+	}
+	
 	#[inline(always)]
 	fn createPlaceholderResource<'b, HeaderGenerator: for<'r> FnMut(&'r Url) -> Result<Vec<(String, String)>, CordialError>>(&self, sourceSets: &SourceSets<'b>, frameWidthBySourceSet: &HashMap<usize, u16>, frameHeightBySourceSet: &HashMap<usize, u16>, headerGenerator: &mut HeaderGenerator, sourceSetIndex: usize) -> Result<(Url, HashMap<ResourceTag, Rc<UrlDataDetails>>, StatusCode, ContentType, ResponseHeaders, ResponseBody, Option<(ResponseHeaders, Utf8Body)>, bool), CordialError>
 	{
@@ -179,6 +253,7 @@ impl<'a> Engiffen<'a>
 					width,
 					height,
 					size: body.len() as u64,
+					placeholder: None,
 				}
 			),
 		};
@@ -215,6 +290,8 @@ impl<'a> Engiffen<'a>
 	
 	const GifFileExtension: &'static str = ".gif";
 	
+	const WebPFileExtension: &'static str = ".webp";
+	
 	#[inline(always)]
 	fn sourceImages(&self) -> Result<EngiffenSourceImages, CordialError>
 	{
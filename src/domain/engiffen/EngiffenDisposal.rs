@@ -37,4 +37,11 @@ impl EngiffenDisposal
 			restore_to_previous_frame => Previous,
 		}
 	}
+	
+	/// WebP has no equivalent of restoring to the previous frame.
+	#[inline(always)]
+	fn isDisposeToBackgroundForWebP(&self) -> bool
+	{
+		*self == EngiffenDisposal::restore_to_background_color
+	}
 }
@@ -36,9 +36,9 @@ impl Default for EngiffenFrame
 impl EngiffenFrame
 {
 	#[inline(always)]
-	fn transform(&self, image: &mut EngiffenSourceImage) -> Result<Option<EngiffenSourceImage>, CordialError>
+	fn transform(&self, image: &mut EngiffenSourceImage, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<EngiffenSourceImage>, CordialError>
 	{
-		ImageTransformation::applyTransformations(image, &self.transformations[..])
+		ImageTransformation::applyTransformations(image, &self.transformations[..], &mut FocalPoint::default(), resources, configuration, iso639Dash1Alpha2Language)
 	}
 	
 	#[inline(always)]
@@ -58,6 +58,19 @@ impl EngiffenFrame
 		frame.left = self.left_offset;
 	}
 	
+	#[inline(always)]
+	fn toWebPFrame(&self, image: &EngiffenOutputImage) -> (::image::DynamicImage, u32, u32, u32, bool)
+	{
+		let mut rawPixels = Vec::with_capacity(image.pixels.len() * 4);
+		for pixel in image.pixels.iter()
+		{
+			rawPixels.extend_from_slice(&pixel[..]);
+		}
+		let image = ::image::DynamicImage::ImageRgba8(::image::RgbaImage::from_raw(image.width, image.height, rawPixels).expect("pixels are always width x height"));
+		
+		(image, self.left_offset as u32, self.top_offset as u32, (self.frame_delay_in_tens_of_milliseconds as u32) * 10, self.frame_disposal.isDisposeToBackgroundForWebP())
+	}
+	
 	#[inline(always)]
 	fn frame_delay_in_tens_of_milliseconds_default() -> u16
 	{
@@ -37,6 +37,19 @@ impl EngiffenLoops
 		}
 	}
 	
+	/// WebP loop counts are the number of times the animation is played, rather than repeated; zero is forever.
+	#[inline(always)]
+	fn toWebPLoopCount(&self) -> u16
+	{
+		use self::EngiffenLoops::*;
+		
+		match *self
+		{
+			infinite => 0,
+			finite { repetitions } => repetitions.saturating_add(1),
+		}
+	}
+	
 	#[inline(always)]
 	fn repetitions_default() -> u16
 	{
@@ -32,7 +32,7 @@ impl Default for EngiffenSource
 impl EngiffenSource
 {
 	#[inline(always)]
-	fn transform<'a>(&'a self, sourceSets: &mut SourceSets<'a>, sourceSetIndex: usize, imageIndex: usize, image: &mut ::image::DynamicImage, frameWidthBySourceSet: &mut HashMap<usize, u16>, frameHeightBySourceSet: &mut HashMap<usize, u16>) -> Result<(), CordialError>
+	fn transform<'a>(&'a self, sourceSets: &mut SourceSets<'a>, sourceSetIndex: usize, imageIndex: usize, image: &mut ::image::DynamicImage, frameWidthBySourceSet: &mut HashMap<usize, u16>, frameHeightBySourceSet: &mut HashMap<usize, u16>, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<(), CordialError>
 	{
 		let engiffenFrame = match self.perImage.get(&imageIndex)
 		{
@@ -40,7 +40,7 @@ impl EngiffenSource
 			Some(engiffenFrame) => engiffenFrame,
 		};
 		
-		let transformedImage = if let Some(transformedImage) = engiffenFrame.transform(image)?
+		let transformedImage = if let Some(transformedImage) = engiffenFrame.transform(image, resources, configuration, iso639Dash1Alpha2Language)?
 		{
 			transformedImage
 		}
@@ -0,0 +1,83 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct FontFace
+{
+	#[serde(default)] font_family: Option<String>,
+	#[serde(default = "FontFace::font_weight_default")] font_weight: String,
+	#[serde(default = "FontFace::font_style_default")] font_style: String,
+	#[serde(default)] font_display: FontDisplay,
+}
+
+impl Default for FontFace
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			font_family: None,
+			font_weight: Self::font_weight_default(),
+			font_style: Self::font_style_default(),
+			font_display: FontDisplay::default(),
+		}
+	}
+}
+
+impl FontFace
+{
+	/// `sources` are `(url, format)` pairs, most preferred first.
+	#[inline(always)]
+	pub(crate) fn writeRule(&self, css: &mut String, resourceUrl: &ResourceUrl, sources: &[(Url, &'static str)], staticInstance: Option<&FontStaticInstance>, unicodeRange: Option<String>) -> Result<(), CordialError>
+	{
+		let fontFamily = match self.font_family
+		{
+			Some(ref fontFamily) => fontFamily.as_str(),
+			None => match Path::new(resourceUrl.withoutFileNameExtension()).file_name().and_then(|fileName| fileName.to_str())
+			{
+				None => return Err(CordialError::Configuration(format!("Font resource URL '{}' does not have a file name to use as a font-family", resourceUrl))),
+				Some(fileName) => fileName,
+			},
+		};
+		
+		let source = sources.iter().map(|&(ref url, format)| format!("url(\"{}\") format(\"{}\")", url.as_str(), format)).collect::<Vec<_>>().join(", ");
+		
+		css.push_str("@font-face\n{\n");
+		css.push_str(&format!("\tfont-family: \"{}\";\n", fontFamily.replace('"', "\\\"")));
+		let (fontStyle, fontWeight, fontStretch) = match staticInstance
+		{
+			None => (None, None, None),
+			Some(staticInstance) => (staticInstance.fontStyle(), staticInstance.fontWeight(), staticInstance.fontStretch()),
+		};
+		css.push_str(&format!("\tfont-style: {};\n", fontStyle.as_ref().unwrap_or(&self.font_style)));
+		css.push_str(&format!("\tfont-weight: {};\n", fontWeight.as_ref().unwrap_or(&self.font_weight)));
+		if let Some(fontStretch) = fontStretch
+		{
+			css.push_str(&format!("\tfont-stretch: {};\n", fontStretch));
+		}
+		css.push_str(&format!("\tfont-display: {};\n", self.font_display.css()));
+		css.push_str(&format!("\tsrc: {};\n", source));
+		if let Some(unicodeRange) = unicodeRange
+		{
+			css.push_str(&format!("\tunicode-range: {};\n", unicodeRange));
+		}
+		css.push_str("}\n");
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn font_weight_default() -> String
+	{
+		"normal".to_owned()
+	}
+	
+	#[inline(always)]
+	fn font_style_default() -> String
+	{
+		"normal".to_owned()
+	}
+}
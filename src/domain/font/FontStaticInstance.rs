@@ -0,0 +1,108 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// A fixed instance of a variable font, eg `axes: { wght: 700 }`, for browsers without variable font support.
+/// Axes not given are pinned to their default; standard axes (`wght`, `wdth`, `ital` and `slnt`) also set the generated `@font-face` descriptors.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct FontStaticInstance
+{
+	#[serde(default)] name: Option<String>,
+	axes: BTreeMap<String, f32>,
+}
+
+impl FontStaticInstance
+{
+	/// Used in file names, eg `wght700-wdth75`, so it can not be empty or contain a `/`.
+	#[inline(always)]
+	pub(crate) fn name(&self) -> Result<String, CordialError>
+	{
+		let name = match self.name
+		{
+			Some(ref name) => name.clone(),
+			None => self.axes.iter().map(|(axisTag, value)| format!("{}{}", axisTag, value)).collect::<Vec<_>>().join("-"),
+		};
+		
+		if name.is_empty()
+		{
+			return Err(CordialError::Configuration("A font static instance has an empty name (give it a name or at least one axis)".to_owned()));
+		}
+		
+		if name.contains('/')
+		{
+			return Err(CordialError::Configuration(format!("Font static instance name '{}' contains a '/'", name)));
+		}
+		
+		Ok(name)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn instance(&self, fontBytes: &[u8], inputContentFilePath: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		use ::hb_subset::Blob;
+		use ::hb_subset::FontFace as HarfBuzzFontFace;
+		use ::hb_subset::SubsetInput;
+		
+		let error = |cause: String| CordialError::CouldNotInstanceFont(inputContentFilePath.to_path_buf(), cause);
+		
+		let blob = Blob::from_bytes(fontBytes).map_err(|cause| error(format!("{:?}", cause)))?;
+		let fontFace = HarfBuzzFontFace::new(blob).map_err(|cause| error(format!("{:?}", cause)))?;
+		
+		let mut subsetInput = SubsetInput::new().map_err(|cause| error(format!("{:?}", cause)))?;
+		subsetInput.keep_everything();
+		subsetInput.pin_all_axes_to_default(&fontFace).map_err(|cause| error(format!("{:?}", cause)))?;
+		for (axisTag, value) in self.axes.iter()
+		{
+			subsetInput.pin_axis_location(&fontFace, Self::tag(axisTag, inputContentFilePath)?, *value).map_err(|cause| error(format!("{:?}", cause)))?;
+		}
+		
+		let instancedFontFace = subsetInput.subset_font(&fontFace).map_err(|cause| error(format!("{:?}", cause)))?;
+		let instancedFontBytes = instancedFontFace.underlying_blob().to_vec();
+		
+		info!("Instanced font {:?} as '{}' ({} bytes to {} bytes)", inputContentFilePath, self.name()?, fontBytes.len(), instancedFontBytes.len());
+		Ok(instancedFontBytes)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn fontWeight(&self) -> Option<String>
+	{
+		self.axes.get("wght").map(|value| format!("{}", value))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn fontStretch(&self) -> Option<String>
+	{
+		self.axes.get("wdth").map(|value| format!("{}%", value))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn fontStyle(&self) -> Option<String>
+	{
+		if let Some(&italic) = self.axes.get("ital")
+		{
+			return Some(if italic >= 1.0 { "italic" } else { "normal" }.to_owned());
+		}
+		
+		// slnt is measured counter-clockwise, CSS oblique clockwise.
+		self.axes.get("slnt").map(|&slant| if slant == 0.0
+		{
+			"normal".to_owned()
+		}
+		else
+		{
+			format!("oblique {}deg", -slant)
+		})
+	}
+	
+	#[inline(always)]
+	fn tag(axisTag: &str, inputContentFilePath: &Path) -> Result<::hb_subset::Tag, CordialError>
+	{
+		let bytes = axisTag.as_bytes();
+		if bytes.len() != 4 || !bytes.iter().all(|byte| byte.is_ascii_alphanumeric())
+		{
+			return Err(CordialError::CouldNotInstanceFont(inputContentFilePath.to_path_buf(), format!("Variable font axis tag '{}' is not four ASCII letters or digits", axisTag)));
+		}
+		Ok(::hb_subset::Tag::new([bytes[0], bytes[1], bytes[2], bytes[3]]))
+	}
+}
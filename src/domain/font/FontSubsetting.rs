@@ -0,0 +1,230 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Fonts are rendered before the HTML pages that use them, so the characters used are at first found from each HTML page's markdown and abstract.
+/// Once the HTML pages have been rendered, the characters of their rendered output (including text from templates and markdown plugins) are known; if a subset lacks any of them, the site is rendered a second time (see `Configuration::render()`).
+/// `additional_characters` are for text which is added in the browser, eg by JavaScript.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct FontSubsetting
+{
+	#[serde(default = "FontSubsetting::include_basic_latin_default")] include_basic_latin: bool,
+	#[serde(default)] additional_characters: String,
+	#[serde(default)] additional_characters_by_language: HashMap<Iso639Dash1Alpha2Language, String>,
+}
+
+impl Default for FontSubsetting
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			include_basic_latin: Self::include_basic_latin_default(),
+			additional_characters: String::new(),
+			additional_characters_by_language: Default::default(),
+		}
+	}
+}
+
+impl FontSubsetting
+{
+	#[inline(always)]
+	pub(crate) fn subset(&self, fontBytes: &[u8], resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, inputContentFilePath: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		let characters = self.characters(resources, configuration, iso639Dash1Alpha2Language)?;
+		Self::subsetToCharacters(fontBytes, &characters, inputContentFilePath)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn subsetToCharacters(fontBytes: &[u8], characters: &BTreeSet<char>, inputContentFilePath: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		match ::hb_subset::subset(fontBytes, characters.iter().cloned())
+		{
+			Err(error) => Err(CordialError::CouldNotSubsetFont(inputContentFilePath.to_path_buf(), format!("{:?}", error))),
+			Ok(subsetFontBytes) =>
+			{
+				info!("Subset font {:?} to {} characters ({} bytes to {} bytes)", inputContentFilePath, characters.len(), fontBytes.len(), subsetFontBytes.len());
+				Ok(subsetFontBytes)
+			}
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn characters(&self, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<BTreeSet<char>, CordialError>
+	{
+		let mut characters = BTreeSet::new();
+		
+		if self.include_basic_latin
+		{
+			characters.extend((0x20u8 .. 0x7F).map(|byte| byte as char));
+		}
+		
+		characters.extend(self.additional_characters.chars());
+		
+		if let Some(additionalCharacters) = self.additional_characters_by_language.get(&iso639Dash1Alpha2Language)
+		{
+			characters.extend(additionalCharacters.chars());
+		}
+		
+		for resource in resources.values()
+		{
+			// The font being rendered is already mutably borrowed.
+			if let Ok(resource) = resource.try_borrow()
+			{
+				if let Some(text) = resource.textForFontSubsetting(configuration, iso639Dash1Alpha2Language)?
+				{
+					characters.extend(text.chars().filter(|character| !character.is_control()));
+				}
+			}
+		}
+		
+		Ok(characters)
+	}
+	
+	/// Adds the characters of text outside of tags and of the attributes whose values are shown to the user, such as `alt` and `placeholder`; the entities html5ever uses when serializing are decoded.
+	/// The contents of `<script>` (including JSON-LD) and `<style>` are not shown, so are skipped.
+	pub(crate) fn addCharactersInHtml(html: &[u8], characters: &mut BTreeSet<char>)
+	{
+		let html = String::from_utf8_lossy(html);
+		
+		let mut remaining: &str = &html;
+		while let Some(tagStartIndex) = remaining.find('<')
+		{
+			Self::addCharactersInText(&remaining[.. tagStartIndex], characters);
+			
+			let tag = &remaining[tagStartIndex + 1 ..];
+			let tagEndIndex = match Self::tagEndIndex(tag)
+			{
+				None => return,
+				Some(tagEndIndex) => tagEndIndex,
+			};
+			let tagContents = &tag[.. tagEndIndex];
+			remaining = &tag[tagEndIndex + 1 ..];
+			
+			if tagContents.starts_with('/')
+			{
+				continue;
+			}
+			
+			let tagName = tagContents.split(|character: char| character.is_whitespace() || character == '/').next().unwrap().to_ascii_lowercase();
+			Self::addCharactersInUserVisibleAttributes(&tagContents[tagName.len() ..], characters);
+			
+			if (tagName == "script" || tagName == "style") && !tagContents.ends_with('/')
+			{
+				let endTag = format!("</{}", tagName);
+				match remaining.to_ascii_lowercase().find(&endTag)
+				{
+					None => return,
+					Some(endTagIndex) => remaining = &remaining[endTagIndex ..],
+				}
+			}
+		}
+		
+		Self::addCharactersInText(remaining, characters);
+	}
+	
+	/// html5ever does not escape `>` in attribute values.
+	fn tagEndIndex(tag: &str) -> Option<usize>
+	{
+		let mut quote = None;
+		for (index, character) in tag.char_indices()
+		{
+			match quote
+			{
+				Some(quoteCharacter) => if character == quoteCharacter
+				{
+					quote = None;
+				},
+				None => match character
+				{
+					'>' => return Some(index),
+					'"' | '\'' => quote = Some(character),
+					_ => (),
+				},
+			}
+		}
+		None
+	}
+	
+	fn addCharactersInUserVisibleAttributes(attributes: &str, characters: &mut BTreeSet<char>)
+	{
+		const UserVisibleAttributeNames: [&'static str; 6] = ["alt", "aria-label", "label", "placeholder", "title", "value"];
+		
+		let mut remaining = attributes;
+		while let Some(equalsIndex) = remaining.find('=')
+		{
+			let name = remaining[.. equalsIndex].trim_right().rsplit(|character: char| character.is_whitespace()).next().unwrap().to_ascii_lowercase();
+			
+			let afterEquals = remaining[equalsIndex + 1 ..].trim_left();
+			let (value, rest) = match afterEquals.chars().next()
+			{
+				Some(quote @ '"') | Some(quote @ '\'') =>
+				{
+					let quoted = &afterEquals[1 ..];
+					match quoted.find(quote)
+					{
+						None => (quoted, ""),
+						Some(index) => (&quoted[.. index], &quoted[index + 1 ..]),
+					}
+				}
+				_ => match afterEquals.find(|character: char| character.is_whitespace())
+				{
+					None => (afterEquals, ""),
+					Some(index) => (&afterEquals[.. index], &afterEquals[index ..]),
+				},
+			};
+			
+			if UserVisibleAttributeNames.contains(&name.as_str())
+			{
+				Self::addCharactersInText(value, characters);
+			}
+			remaining = rest;
+		}
+	}
+	
+	fn addCharactersInText(text: &str, characters: &mut BTreeSet<char>)
+	{
+		let mut entity: Option<String> = None;
+		for character in text.chars()
+		{
+			if let Some(mut name) = entity.take()
+			{
+				if character == ';'
+				{
+					match name.as_str()
+					{
+						"amp" => characters.insert('&'),
+						"lt" => characters.insert('<'),
+						"gt" => characters.insert('>'),
+						"quot" => characters.insert('"'),
+						"nbsp" => characters.insert('\u{A0}'),
+						_ => false,
+					};
+				}
+				else if character.is_alphanumeric() || character == '#'
+				{
+					name.push(character);
+					entity = Some(name);
+				}
+				continue;
+			}
+			
+			match character
+			{
+				'&' => entity = Some(String::new()),
+				_ => if !character.is_control()
+				{
+					characters.insert(character);
+				},
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn include_basic_latin_default() -> bool
+	{
+		true
+	}
+}
@@ -0,0 +1,118 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// The predefined ranges match those Google Fonts uses, so a page's text normally only downloads one or two chunks.
+/// A `custom` range uses CSS `unicode-range` syntax, eg `[ "U+0590-05FF", "U+FB1D-FB4F" ]`; wildcards are not supported.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum FontUnicodeRange
+{
+	latin,
+	latin_ext,
+	cyrillic,
+	cyrillic_ext,
+	greek,
+	greek_ext,
+	vietnamese,
+	cjk,
+	custom
+	{
+		name: String,
+		unicode_range: Vec<String>,
+	},
+}
+
+impl FontUnicodeRange
+{
+	#[inline(always)]
+	pub(crate) fn name(&self) -> &str
+	{
+		use self::FontUnicodeRange::*;
+		
+		match *self
+		{
+			latin => "latin",
+			latin_ext => "latin-ext",
+			cyrillic => "cyrillic",
+			cyrillic_ext => "cyrillic-ext",
+			greek => "greek",
+			greek_ext => "greek-ext",
+			vietnamese => "vietnamese",
+			cjk => "cjk",
+			custom { ref name, .. } => name,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn css(&self) -> Result<String, CordialError>
+	{
+		let codePointRanges = self.codePointRanges()?;
+		Ok(codePointRanges.iter().map(|&(first, last)| if first == last
+		{
+			format!("U+{:04X}", first)
+		}
+		else
+		{
+			format!("U+{:04X}-{:04X}", first, last)
+		}).collect::<Vec<_>>().join(", "))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn characters(&self) -> Result<BTreeSet<char>, CordialError>
+	{
+		let mut characters = BTreeSet::new();
+		for (first, last) in self.codePointRanges()?
+		{
+			characters.extend((first .. last + 1).filter_map(::std::char::from_u32));
+		}
+		Ok(characters)
+	}
+	
+	#[inline(always)]
+	fn codePointRanges(&self) -> Result<Vec<(u32, u32)>, CordialError>
+	{
+		use self::FontUnicodeRange::*;
+		
+		let predefined: &'static [(u32, u32)] = match *self
+		{
+			latin => &[(0x0000, 0x00FF), (0x0131, 0x0131), (0x0152, 0x0153), (0x02BB, 0x02BC), (0x02C6, 0x02C6), (0x02DA, 0x02DA), (0x02DC, 0x02DC), (0x2000, 0x206F), (0x2074, 0x2074), (0x20AC, 0x20AC), (0x2122, 0x2122), (0x2191, 0x2191), (0x2193, 0x2193), (0x2212, 0x2212), (0x2215, 0x2215), (0xFEFF, 0xFEFF), (0xFFFD, 0xFFFD)],
+			latin_ext => &[(0x0100, 0x024F), (0x0259, 0x0259), (0x1E00, 0x1EFF), (0x2020, 0x2020), (0x20A0, 0x20AB), (0x20AD, 0x20CF), (0x2113, 0x2113), (0x2C60, 0x2C7F), (0xA720, 0xA7FF)],
+			cyrillic => &[(0x0400, 0x045F), (0x0490, 0x0491), (0x04B0, 0x04B1), (0x2116, 0x2116)],
+			cyrillic_ext => &[(0x0460, 0x052F), (0x1C80, 0x1C88), (0x20B4, 0x20B4), (0x2DE0, 0x2DFF), (0xA640, 0xA69F), (0xFE2E, 0xFE2F)],
+			greek => &[(0x0370, 0x03FF)],
+			greek_ext => &[(0x1F00, 0x1FFF)],
+			vietnamese => &[(0x0102, 0x0103), (0x0110, 0x0111), (0x0128, 0x0129), (0x0168, 0x0169), (0x01A0, 0x01A1), (0x01AF, 0x01B0), (0x1EA0, 0x1EF9), (0x20AB, 0x20AB)],
+			cjk => &[(0x2E80, 0x2FDF), (0x3000, 0x303F), (0x3040, 0x30FF), (0x3100, 0x312F), (0x3130, 0x318F), (0x3190, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0x9FFF), (0xAC00, 0xD7AF), (0xF900, 0xFAFF), (0xFF00, 0xFFEF)],
+			custom { ref name, ref unicode_range } => return unicode_range.iter().map(|range| Self::parseCssRange(name, range)).collect(),
+		};
+		Ok(predefined.to_vec())
+	}
+	
+	#[inline(always)]
+	fn parseCssRange(name: &str, range: &str) -> Result<(u32, u32), CordialError>
+	{
+		let invalid = || CordialError::Configuration(format!("Custom unicode range '{}' has an invalid range '{}'", name, range));
+		
+		let range = range.trim();
+		if !range.starts_with("U+") && !range.starts_with("u+")
+		{
+			return Err(invalid());
+		}
+		let range = &range[2..];
+		
+		let mut codePoints = range.splitn(2, '-');
+		let first = u32::from_str_radix(codePoints.next().unwrap(), 16).map_err(|_| invalid())?;
+		let last = match codePoints.next()
+		{
+			None => first,
+			Some(last) => u32::from_str_radix(last, 16).map_err(|_| invalid())?,
+		};
+		
+		if first > last || last > 0x10FFFF
+		{
+			return Err(invalid());
+		}
+		Ok((first, last))
+	}
+}
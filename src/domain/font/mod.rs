@@ -5,4 +5,9 @@
 use super::*;
 
 
+include!("FontDisplay.rs");
+include!("FontFace.rs");
+include!("FontStaticInstance.rs");
+include!("FontSubsetting.rs");
+include!("FontUnicodeRange.rs");
 include!("TtfMimeType.rs");
@@ -18,6 +18,7 @@ impl HandlebarsWrapper
 		
 		// Register any helpers here
 		LuaShortCodeHelper::registerForAllShortCodes(configuration, &mut handlebars)?;
+		handlebars.register_helper("svg_symbol", Box::new(SvgSymbolHelper));
 		
 		// Register any decorators here
 		
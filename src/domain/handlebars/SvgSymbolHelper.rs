@@ -0,0 +1,43 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Renders `{{svg_symbol "images/icons.svg" "arrow" class="icon" title="Next"}}` as a `<svg>` which `<use>`s a symbol in a SVG sprite.
+/// Relies on the template data `svg_sprites`, which maps sprite resource URLs to their versioned URLs.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct SvgSymbolHelper;
+
+impl HelperDef for SvgSymbolHelper
+{
+	fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> Result<(), RenderError>
+	{
+		let spriteResourceUrl = Self::stringParameter(h, 0, "sprite resource URL")?;
+		let symbolId = Self::stringParameter(h, 1, "symbol id")?;
+		let class = h.hash_get("class").and_then(|class| class.value().as_str()).map(|class| class.to_owned());
+		let title = h.hash_get("title").and_then(|title| title.value().as_str()).map(|title| title.to_owned());
+		
+		let spriteUrl = match rc.context().data().get("svg_sprites").and_then(|svgSprites| svgSprites.get(&spriteResourceUrl)).and_then(|spriteUrl| spriteUrl.as_str())
+		{
+			None => return Err(RenderError::new(format!("svg_symbol helper could not find a svg_sprite resource '{}'", spriteResourceUrl))),
+			Some(spriteUrl) => spriteUrl.to_owned(),
+		};
+		
+		let svgSymbolNode = SvgSpritePipeline::useNode(&spriteUrl, &symbolId, class.as_ref().map(String::as_str), title.as_ref().map(String::as_str));
+		rc.writer.write(svgSymbolNode.to_html_fragment().as_bytes())?;
+		
+		Ok(())
+	}
+}
+
+impl SvgSymbolHelper
+{
+	#[inline(always)]
+	fn stringParameter(h: &Helper, index: usize, description: &str) -> Result<String, RenderError>
+	{
+		match h.param(index).and_then(|parameter| parameter.value().as_str())
+		{
+			None => Err(RenderError::new(format!("svg_symbol helper requires a {} string as parameter {}", description, index))),
+			Some(value) => Ok(value.to_owned()),
+		}
+	}
+}
@@ -3,8 +3,13 @@
 
 
 use super::*;
+use ::handlebars::Helper;
+use ::handlebars::HelperDef;
+use ::handlebars::RenderContext;
+use ::handlebars::RenderError;
 
 
 include!("HandlebarsTemplate.rs");
 include!("HandlebarsTemplateRenderer.rs");
 include!("HandlebarsWrapper.rs");
+include!("SvgSymbolHelper.rs");
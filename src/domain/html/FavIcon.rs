@@ -26,7 +26,17 @@ impl Default for FavIcon
 impl FavIcon
 {
 	#[inline(always)]
-	fn addLinkNodes(&self, endHeadNodes: &mut Vec<UnattachedNode>, linkRelation: &str, resources: &Resources, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Option<Iso639Dash1Alpha2Language>) -> Result<(), CordialError>
+	pub(crate) fn new(url: ResourceUrl, sizes: BTreeSet<u32>) -> Self
+	{
+		Self
+		{
+			url,
+			sizes,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn addLinkNodes(&self, endHeadNodes: &mut Vec<UnattachedNode>, linkRelation: &str, resources: &Resources, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Option<Iso639Dash1Alpha2Language>) -> Result<(), CordialError>
 	{
 		for iconSize in self.sizes.iter()
 		{
@@ -54,7 +64,7 @@ impl FavIcon
 	}
 	
 	#[inline(always)]
-	fn sizes_default() -> BTreeSet<u32>
+	pub(crate) fn sizes_default() -> BTreeSet<u32>
 	{
 		btreeset!
 		{
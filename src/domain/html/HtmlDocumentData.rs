@@ -12,6 +12,7 @@ pub(crate) struct HtmlDocumentData<'a>
 	pub(crate) markdownPluginData: MarkdownPluginData<'a>,
 	pub(crate) htmlAbstract: Rc<HtmlAbstract>,
 	pub(crate) articleImage: Option<(ResourceUrl, Rc<ImageMetaData>)>,
+	pub(crate) socialCardImage: Option<(ResourceUrl, Rc<ImageMetaData>)>,
 	pub(crate) siteMapImages: &'a [ResourceUrl],
 	pub(crate) siteMapAudios: &'a [ResourceUrl],
 	pub(crate) siteMapVideos: &'a [ResourceUrl],
@@ -28,7 +29,10 @@ pub(crate) struct HtmlDocumentData<'a>
 	pub(crate) favIcon: Option<&'a FavIcon>,
 	pub(crate) svgFavIcon: Option<&'a ResourceUrl>,
 	pub(crate) safariStyling: Option<&'a SafariStyling>,
+	pub(crate) iconSet: Option<&'a ResourceUrl>,
 	pub(crate) windowsTilesBrowserConfig: Option<&'a ResourceUrl>,
+	pub(crate) stylesheets: &'a [ResourceUrl],
+	pub(crate) preloadFontsUsingLinkHeader: bool,
 }
 
 impl<'a> HtmlDocumentData<'a>
@@ -94,6 +98,14 @@ impl<'a> HtmlDocumentData<'a>
 		
 		self.addLinkNodes(&mut endHeadNodes, addAmpLink, ampLinkIsCanonical)?;
 		
+		if !self.preloadFontsUsingLinkHeader
+		{
+			for fontUrl in self.criticalFontUrls(resources)?
+			{
+				endHeadNodes.push("link".with_rel_attribute("preload").with_attribute("as".str_attribute("font")).with_attribute("type".str_attribute("font/woff2")).with_empty_attribute("crossorigin").with_href_attribute(fontUrl.as_str()));
+			}
+		}
+		
 		if let Some(favIcon) = self.favIcon
 		{
 			favIcon.addLinkNodes(&mut endHeadNodes, "icon", resources, self.configuration.fallbackIso639Dash1Alpha2Language(), Some(self.iso639Dash1Alpha2Language()))?;
@@ -107,6 +119,11 @@ impl<'a> HtmlDocumentData<'a>
 			endHeadNodes.push("link".with_rel_attribute("icon").with_attribute("type".str_attribute(urlData.mimeType().as_ref())).with_href_attribute(urlData.url_str()))
 		}
 		
+		if let Some(iconSetResourceUrl) = self.iconSet
+		{
+			self.addIconSetLinkNodes(&mut endHeadNodes, resources, iconSetResourceUrl)?;
+		}
+		
 		if let Some(safariStyling) = self.safariStyling
 		{
 			safariStyling.addToEndHeadNodes(&mut endHeadNodes, resources, self.configuration.fallbackIso639Dash1Alpha2Language(), Some(self.iso639Dash1Alpha2Language()), self.resourceUrl)?;
@@ -135,6 +152,61 @@ impl<'a> HtmlDocumentData<'a>
 		Ok(endHeadNodes)
 	}
 	
+	#[inline(always)]
+	pub(crate) fn addFontPreloadLinkHeader(&self, resources: &Resources, headers: &mut Vec<(String, String)>) -> Result<(), CordialError>
+	{
+		if self.preloadFontsUsingLinkHeader
+		{
+			HeaderGenerator::addFontPreloadLinkHeader(headers, &self.criticalFontUrls(resources)?);
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn criticalFontUrls(&self, resources: &Resources) -> Result<Vec<Rc<Url>>, CordialError>
+	{
+		let mut criticalFontUrls: Vec<Rc<Url>> = Vec::new();
+		for stylesheet in self.stylesheets.iter()
+		{
+			let resource = stylesheet.resourceMandatory(resources)?;
+			for fontUrl in resource.stylesheetCriticalFontUrls(self.configuration.fallbackIso639Dash1Alpha2Language(), self.iso639Dash1Alpha2Language())?
+			{
+				if !criticalFontUrls.contains(&fontUrl)
+				{
+					criticalFontUrls.push(fontUrl);
+				}
+			}
+		}
+		Ok(criticalFontUrls)
+	}
+	
+	#[inline(always)]
+	fn addIconSetLinkNodes(&self, endHeadNodes: &mut Vec<UnattachedNode>, resources: &Resources, iconSetResourceUrl: &ResourceUrl) -> Result<(), CordialError>
+	{
+		let resource = iconSetResourceUrl.resourceMandatory(resources)?;
+		let iconSetPipeline = resource.iconSetPipeline()?;
+		
+		let urlData = self.urlDataMandatory(resources, iconSetResourceUrl, ResourceTag::favicon_ico)?;
+		endHeadNodes.push("link".with_rel_attribute("icon").with_attribute("type".str_attribute(urlData.mimeType().as_ref())).with_href_attribute(urlData.url_str()).with_attribute("sizes".string_attribute(iconSetPipeline.faviconIcoSizes())));
+		
+		if self.favIcon.is_none()
+		{
+			iconSetPipeline.favIcon(iconSetResourceUrl).addLinkNodes(endHeadNodes, "icon", resources, self.configuration.fallbackIso639Dash1Alpha2Language(), Some(self.iso639Dash1Alpha2Language()))?;
+		}
+		
+		let hasTouchIcon = match self.safariStyling
+		{
+			None => false,
+			Some(safariStyling) => safariStyling.hasTouchIcon(),
+		};
+		if !hasTouchIcon
+		{
+			iconSetPipeline.appleTouchIcon(iconSetResourceUrl).addLinkNodes(endHeadNodes, "apple-touch-icon", resources, self.configuration.fallbackIso639Dash1Alpha2Language(), Some(self.iso639Dash1Alpha2Language()))?;
+		}
+		
+		Ok(())
+	}
+	
 	#[inline(always)]
 	fn htmlDescription(&self) -> &str
 	{
@@ -177,7 +249,21 @@ impl<'a> HtmlDocumentData<'a>
 	#[inline(always)]
 	fn addFacebookOpenGraphHtmlNodes(&self, endHeadNodes: &mut Vec<UnattachedNode>, resources: &Resources) -> Result<(), CordialError>
 	{
-		self.facebookOpenGraph.facebookOpenGraph(endHeadNodes, self.title(), self.description(), &self.htmlUrls.linkHeaderCanonicalUrl()?, self.publicationDate, self.lastModificationDateOrPublicationDate, self.expirationDate, self.configuration, resources, &self.articleImage, self.fallbackIso639Dash1Alpha2Language, self.htmlUrls.languageData)
+		self.facebookOpenGraph.facebookOpenGraph(endHeadNodes, self.title(), self.description(), &self.htmlUrls.linkHeaderCanonicalUrl()?, self.publicationDate, self.lastModificationDateOrPublicationDate, self.expirationDate, self.configuration, resources, self.socialImage(), self.fallbackIso639Dash1Alpha2Language, self.htmlUrls.languageData)
+	}
+	
+	/// The `article_image`, or, failing that, the page's social card.
+	#[inline(always)]
+	fn socialImage(&self) -> &Option<(ResourceUrl, Rc<ImageMetaData>)>
+	{
+		if self.articleImage.is_some()
+		{
+			&self.articleImage
+		}
+		else
+		{
+			&self.socialCardImage
+		}
 	}
 	
 	#[inline(always)]
@@ -186,7 +272,7 @@ impl<'a> HtmlDocumentData<'a>
 		let articleAudio = self.siteMapAudios.get(0);
 		let articleVideo = self.siteMapVideos.get(0);
 		
-		self.twitterCard.addTo(endHeadNodes, &self.articleImage, articleAudio, articleVideo, resources, self.fallbackIso639Dash1Alpha2Language, self.htmlUrls.languageData)
+		self.twitterCard.addTo(endHeadNodes, self.socialImage(), articleAudio, articleVideo, resources, self.fallbackIso639Dash1Alpha2Language, self.htmlUrls.languageData)
 	}
 	
 	#[inline(always)]
@@ -376,6 +462,18 @@ impl<'a> HtmlDocumentData<'a>
 			
 			let templateParameters: Option<&JsonMap<String, JsonValue>> = None;
 			
+			let svgSpriteUrls = self.configuration.svgSpriteUrls(resources, iso639Dash1Alpha2Language)?;
+			
+			let imageArticlePlaceholder = match self.articleImage
+			{
+				None => None,
+				Some((ref imageResourceUrl, _)) =>
+				{
+					let resource = imageResourceUrl.resourceMandatory(resources)?;
+					resource.urlDataMandatory(self.fallbackIso639Dash1Alpha2Language, Some(iso639Dash1Alpha2Language), &ResourceTag::primary_image)?.placeholder().cloned()
+				}
+			};
+			
 			handlebars.renderHtmlUsingNamedTemplate(handlebarsTemplate, &json!
 			({
 				"configuration": self.configuration,
@@ -400,7 +498,9 @@ impl<'a> HtmlDocumentData<'a>
 						Some((imageResourceUrl, imageMetaData.imageAbstract(iso639Dash1Alpha2Language)?))
 					},
 				},
+				"image_article_placeholder": imageArticlePlaceholder,
 				"site_map_images": self.siteMapImages,
+				"svg_sprites": svgSpriteUrls.deref(),
 			}))?
 		};
 		
@@ -137,7 +137,8 @@ impl HtmlOutputFormat
 	fn regularHtmlDocument(resources: &Resources, htmlUrl: Url, pjaxCssSelector: &Option<String>, template: &str, htmlDocumentData: &HtmlDocumentData, headerGenerator: &mut HeaderGenerator, maximumAge: u32, inputContentFilePath: &Path, handlebars: &HandlebarsWrapper, result: &mut Vec<PipelineResponse>, addAmpLink: bool, ampLinkIsCanonical: bool) -> Result<(), CordialError>
 	{
 		// Canonical HTML document
-		let htmlHeaders = headerGenerator.generateHeaders(Self::IsNotPjax, Self::CanBeCompressed, maximumAge, Self::IsNotDownloadable, &htmlUrl)?;
+		let mut htmlHeaders = headerGenerator.generateHeaders(Self::IsNotPjax, Self::CanBeCompressed, maximumAge, Self::IsNotDownloadable, &htmlUrl)?;
+		htmlDocumentData.addFontPreloadLinkHeader(resources, &mut htmlHeaders)?;
 		let (htmlDocument, htmlBody) = htmlDocumentData.renderHtmlDocument(resources, pjaxCssSelector.is_some(), inputContentFilePath, Self::IsNotAmp, addAmpLink, ampLinkIsCanonical, handlebars, template)?;
 		
 		// PJAX variant of HTML document
@@ -163,7 +164,8 @@ impl HtmlOutputFormat
 	fn ampDocument(resources: &Resources, ampUrl: Url, isAlsoCanonical: bool, template: &str, htmlDocumentData: &HtmlDocumentData, headerGenerator: &mut HeaderGenerator, maximumAge: u32, inputContentFilePath: &Path, handlebars: &HandlebarsWrapper, result: &mut Vec<PipelineResponse>, ampLinkIsCanonical: bool) -> Result<(), CordialError>
 	{
 		// Canonical HTML document
-		let htmlHeaders = headerGenerator.generateHeaders(Self::IsNotPjax, Self::CanBeCompressed, maximumAge, Self::IsNotDownloadable, &ampUrl)?;
+		let mut htmlHeaders = headerGenerator.generateHeaders(Self::IsNotPjax, Self::CanBeCompressed, maximumAge, Self::IsNotDownloadable, &ampUrl)?;
+		htmlDocumentData.addFontPreloadLinkHeader(resources, &mut htmlHeaders)?;
 		let (_htmlDocument, htmlBody) = htmlDocumentData.renderHtmlDocument(resources, false, inputContentFilePath, Self::IsAmp, Self::IsAmp, ampLinkIsCanonical, handlebars, template)?;
 		
 		// PJAX variant of HTML document
@@ -50,6 +50,12 @@ impl SafariStyling
 		}
 	}
 	
+	#[inline(always)]
+	pub(crate) fn hasTouchIcon(&self) -> bool
+	{
+		self.touch_icon.is_some()
+	}
+	
 	#[inline(always)]
 	pub(crate) fn addToEndHeadNodes(&self, endHeadNodes: &mut Vec<UnattachedNode>, resources: &Resources, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Option<Iso639Dash1Alpha2Language>, resourceUrl: &ResourceUrl) -> Result<(), CordialError>
 	{
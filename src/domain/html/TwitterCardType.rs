@@ -161,10 +161,13 @@ impl TwitterCardType
 					}
 					endHeadNodes.push(meta_with_property_and_content("twitter:image:alt", altText));
 					
-					let mp4UrlBorrow = audioPipeline.mp4Url.borrow();
-					endHeadNodes.push(meta_with_name_and_content("twitter:player:stream", &format!("{}", mp4UrlBorrow.as_ref().unwrap().as_str())));
-					
-					endHeadNodes.push(meta_with_name_and_content("twitter:player:stream:content_type", audioPipeline.twitterContentType().0.as_ref()));
+					// Twitter only supports MP4 streams.
+					if let Some(ref mp4Url) = *audioPipeline.mp4Url.borrow()
+					{
+						endHeadNodes.push(meta_with_name_and_content("twitter:player:stream", mp4Url.as_str()));
+						
+						endHeadNodes.push(meta_with_name_and_content("twitter:player:stream:content_type", audioPipeline.twitterContentType().0.as_ref()));
+					}
 				}
 			}
 			
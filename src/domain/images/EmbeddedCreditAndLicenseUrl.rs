@@ -0,0 +1,57 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// The credit and license URL found in an input file's embedded image metadata (EXIF, IPTC and XMP).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EmbeddedCreditAndLicenseUrl
+{
+	pub(crate) credit: Option<FullName>,
+	pub(crate) licenseUrl: Option<ResourceUrl>,
+	pub(crate) externalLicenseUrl: Option<Rc<Url>>,
+}
+
+impl EmbeddedCreditAndLicenseUrl
+{
+	/// License URLs on one of the site's own hosts are only used if they are a known resource; a license URL on any other site is kept as an external URL, and only used if no local one is found.
+	#[inline(always)]
+	pub(crate) fn import(embeddedImageMetaData: &EmbeddedImageMetaData, resources: &Resources, localization: &Localization) -> Self
+	{
+		let mut externalLicenseUrl = None;
+		let mut licenseUrl = None;
+		
+		for embeddedLicenseUrl in embeddedImageMetaData.licenseUrls().iter()
+		{
+			let resourceUrl = match Url::parse(embeddedLicenseUrl)
+			{
+				Err(_) => ResourceUrl::string(embeddedLicenseUrl.trim_left_matches('/')),
+				Ok(url) => match localization.resourceUrlForSiteUrl(&url)
+				{
+					Some(resourceUrl) => resourceUrl,
+					None =>
+					{
+						if externalLicenseUrl.is_none()
+						{
+							externalLicenseUrl = Some(Rc::new(url));
+						}
+						continue;
+					}
+				},
+			};
+			
+			if resources.contains_key(&resourceUrl)
+			{
+				licenseUrl = Some(resourceUrl);
+				externalLicenseUrl = None;
+				break;
+			}
+		}
+		
+		Self
+		{
+			credit: embeddedImageMetaData.credit().map(|credit| Rc::new(credit.to_owned())),
+			licenseUrl,
+			externalLicenseUrl,
+		}
+	}
+}
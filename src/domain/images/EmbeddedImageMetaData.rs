@@ -0,0 +1,369 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Metadata embedded in an image file as EXIF, IPTC (IIM) or XMP.
+///
+/// None of this metadata (including GPS co-ordinates) is ever copied to the images served; they are always re-encoded from decoded pixels.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct EmbeddedImageMetaData
+{
+	orientation: Option<u32>,
+	credits: Vec<String>,
+	copyrightNotices: Vec<String>,
+	licenseUrls: Vec<String>,
+}
+
+impl EmbeddedImageMetaData
+{
+	const XmpStart: &'static [u8] = b"<x:xmpmeta";
+	
+	const XmpEnd: &'static [u8] = b"</x:xmpmeta>";
+	
+	const PhotoshopImageResourcesSignature: &'static [u8] = b"Photoshop 3.0\0";
+	
+	const IptcImageResourceIdentifier: u16 = 0x0404;
+	
+	/// Unreadable or corrupt metadata is ignored, as it is for browsers.
+	#[inline(always)]
+	pub(crate) fn read(inputContentFilePath: &Path) -> Result<Self, CordialError>
+	{
+		let bytes = inputContentFilePath.fileContentsAsBytes().context(inputContentFilePath)?;
+		
+		let mut embeddedImageMetaData = Self::default();
+		embeddedImageMetaData.readXmp(&bytes);
+		embeddedImageMetaData.readIptc(&bytes);
+		embeddedImageMetaData.readExif(&bytes);
+		Ok(embeddedImageMetaData)
+	}
+	
+	/// Rotates and flips an image so that it is upright, using the EXIF `Orientation` tag.
+	#[inline(always)]
+	pub(crate) fn autoOrient(&self, image: ::image::DynamicImage) -> ::image::DynamicImage
+	{
+		match self.orientation
+		{
+			Some(2) => image.fliph(),
+			Some(3) => image.rotate180(),
+			Some(4) => image.flipv(),
+			Some(5) => image.rotate90().fliph(),
+			Some(6) => image.rotate90(),
+			Some(7) => image.rotate270().fliph(),
+			Some(8) => image.rotate270(),
+			_ => image,
+		}
+	}
+	
+	/// Preference is given to XMP, then IPTC, then EXIF; explicit credits are preferred to copyright notices.
+	#[inline(always)]
+	pub(crate) fn credit(&self) -> Option<&str>
+	{
+		self.credits.iter().chain(self.copyrightNotices.iter()).map(|credit| credit.as_str()).next()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn licenseUrls(&self) -> &[String]
+	{
+		&self.licenseUrls
+	}
+	
+	#[inline(always)]
+	fn readExif(&mut self, bytes: &[u8])
+	{
+		use ::exif::Tag;
+		use ::exif::Value;
+		
+		let reader = match ::exif::Reader::new(&mut ::std::io::Cursor::new(bytes))
+		{
+			Err(_) => return,
+			Ok(reader) => reader,
+		};
+		
+		const IsThumbnail: bool = false;
+		
+		if let Some(field) = reader.get_field(Tag::Orientation, IsThumbnail)
+		{
+			self.orientation = field.value.get_uint(0);
+		}
+		
+		let ascii = |tag: Tag, into: &mut Vec<String>|
+		{
+			if let Some(field) = reader.get_field(tag, IsThumbnail)
+			{
+				if let Value::Ascii(ref strings) = field.value
+				{
+					for string in strings.iter()
+					{
+						Self::push(into, &String::from_utf8_lossy(string));
+					}
+				}
+			}
+		};
+		ascii(Tag::Artist, &mut self.credits);
+		ascii(Tag::Copyright, &mut self.copyrightNotices);
+	}
+	
+	/// Only IPTC embedded in Photoshop image resources (as used in JPEG `APP13` segments) is found.
+	#[inline(always)]
+	fn readIptc(&mut self, bytes: &[u8])
+	{
+		#[inline(always)]
+		fn u16At(bytes: &[u8], index: usize) -> Option<u16>
+		{
+			if index + 2 > bytes.len()
+			{
+				None
+			}
+			else
+			{
+				Some(((bytes[index] as u16) << 8) | (bytes[index + 1] as u16))
+			}
+		}
+		
+		#[inline(always)]
+		fn u32At(bytes: &[u8], index: usize) -> Option<u32>
+		{
+			match (u16At(bytes, index), u16At(bytes, index + 2))
+			{
+				(Some(upper), Some(lower)) => Some(((upper as u32) << 16) | (lower as u32)),
+				_ => None,
+			}
+		}
+		
+		let start = match Self::find(bytes, Self::PhotoshopImageResourcesSignature)
+		{
+			None => return,
+			Some(start) => start + Self::PhotoshopImageResourcesSignature.len(),
+		};
+		
+		// Photoshop image resource blocks: '8BIM', u16 identifier, even-padded Pascal string name, u32 size, even-padded data.
+		let mut index = start;
+		while bytes.len() >= index + 4 && &bytes[index .. index + 4] == b"8BIM"
+		{
+			let identifier = match u16At(bytes, index + 4)
+			{
+				None => return,
+				Some(identifier) => identifier,
+			};
+			
+			let nameIndex = index + 6;
+			if nameIndex >= bytes.len()
+			{
+				return;
+			}
+			let nameLength = 1 + bytes[nameIndex] as usize;
+			let sizeIndex = nameIndex + nameLength + (nameLength & 1);
+			
+			let size = match u32At(bytes, sizeIndex)
+			{
+				None => return,
+				Some(size) => size as usize,
+			};
+			
+			let dataIndex = sizeIndex + 4;
+			if dataIndex + size > bytes.len()
+			{
+				return;
+			}
+			
+			if identifier == Self::IptcImageResourceIdentifier
+			{
+				self.readIptcRecords(&bytes[dataIndex .. dataIndex + size]);
+				return;
+			}
+			
+			index = dataIndex + size + (size & 1);
+		}
+	}
+	
+	#[inline(always)]
+	fn readIptcRecords(&mut self, records: &[u8])
+	{
+		const TagMarker: u8 = 0x1C;
+		const ApplicationRecord: u8 = 2;
+		const ByLine: u8 = 80;
+		const Credit: u8 = 110;
+		const CopyrightNotice: u8 = 116;
+		
+		let mut credits = Vec::new();
+		let mut byLines = Vec::new();
+		
+		let mut index = 0;
+		while index + 5 <= records.len() && records[index] == TagMarker
+		{
+			let record = records[index + 1];
+			let dataSet = records[index + 2];
+			let length = ((records[index + 3] as usize) << 8) | (records[index + 4] as usize);
+			
+			// Extended data sets (length with the top bit set) are not used for text.
+			if length & 0x8000 != 0
+			{
+				return;
+			}
+			
+			let dataIndex = index + 5;
+			if dataIndex + length > records.len()
+			{
+				return;
+			}
+			
+			if record == ApplicationRecord
+			{
+				let value = String::from_utf8_lossy(&records[dataIndex .. dataIndex + length]);
+				match dataSet
+				{
+					ByLine => Self::push(&mut byLines, &value),
+					Credit => Self::push(&mut credits, &value),
+					CopyrightNotice => Self::push(&mut self.copyrightNotices, &value),
+					_ => (),
+				}
+			}
+			
+			index = dataIndex + length;
+		}
+		
+		self.credits.extend(credits);
+		self.credits.extend(byLines);
+	}
+	
+	#[inline(always)]
+	fn readXmp(&mut self, bytes: &[u8])
+	{
+		use ::xml::reader::EventReader;
+		use ::xml::reader::XmlEvent::*;
+		
+		const RdfNamespace: &'static str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+		
+		#[derive(Copy, Clone)]
+		enum Property
+		{
+			Credit,
+			Creator,
+			Rights,
+			LicenseUrl,
+		}
+		
+		#[inline(always)]
+		fn propertyFor(namespace: &Option<String>, localName: &str) -> Option<Property>
+		{
+			use self::Property::*;
+			
+			match (namespace.as_ref().map(|namespace| namespace.as_str()), localName)
+			{
+				(Some("http://ns.adobe.com/photoshop/1.0/"), "Credit") => Some(Credit),
+				(Some("http://purl.org/dc/elements/1.1/"), "creator") => Some(Creator),
+				(Some("http://purl.org/dc/elements/1.1/"), "rights") => Some(Rights),
+				(Some("http://ns.adobe.com/xap/1.0/rights/"), "WebStatement") => Some(LicenseUrl),
+				(Some("http://creativecommons.org/ns#"), "license") => Some(LicenseUrl),
+				_ => None,
+			}
+		}
+		
+		let xmp = match Self::find(bytes, Self::XmpStart)
+		{
+			None => return,
+			Some(start) => match Self::find(&bytes[start ..], Self::XmpEnd)
+			{
+				None => return,
+				Some(length) => &bytes[start .. start + length + Self::XmpEnd.len()],
+			}
+		};
+		
+		let mut credits = Vec::new();
+		let mut creators = Vec::new();
+		
+		{
+			let mut record = |property: Property, value: &str, copyrightNotices: &mut Vec<String>, licenseUrls: &mut Vec<String>|
+			{
+				use self::Property::*;
+				
+				match property
+				{
+					Credit => Self::push(&mut credits, value),
+					Creator => Self::push(&mut creators, value),
+					Rights => Self::push(copyrightNotices, value),
+					LicenseUrl => Self::push(licenseUrls, value),
+				}
+			};
+			
+			let mut currentProperty: Option<(Property, usize)> = None;
+			let mut depth = 0;
+			for event in EventReader::new(xmp)
+			{
+				match event
+				{
+					Err(_) => break,
+					
+					Ok(StartElement { name, attributes, .. }) =>
+					{
+						depth += 1;
+						
+						// Properties may be written in the shorthand attribute form on `rdf:Description`.
+						for attribute in attributes.iter()
+						{
+							if let Some(property) = propertyFor(&attribute.name.namespace, &attribute.name.local_name)
+							{
+								record(property, &attribute.value, &mut self.copyrightNotices, &mut self.licenseUrls);
+							}
+						}
+						
+						if currentProperty.is_none()
+						{
+							if let Some(property) = propertyFor(&name.namespace, &name.local_name)
+							{
+								currentProperty = Some((property, depth));
+								
+								for attribute in attributes.iter()
+								{
+									if attribute.name.namespace.as_ref().map(|namespace| namespace.as_str()) == Some(RdfNamespace) && attribute.name.local_name == "resource"
+									{
+										record(property, &attribute.value, &mut self.copyrightNotices, &mut self.licenseUrls);
+									}
+								}
+							}
+						}
+					}
+					
+					Ok(EndElement { .. }) =>
+					{
+						if let Some((_, propertyDepth)) = currentProperty
+						{
+							if propertyDepth == depth
+							{
+								currentProperty = None;
+							}
+						}
+						depth -= 1;
+					}
+					
+					// The first `rdf:li` of an `rdf:Alt` is the default language ('x-default').
+					Ok(Characters(text)) => if let Some((property, _)) = currentProperty
+					{
+						record(property, &text, &mut self.copyrightNotices, &mut self.licenseUrls);
+					},
+					
+					Ok(_) => (),
+				}
+			}
+		}
+		
+		self.credits.extend(credits);
+		self.credits.extend(creators);
+	}
+	
+	#[inline(always)]
+	fn push(into: &mut Vec<String>, value: &str)
+	{
+		let value = value.trim();
+		if !value.is_empty()
+		{
+			into.push(value.to_owned());
+		}
+	}
+	
+	#[inline(always)]
+	fn find(haystack: &[u8], needle: &[u8]) -> Option<usize>
+	{
+		haystack.windows(needle.len()).position(|window| window == needle)
+	}
+}
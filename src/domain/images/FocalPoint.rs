@@ -0,0 +1,230 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// The point of interest in an image, which crops keep in view.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub(crate) enum FocalPoint
+{
+	center,
+	
+	/// Fractions of the width and height from the top-left, eg `{ x: 0.5, y: 0.25 }` is horizontally centered a quarter of the way down.
+	fraction
+	{
+		x: f64,
+		y: f64,
+	},
+	
+	/// The most detailed region of the image, by the Shannon entropy of its luminance.
+	entropy,
+}
+
+impl Default for FocalPoint
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FocalPoint::center
+	}
+}
+
+impl FocalPoint
+{
+	const EntropyGridSize: u32 = 10;
+	
+	const EntropySampleWidth: u32 = 160;
+	
+	/// Resolves an `entropy` focal point to a `fraction` so that it need only be computed once for an image.
+	#[inline(always)]
+	pub(crate) fn resolve(&self, image: &::image::DynamicImage) -> Result<Self, CordialError>
+	{
+		let (x, y) = self.fractions(image)?;
+		Ok(FocalPoint::fraction { x, y })
+	}
+	
+	/// Crops the largest region with the same aspect ratio as `dimensions` that keeps the focal point as close to its center as possible, returning it with the focal point translated into it.
+	#[inline(always)]
+	pub(crate) fn cropToAspectRatio(&self, image: &mut ::image::DynamicImage, dimensions: (u32, u32)) -> Result<Option<(::image::DynamicImage, Self)>, CordialError>
+	{
+		let (width, height) = image.dimensions();
+		let (aspectWidth, aspectHeight) = dimensions;
+		
+		let (cropWidth, cropHeight) = if (width as u64) * (aspectHeight as u64) > (height as u64) * (aspectWidth as u64)
+		{
+			(max(1, ((height as u64 * aspectWidth as u64) / aspectHeight as u64) as u32), height)
+		}
+		else
+		{
+			(width, max(1, ((width as u64 * aspectHeight as u64) / aspectWidth as u64) as u32))
+		};
+		
+		// Ignore differences in aspect ratio caused by rounding when scaling.
+		if (cropWidth as u64) * (cropHeight as u64) * 100 >= (width as u64) * (height as u64) * 99
+		{
+			return Ok(None);
+		}
+		
+		let (x, y) = self.cropOrigin(image, cropWidth, cropHeight)?;
+		let croppedImage = image.crop(x, y, cropWidth, cropHeight);
+		let focalPoint = self.afterCrop(image, x, y, &croppedImage)?;
+		Ok(Some((croppedImage, focalPoint)))
+	}
+	
+	/// An explicit `x` and `y` are used as is; otherwise the one(s) omitted are positioned around the focal point.
+	#[inline(always)]
+	pub(crate) fn cropOriginUnlessExplicit(&self, image: &::image::DynamicImage, x: Option<u32>, y: Option<u32>, cropWidth: u32, cropHeight: u32) -> Result<(u32, u32), CordialError>
+	{
+		match (x, y)
+		{
+			(Some(x), Some(y)) => Ok((x, y)),
+			(x, y) =>
+			{
+				let (focalX, focalY) = self.cropOrigin(image, cropWidth, cropHeight)?;
+				Ok((x.unwrap_or(focalX), y.unwrap_or(focalY)))
+			}
+		}
+	}
+	
+	/// The same point of interest in `croppedImage`, which was cropped from `image` at `x` and `y`; if the point of interest was cropped away, the nearest point on the edge is used.
+	///
+	/// An `entropy` focal point is left to be computed again for the cropped image.
+	#[inline(always)]
+	pub(crate) fn afterCrop(&self, image: &::image::DynamicImage, x: u32, y: u32, croppedImage: &::image::DynamicImage) -> Result<Self, CordialError>
+	{
+		#[inline(always)]
+		fn translate(fraction: f64, length: u32, origin: u32, croppedLength: u32) -> f64
+		{
+			if croppedLength == 0
+			{
+				0.5
+			}
+			else
+			{
+				let center = fraction * (length as f64) - (origin as f64);
+				(center / (croppedLength as f64)).max(0.0).min(1.0)
+			}
+		}
+		
+		if let FocalPoint::entropy = *self
+		{
+			return Ok(FocalPoint::entropy);
+		}
+		
+		let (width, height) = image.dimensions();
+		let (croppedWidth, croppedHeight) = croppedImage.dimensions();
+		let (fractionX, fractionY) = self.fractions(image)?;
+		Ok
+		(
+			FocalPoint::fraction
+			{
+				x: translate(fractionX, width, x, croppedWidth),
+				y: translate(fractionY, height, y, croppedHeight),
+			}
+		)
+	}
+	
+	/// The top-left of a `cropWidth` by `cropHeight` region centered as closely as possible on the focal point.
+	#[inline(always)]
+	pub(crate) fn cropOrigin(&self, image: &::image::DynamicImage, cropWidth: u32, cropHeight: u32) -> Result<(u32, u32), CordialError>
+	{
+		#[inline(always)]
+		fn origin(fraction: f64, length: u32, cropLength: u32) -> u32
+		{
+			if cropLength >= length
+			{
+				0
+			}
+			else
+			{
+				let center = fraction * (length as f64);
+				let origin = center - (cropLength as f64) / 2.0;
+				let maximumOrigin = (length - cropLength) as f64;
+				origin.max(0.0).min(maximumOrigin).round() as u32
+			}
+		}
+		
+		let (width, height) = image.dimensions();
+		let (x, y) = self.fractions(image)?;
+		Ok((origin(x, width, cropWidth), origin(y, height, cropHeight)))
+	}
+	
+	#[inline(always)]
+	fn fractions(&self, image: &::image::DynamicImage) -> Result<(f64, f64), CordialError>
+	{
+		use self::FocalPoint::*;
+		
+		match *self
+		{
+			center => Ok((0.5, 0.5)),
+			
+			fraction { x, y } =>
+			{
+				if x < 0.0 || x > 1.0 || y < 0.0 || y > 1.0
+				{
+					Err(CordialError::Configuration(format!("focal point fractions must be between 0 and 1 inclusive, not '{}' and '{}'", x, y)))
+				}
+				else
+				{
+					Ok((x, y))
+				}
+			}
+			
+			entropy => Ok(Self::entropyFractions(image)),
+		}
+	}
+	
+	/// Divides a downscaled, greyscale copy of the image into a grid, and finds the cell whose neighbourhood has the highest entropy.
+	fn entropyFractions(image: &::image::DynamicImage) -> (f64, f64)
+	{
+		let (width, height) = image.dimensions();
+		let sampleWidth = max(Self::EntropyGridSize, min(width, Self::EntropySampleWidth));
+		let sampleHeight = max(Self::EntropyGridSize, ((height as u64 * sampleWidth as u64) / max(width, 1) as u64) as u32);
+		let sample = image.resize_exact(sampleWidth, sampleHeight, ::image::FilterType::Triangle).to_luma();
+		
+		let gridSize = Self::EntropyGridSize as usize;
+		let mut histograms = vec![[0u32; 32]; gridSize * gridSize];
+		for (x, y, pixel) in sample.enumerate_pixels()
+		{
+			let column = ((x * Self::EntropyGridSize) / sampleWidth) as usize;
+			let row = ((y * Self::EntropyGridSize) / sampleHeight) as usize;
+			histograms[row * gridSize + column][(pixel.data[0] >> 3) as usize] += 1;
+		}
+		
+		let entropies: Vec<f64> = histograms.iter().map(|histogram|
+		{
+			let total = histogram.iter().sum::<u32>() as f64;
+			histogram.iter().filter(|count| **count != 0).map(|count|
+			{
+				let probability = (*count as f64) / total;
+				-probability * probability.log2()
+			}).sum()
+		}).collect();
+		
+		let mut bestCell = (gridSize / 2, gridSize / 2);
+		let mut bestEntropy = ::std::f64::MIN;
+		for row in 0 .. gridSize
+		{
+			for column in 0 .. gridSize
+			{
+				let mut neighbourhoodEntropy = 0.0;
+				for neighbourRow in row.saturating_sub(1) .. min(row + 2, gridSize)
+				{
+					for neighbourColumn in column.saturating_sub(1) .. min(column + 2, gridSize)
+					{
+						neighbourhoodEntropy += entropies[neighbourRow * gridSize + neighbourColumn];
+					}
+				}
+				
+				if neighbourhoodEntropy > bestEntropy
+				{
+					bestEntropy = neighbourhoodEntropy;
+					bestCell = (column, row);
+				}
+			}
+		}
+		
+		let cellFraction = 1.0 / (gridSize as f64);
+		((bestCell.0 as f64 + 0.5) * cellFraction, (bestCell.1 as f64 + 0.5) * cellFraction)
+	}
+}
@@ -0,0 +1,341 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// An ICC color profile embedded in an image file.
+#[derive(Debug, Clone)]
+pub(crate) struct IccProfile(Vec<u8>);
+
+impl IccProfile
+{
+	const JpegIccProfileSignature: &'static [u8] = b"ICC_PROFILE\0";
+	
+	const PngSignature: &'static [u8] = b"\x89PNG\r\n\x1A\n";
+	
+	/// Finds embedded ICC profiles in JPEG (`APP2`), PNG (`iCCP`) and WebP (`ICCP`) files.
+	#[inline(always)]
+	pub(crate) fn read(inputContentFilePath: &Path) -> Result<Option<Self>, CordialError>
+	{
+		let bytes = inputContentFilePath.fileContentsAsBytes().context(inputContentFilePath)?;
+		
+		let profile = if bytes.starts_with(b"\xFF\xD8")
+		{
+			Self::fromJpeg(&bytes)
+		}
+		else if bytes.starts_with(Self::PngSignature)
+		{
+			Self::fromPng(&bytes)
+		}
+		else if bytes.len() >= 12 && &bytes[0 .. 4] == b"RIFF" && &bytes[8 .. 12] == b"WEBP"
+		{
+			Self::fromWebP(&bytes)
+		}
+		else
+		{
+			None
+		};
+		
+		Ok(profile.map(IccProfile))
+	}
+	
+	/// Images whose profile is not for RGB data (eg is for CMYK or greyscale) are left unchanged.
+	#[inline(always)]
+	pub(crate) fn convertToSRgb(&self, image: ::image::DynamicImage, context: &Path) -> Result<::image::DynamicImage, CordialError>
+	{
+		self.convert(image, &::lcms2::Profile::new_srgb(), context)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn convertToDisplayP3(&self, image: ::image::DynamicImage, context: &Path) -> Result<::image::DynamicImage, CordialError>
+	{
+		self.convert(image, &Self::displayP3Profile(context)?, context)
+	}
+	
+	/// True if any of the profile's primaries, converted to sRGB without clipping, lies outside sRGB (eg Display P3 or Adobe RGB, but not the sRGB profile most cameras and editors embed).
+	#[inline(always)]
+	pub(crate) fn isWiderThanSRgb(&self, context: &Path) -> Result<bool, CordialError>
+	{
+		use ::lcms2::ColorSpaceSignature;
+		use ::lcms2::Intent;
+		use ::lcms2::PixelFormat;
+		use ::lcms2::Transform;
+		
+		// Allows for rounding in profiles that are sRGB in all but name.
+		const Tolerance: f32 = 0.01;
+		
+		let error = |reason: String| CordialError::CouldNotConvertColorProfile(context.to_path_buf(), reason);
+		
+		let inputProfile = ::lcms2::Profile::new_icc(&self.0).map_err(|cause| error(cause.to_string()))?;
+		if inputProfile.color_space() != ColorSpaceSignature::RgbData
+		{
+			return Ok(false);
+		}
+		
+		let transform: Transform<[f32; 3], [f32; 3]> = Transform::new(&inputProfile, PixelFormat::RGB_FLT, &::lcms2::Profile::new_srgb(), PixelFormat::RGB_FLT, Intent::RelativeColorimetric).map_err(|cause| error(cause.to_string()))?;
+		
+		let primaries = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+		let mut primariesInSRgb = [[0.0; 3]; 3];
+		transform.transform_pixels(&primaries, &mut primariesInSRgb);
+		
+		Ok(primariesInSRgb.iter().flat_map(|primary| primary.iter()).any(|&channel| channel < -Tolerance || channel > 1.0 + Tolerance))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn displayP3ProfileBytes(context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		Self::displayP3Profile(context)?.icc().map_err(|error| CordialError::CouldNotConvertColorProfile(context.to_path_buf(), error.to_string()))
+	}
+	
+	/// Inserts an `APP2` segment immediately after the JPEG start-of-image marker.
+	pub(crate) fn embedInJpeg(iccProfileBytes: &[u8], jpegBytes: Vec<u8>, context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		const MaximumChunkSize: usize = 65_533 - 14;
+		
+		let chunks: Vec<&[u8]> = iccProfileBytes.chunks(MaximumChunkSize).collect();
+		if chunks.len() > 255 || jpegBytes.len() < 2
+		{
+			return Err(CordialError::CouldNotConvertColorProfile(context.to_path_buf(), "ICC profile is too large to embed in a JPEG".to_owned()));
+		}
+		
+		let mut bytes = Vec::with_capacity(jpegBytes.len() + iccProfileBytes.len() + chunks.len() * 18);
+		bytes.extend_from_slice(&jpegBytes[0 .. 2]);
+		
+		let count = chunks.len() as u8;
+		for (index, chunk) in chunks.iter().enumerate()
+		{
+			let length = 2 + Self::JpegIccProfileSignature.len() + 2 + chunk.len();
+			bytes.extend_from_slice(&[0xFF, 0xE2, (length >> 8) as u8, length as u8]);
+			bytes.extend_from_slice(Self::JpegIccProfileSignature);
+			bytes.push((index + 1) as u8);
+			bytes.push(count);
+			bytes.extend_from_slice(chunk);
+		}
+		
+		bytes.extend_from_slice(&jpegBytes[2 ..]);
+		Ok(bytes)
+	}
+	
+	/// Inserts an `iCCP` chunk immediately after the PNG `IHDR` chunk.
+	pub(crate) fn embedInPng(iccProfileBytes: &[u8], pngBytes: Vec<u8>, context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		const EndOfIhdrChunk: usize = 8 + 4 + 4 + 13 + 4;
+		
+		if !pngBytes.starts_with(Self::PngSignature) || pngBytes.len() < EndOfIhdrChunk
+		{
+			return Err(CordialError::CouldNotConvertColorProfile(context.to_path_buf(), "Not a PNG".to_owned()));
+		}
+		
+		let mut compressedIccProfile = Vec::with_capacity(iccProfileBytes.len());
+		if let Err(error) = ::zopfli::compress(&::zopfli::Options::default(), &::zopfli::Format::Zlib, iccProfileBytes, &mut compressedIccProfile)
+		{
+			return Err(CordialError::CouldNotConvertColorProfile(context.to_path_buf(), error.to_string()));
+		}
+		
+		let mut chunkTypeAndData = Vec::with_capacity(4 + 5 + compressedIccProfile.len());
+		chunkTypeAndData.extend_from_slice(b"iCCP");
+		chunkTypeAndData.extend_from_slice(b"icc\0\0");
+		chunkTypeAndData.extend_from_slice(&compressedIccProfile);
+		
+		let dataLength = (chunkTypeAndData.len() - 4) as u32;
+		let crc = Self::crc32(&chunkTypeAndData);
+		
+		let mut bytes = Vec::with_capacity(pngBytes.len() + chunkTypeAndData.len() + 8);
+		bytes.extend_from_slice(&pngBytes[0 .. EndOfIhdrChunk]);
+		bytes.extend_from_slice(&Self::bigEndianU32(dataLength));
+		bytes.extend_from_slice(&chunkTypeAndData);
+		bytes.extend_from_slice(&Self::bigEndianU32(crc));
+		bytes.extend_from_slice(&pngBytes[EndOfIhdrChunk ..]);
+		Ok(bytes)
+	}
+	
+	fn convert(&self, image: ::image::DynamicImage, outputProfile: &::lcms2::Profile, context: &Path) -> Result<::image::DynamicImage, CordialError>
+	{
+		use ::lcms2::ColorSpaceSignature;
+		use ::lcms2::Intent;
+		use ::lcms2::PixelFormat;
+		use ::lcms2::Transform;
+		use ::rgb::FromSlice;
+		use ::rgb::RGBA8;
+		
+		let error = |reason: String| CordialError::CouldNotConvertColorProfile(context.to_path_buf(), reason);
+		
+		let inputProfile = ::lcms2::Profile::new_icc(&self.0).map_err(|cause| error(cause.to_string()))?;
+		if inputProfile.color_space() != ColorSpaceSignature::RgbData
+		{
+			return Ok(image);
+		}
+		
+		let transform: Transform<RGBA8, RGBA8> = Transform::new(&inputProfile, PixelFormat::RGBA_8, outputProfile, PixelFormat::RGBA_8, Intent::Perceptual).map_err(|cause| error(cause.to_string()))?;
+		
+		let mut rgba = image.to_rgba();
+		transform.transform_in_place(rgba.as_rgba_mut());
+		Ok(::image::DynamicImage::ImageRgba8(rgba))
+	}
+	
+	/// Display P3: DCI-P3 primaries with a D65 white point and the sRGB transfer function.
+	#[inline(always)]
+	fn displayP3Profile(context: &Path) -> Result<::lcms2::Profile, CordialError>
+	{
+		use ::lcms2::CIExyY;
+		use ::lcms2::CIExyYTRIPLE;
+		use ::lcms2::Profile;
+		use ::lcms2::ToneCurve;
+		
+		let error = |reason: String| CordialError::CouldNotConvertColorProfile(context.to_path_buf(), reason);
+		
+		let whitePoint = CIExyY { x: 0.3127, y: 0.3290, Y: 1.0 };
+		let primaries = CIExyYTRIPLE
+		{
+			Red: CIExyY { x: 0.680, y: 0.320, Y: 1.0 },
+			Green: CIExyY { x: 0.265, y: 0.690, Y: 1.0 },
+			Blue: CIExyY { x: 0.150, y: 0.060, Y: 1.0 },
+		};
+		
+		let sRgbTransferFunction = ToneCurve::new_parametric(4, &[2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045]).map_err(|cause| error(cause.to_string()))?;
+		
+		Profile::new_rgb(&whitePoint, &primaries, &[&sRgbTransferFunction, &sRgbTransferFunction, &sRgbTransferFunction]).map_err(|cause| error(cause.to_string()))
+	}
+	
+	fn fromJpeg(bytes: &[u8]) -> Option<Vec<u8>>
+	{
+		const StartOfScan: u8 = 0xDA;
+		const EndOfImage: u8 = 0xD9;
+		const App2: u8 = 0xE2;
+		
+		let mut chunks = Vec::new();
+		
+		let mut index = 2;
+		while index + 4 <= bytes.len() && bytes[index] == 0xFF
+		{
+			let marker = bytes[index + 1];
+			if marker == StartOfScan || marker == EndOfImage
+			{
+				break;
+			}
+			
+			let length = ((bytes[index + 2] as usize) << 8) | (bytes[index + 3] as usize);
+			if length < 2 || index + 2 + length > bytes.len()
+			{
+				break;
+			}
+			
+			let data = &bytes[index + 4 .. index + 2 + length];
+			let signatureLength = Self::JpegIccProfileSignature.len();
+			if marker == App2 && data.len() > signatureLength + 2 && data.starts_with(Self::JpegIccProfileSignature)
+			{
+				let sequenceNumber = data[signatureLength];
+				chunks.push((sequenceNumber, &data[signatureLength + 2 ..]));
+			}
+			
+			index += 2 + length;
+		}
+		
+		if chunks.is_empty()
+		{
+			return None;
+		}
+		
+		chunks.sort_by_key(|&(sequenceNumber, _)| sequenceNumber);
+		let mut profile = Vec::new();
+		for (_, chunk) in chunks
+		{
+			profile.extend_from_slice(chunk);
+		}
+		Some(profile)
+	}
+	
+	fn fromPng(bytes: &[u8]) -> Option<Vec<u8>>
+	{
+		let mut index = Self::PngSignature.len();
+		while index + 12 <= bytes.len()
+		{
+			let length = Self::readBigEndianU32(&bytes[index .. index + 4]) as usize;
+			let chunkType = &bytes[index + 4 .. index + 8];
+			let dataIndex = index + 8;
+			if dataIndex + length + 4 > bytes.len() || chunkType == b"IDAT"
+			{
+				return None;
+			}
+			
+			if chunkType == b"iCCP"
+			{
+				let data = &bytes[dataIndex .. dataIndex + length];
+				
+				// Profile name, null terminator, compression method (always zero, zlib), compressed profile.
+				let compressedProfile = match data.iter().position(|byte| *byte == 0)
+				{
+					Some(endOfName) if endOfName + 2 <= data.len() => &data[endOfName + 2 ..],
+					_ => return None,
+				};
+				
+				let mut profile = Vec::new();
+				return match ::flate2::read::ZlibDecoder::new(compressedProfile).read_to_end(&mut profile)
+				{
+					Err(_) => None,
+					Ok(_) => Some(profile),
+				};
+			}
+			
+			index = dataIndex + length + 4;
+		}
+		None
+	}
+	
+	fn fromWebP(bytes: &[u8]) -> Option<Vec<u8>>
+	{
+		let mut index = 12;
+		while index + 8 <= bytes.len()
+		{
+			let fourCc = &bytes[index .. index + 4];
+			let size = (bytes[index + 4] as usize) | ((bytes[index + 5] as usize) << 8) | ((bytes[index + 6] as usize) << 16) | ((bytes[index + 7] as usize) << 24);
+			let dataIndex = index + 8;
+			if dataIndex + size > bytes.len()
+			{
+				return None;
+			}
+			
+			if fourCc == b"ICCP"
+			{
+				return Some(bytes[dataIndex .. dataIndex + size].to_vec());
+			}
+			
+			index = dataIndex + size + (size & 1);
+		}
+		None
+	}
+	
+	#[inline(always)]
+	fn readBigEndianU32(bytes: &[u8]) -> u32
+	{
+		((bytes[0] as u32) << 24) | ((bytes[1] as u32) << 16) | ((bytes[2] as u32) << 8) | (bytes[3] as u32)
+	}
+	
+	#[inline(always)]
+	fn bigEndianU32(value: u32) -> [u8; 4]
+	{
+		[(value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8, value as u8]
+	}
+	
+	/// The CRC-32 used by PNG chunks (ISO 3309).
+	#[inline(always)]
+	fn crc32(bytes: &[u8]) -> u32
+	{
+		let mut crc = 0xFFFF_FFFFu32;
+		for byte in bytes.iter()
+		{
+			crc ^= *byte as u32;
+			for _ in 0 .. 8
+			{
+				crc = if crc & 1 == 1
+				{
+					0xEDB8_8320 ^ (crc >> 1)
+				}
+				else
+				{
+					crc >> 1
+				};
+			}
+		}
+		crc ^ 0xFFFF_FFFF
+	}
+}
@@ -0,0 +1,136 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Additional formats to encode every width of an image's source set into.
+/// Browsers that support them select them using a `<picture>` element with `<source type="...">` children; the original format remains the fallback `<img>`.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub(crate) enum ImageAlternativeFormat
+{
+	webp
+	{
+		#[serde(default = "ImageAlternativeFormat::webp_quality_default")] quality: u8,
+		#[serde(default)] lossless: bool,
+	},
+	
+	avif
+	{
+		#[serde(default = "ImageAlternativeFormat::avif_quality_default")] quality: u8,
+		#[serde(default = "ImageAlternativeFormat::avif_speed_default")] speed: u8,
+	},
+}
+
+impl ImageAlternativeFormat
+{
+	#[inline(always)]
+	pub(crate) fn mimeType(&self) -> &'static str
+	{
+		use self::ImageAlternativeFormat::*;
+		
+		match *self
+		{
+			webp { .. } => "image/webp",
+			avif { .. } => "image/avif",
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn contentType(&self) -> ContentType
+	{
+		use self::ImageAlternativeFormat::*;
+		
+		match *self
+		{
+			webp { .. } => content_type_image_webp(),
+			avif { .. } => content_type_image_avif(),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn fileExtension(&self) -> &'static str
+	{
+		use self::ImageAlternativeFormat::*;
+		
+		match *self
+		{
+			webp { .. } => ".webp",
+			avif { .. } => ".avif",
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn resourceTag(&self, width: u32) -> ResourceTag
+	{
+		use self::ImageAlternativeFormat::*;
+		
+		match *self
+		{
+			webp { .. } => ResourceTag::webp_image(width),
+			avif { .. } => ResourceTag::avif_image(width),
+		}
+	}
+	
+	pub(crate) fn encode(&self, image: &::image::DynamicImage, context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		use self::ImageAlternativeFormat::*;
+		
+		match *self
+		{
+			webp { quality, lossless } =>
+			{
+				let (width, height) = image.dimensions();
+				if width > MaximumWebPDimension || height > MaximumWebPDimension
+				{
+					return Err(CordialError::ImageTooLargeForWebP(context.to_path_buf(), width, height));
+				}
+				
+				match encodeWebP(image, min(quality, 100), lossless)
+				{
+					None => Err(CordialError::CouldNotEncodeWebP(context.to_path_buf())),
+					Some(bytes) => Ok(bytes),
+				}
+			}
+			
+			avif { quality, speed } =>
+			{
+				use ::rgb::FromSlice;
+				
+				let (width, height) = image.dimensions();
+				let rgba = image.to_rgba();
+				
+				let configuration = ::ravif::Config
+				{
+					quality: min(quality, 100),
+					speed: min(speed, 10),
+					alpha_quality: min(quality, 100),
+					premultiplied_alpha: false,
+				};
+				
+				match ::ravif::encode_rgba(::imgref::Img::new(rgba.as_rgba(), width as usize, height as usize), &configuration)
+				{
+					Err(error) => Err(CordialError::CouldNotEncodeAvif(context.to_path_buf(), error.to_string())),
+					Ok((bytes, _alphaSize)) => Ok(bytes),
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn webp_quality_default() -> u8
+	{
+		DefaultWebPQuality
+	}
+	
+	#[inline(always)]
+	fn avif_quality_default() -> u8
+	{
+		70
+	}
+	
+	#[inline(always)]
+	fn avif_speed_default() -> u8
+	{
+		4
+	}
+}
@@ -0,0 +1,99 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// An alternative crop or transformation of a raster image for a media query, eg a square crop for narrow screens.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct ImageArtDirection
+{
+	/// eg `(max-width: 599px)`.
+	media: String,
+	
+	/// Suffixed to the image's URLs, eg `square` produces `/image-square.jpg`.
+	name: String,
+	
+	/// Width then height, eg `(1, 1)` for square; cropped around the focal point before any `transformations`.
+	#[serde(default)] aspect_ratio: Option<(u32, u32)>,
+	
+	#[serde(default)] transformations: Vec<ImageTransformation>,
+	
+	#[serde(default)] source_set_excluding_original: Vec<ImageSourceSetEntry>,
+}
+
+impl ImageArtDirection
+{
+	#[inline(always)]
+	pub(crate) fn imageSourceSet<'a>(&self, artDirectionIndex: usize, imageSourceSet: &ImageSourceSet<'a>, artDirectedImage: ::image::DynamicImage, focalPoint: &FocalPoint) -> Result<ImageSourceSet<'a>, CordialError>
+	{
+		let mut artDirectedImageSourceSet = imageSourceSet.artDirected(artDirectionIndex, &self.name, artDirectedImage);
+		artDirectedImageSourceSet.add(&self.source_set_excluding_original, focalPoint)?;
+		Ok(artDirectedImageSourceSet)
+	}
+	
+	/// Names are suffixed to the image's URLs, so must not collide with the suffixes used for widths (eg `-100w`) or the wide gamut variant (`-p3`).
+	#[inline(always)]
+	pub(crate) fn validatedName(&self) -> Result<&str, String>
+	{
+		let name = self.name.as_str();
+		
+		if name.is_empty()
+		{
+			return Err("art direction name can not be empty".to_owned());
+		}
+		
+		if name.contains('/')
+		{
+			return Err(format!("art direction name '{}' can not contain '/'", name));
+		}
+		
+		let isWidthSuffix = name.ends_with('w') && name.len() > 1 && name[.. name.len() - 1].bytes().all(|byte| byte.is_ascii_digit());
+		if name == "p3" || isWidthSuffix
+		{
+			return Err(format!("art direction name '{}' is reserved", name));
+		}
+		
+		Ok(name)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn transformations(&self) -> &[ImageTransformation]
+	{
+		&self.transformations
+	}
+	
+	#[inline(always)]
+	pub(crate) fn media(&self) -> &str
+	{
+		&self.media
+	}
+	
+	/// Returns the art directed image and its focal point, translated by any crops.
+	#[inline(always)]
+	pub(crate) fn artDirectedImage(&self, primaryImage: &::image::DynamicImage, focalPoint: &FocalPoint, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<(::image::DynamicImage, FocalPoint), CordialError>
+	{
+		let mut image = primaryImage.clone();
+		let mut focalPoint = *focalPoint;
+		
+		if let Some(aspectRatio) = self.aspect_ratio
+		{
+			if aspectRatio.0 == 0 || aspectRatio.1 == 0
+			{
+				return Err(CordialError::Configuration(format!("art direction '{}' aspect_ratio can not have a zero width or height", self.name)));
+			}
+			
+			let croppedImage = focalPoint.cropToAspectRatio(&mut image, aspectRatio)?;
+			if let Some((croppedImage, croppedFocalPoint)) = croppedImage
+			{
+				image = croppedImage;
+				focalPoint = croppedFocalPoint;
+			}
+		}
+		
+		match ImageTransformation::applyTransformations(&image, &self.transformations, &mut focalPoint, resources, configuration, iso639Dash1Alpha2Language)?
+		{
+			None => Ok((image, focalPoint)),
+			Some(transformedImage) => Ok((transformedImage, focalPoint)),
+		}
+	}
+}
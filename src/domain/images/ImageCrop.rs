@@ -2,12 +2,13 @@
 // Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
 
 
+/// If `x` or `y` are omitted, the crop is positioned around the image's focal point.
 #[serde(deny_unknown_fields)]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct ImageCrop
 {
-	x: u32,
-	y: u32,
+	#[serde(default)] x: Option<u32>,
+	#[serde(default)] y: Option<u32>,
 	width: u32,
 	height: u32,
 }
@@ -15,50 +16,32 @@ pub(crate) struct ImageCrop
 impl ImageCrop
 {
 	#[inline(always)]
-	fn crop(&self, image: &mut ::image::DynamicImage) -> ::image::DynamicImage
+	fn crop(&self, image: &mut ::image::DynamicImage, focalPoint: &FocalPoint) -> Result<(::image::DynamicImage, FocalPoint), CordialError>
 	{
-		image.crop(self.x, self.y, self.width, self.height)
+		let (x, y) = focalPoint.cropOriginUnlessExplicit(image, self.x, self.y, self.width, self.height)?;
+		let croppedImage = image.crop(x, y, self.width, self.height);
+		let focalPoint = focalPoint.afterCrop(image, x, y, &croppedImage)?;
+		Ok((croppedImage, focalPoint))
 	}
 	
 	#[inline(always)]
 	fn dimensionsAfterCrop(&self, dimensions: (u32, u32)) -> (u32, u32)
 	{
-		let (beforeWidth, beforeHeight) = dimensions;
-		
-		let afterWidth = if self.x >= beforeWidth
-		{
-			0
-		}
-		else
+		#[inline(always)]
+		fn afterLength(beforeLength: u32, origin: Option<u32>, length: u32) -> u32
 		{
-			let maximumAfterWidth = beforeWidth - self.x;
-			if self.width >= maximumAfterWidth
+			let origin = origin.unwrap_or(0);
+			if origin >= beforeLength
 			{
-				maximumAfterWidth
+				0
 			}
 			else
 			{
-				self.width
+				min(length, beforeLength - origin)
 			}
-		};
-		
-		let afterHeight = if self.y >= beforeHeight
-		{
-			0
 		}
-		else
-		{
-			let maximumAfterHeight = beforeHeight - self.x;
-			if self.height >= maximumAfterHeight
-			{
-				maximumAfterHeight
-			}
-			else
-			{
-				self.height
-			}
-		};
 		
-		(afterWidth, afterHeight)
+		let (beforeWidth, beforeHeight) = dimensions;
+		(afterLength(beforeWidth, self.x, self.width), afterLength(beforeHeight, self.y, self.height))
 	}
 }
@@ -0,0 +1,15 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// How a raster image's source set is encoded.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ImageEncoderSettings<'a>
+{
+	/// If present, images are encoded as JPEG rather than PNG.
+	pub(crate) jpegQuality: Option<u8>,
+	pub(crate) jpegSpeedOverCompression: bool,
+	pub(crate) jpegEncoder: &'a JpegEncoder,
+	pub(crate) pngQuantization: Option<&'a PngQuantization>,
+	pub(crate) alternativeFormats: &'a [ImageAlternativeFormat],
+}
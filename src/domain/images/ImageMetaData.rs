@@ -20,6 +20,9 @@ pub(crate) struct ImageMetaData
     	Used in either the img attribute sizes or the picture attribute sizes
 	*/
 	#[serde(default)] pub(crate) sizes: Option<(Vec<MediaQueryAndLength>, Length)>,
+	
+	/// Used by every crop of a raster image, including those in its source set.
+	#[serde(default)] pub(crate) focal_point: FocalPoint,
 
 	#[serde(default)] pub(crate) id: Option<String>,
 	#[serde(default)] pub(crate) classes: Vec<String>,
@@ -31,6 +34,9 @@ pub(crate) struct ImageMetaData
 	#[serde(default)] pub(crate) long_description: Option<LongDescription>,
 	
 	#[serde(default)] pub(crate) facebook_open_graph_user_generated: bool,
+	
+	/// Imported from the embedded metadata of the input file rendered for each language, if `import_credit_and_license_url_from_embedded_metadata` is set.
+	#[serde(default, skip_deserializing, skip_serializing)] embeddedCreditsAndLicenseUrls: HashMap<Iso639Dash1Alpha2Language, Rc<EmbeddedCreditAndLicenseUrl>>,
 }
 
 impl Default for ImageMetaData
@@ -44,6 +50,7 @@ impl Default for ImageMetaData
 			license_url: Default::default(),
 			credit: Default::default(),
 			sizes: None,
+			focal_point: Default::default(),
 			id: None,
 			classes: Default::default(),
 			is_server_side_map: false,
@@ -52,6 +59,7 @@ impl Default for ImageMetaData
 			referrer_policy: Default::default(),
 			long_description: None,
 			facebook_open_graph_user_generated: false,
+			embeddedCreditsAndLicenseUrls: Default::default(),
 		}
 	}
 }
@@ -88,33 +96,74 @@ impl ImageMetaData
 		Ok(&self.imageAbstractWithFallback(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?.alt)
 	}
 	
+	/// Records the credit and license URL embedded in the input file rendered for a language; they are only used if `credit` or `license_url` is missing from the resource's configuration.
+	#[inline(always)]
+	pub(crate) fn importCreditAndLicenseUrl(&mut self, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, embeddedCreditAndLicenseUrl: Rc<EmbeddedCreditAndLicenseUrl>)
+	{
+		self.embeddedCreditsAndLicenseUrls.insert(iso639Dash1Alpha2Language, embeddedCreditAndLicenseUrl);
+	}
+	
+	#[inline(always)]
+	pub(crate) fn credit(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> &FullName
+	{
+		if self.credit.is_empty()
+		{
+			if let Some(&Some(ref credit)) = self.embeddedCreditAndLicenseUrl(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language).map(|embeddedCreditAndLicenseUrl| &embeddedCreditAndLicenseUrl.credit)
+			{
+				return credit;
+			}
+		}
+		&self.credit
+	}
+	
 	#[inline(always)]
 	pub(crate) fn licenseUrlAndAnchorTitleAttribute<'resources>(&self, resources: &'resources Resources, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<(Rc<Url>, Rc<String>), CordialError>
 	{
+		let (licenseUrl, externalLicenseUrl) = self.licenseUrl(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language);
+		
+		if let Some(externalLicenseUrl) = externalLicenseUrl
+		{
+			return Ok((externalLicenseUrl.clone(), Rc::new(externalLicenseUrl.as_str().to_owned())));
+		}
+		
 		ResourceReference
 		{
-			resource: self.license_url.clone(),
+			resource: licenseUrl.clone(),
 			tag: ResourceTag::default
 		}.urlAndAnchorTitleAttribute(resources, fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)
 	}
 	
-	//noinspection SpellCheckingInspection
+	/// The configured `license_url`, otherwise that embedded in the input file, or, failing that, an external license URL embedded in it.
 	#[inline(always)]
-	pub(crate) fn addToImgAttributes(&self, imgAttributes: &mut Vec<Attribute>, resources: &Resources, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Option<Iso639Dash1Alpha2Language>, isForAmp: bool) -> Result<(), CordialError>
+	fn licenseUrl(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> (&ResourceUrl, Option<&Rc<Url>>)
 	{
-		if let Some(ref sizes) = self.sizes
+		if self.license_url == ResourceUrl::default()
 		{
-			let mut sizesAttribute = String::new();
-			for size in sizes.0.iter()
+			if let Some(embeddedCreditAndLicenseUrl) = self.embeddedCreditAndLicenseUrl(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)
 			{
-				sizesAttribute.push_str(&size.media);
-				sizesAttribute.push(' ');
-				sizesAttribute.push_str(&size.length);
-				sizesAttribute.push(',');
+				if let Some(ref licenseUrl) = embeddedCreditAndLicenseUrl.licenseUrl
+				{
+					return (licenseUrl, None);
+				}
+				return (&self.license_url, embeddedCreditAndLicenseUrl.externalLicenseUrl.as_ref());
 			}
-			sizesAttribute.push_str(&sizes.1);
-			
-			imgAttributes.push("sizes".string_attribute(sizesAttribute));
+		}
+		(&self.license_url, None)
+	}
+	
+	#[inline(always)]
+	fn embeddedCreditAndLicenseUrl(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Option<&Rc<EmbeddedCreditAndLicenseUrl>>
+	{
+		self.embeddedCreditsAndLicenseUrls.get(&iso639Dash1Alpha2Language).or_else(|| self.embeddedCreditsAndLicenseUrls.get(&fallbackIso639Dash1Alpha2Language))
+	}
+	
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	pub(crate) fn addToImgAttributes(&self, imgAttributes: &mut Vec<Attribute>, resources: &Resources, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Option<Iso639Dash1Alpha2Language>, isForAmp: bool) -> Result<(), CordialError>
+	{
+		if let Some(sizesAttribute) = self.sizesAttribute()
+		{
+			imgAttributes.push(sizesAttribute);
 		}
 		
 		if let Some(ref id) = self.id
@@ -155,6 +204,29 @@ impl ImageMetaData
 		Ok(())
 	}
 	
+	#[inline(always)]
+	pub(crate) fn sizesAttribute(&self) -> Option<Attribute>
+	{
+		match self.sizes
+		{
+			None => None,
+			Some(ref sizes) =>
+			{
+				let mut sizesAttribute = String::new();
+				for size in sizes.0.iter()
+				{
+					sizesAttribute.push_str(&size.media);
+					sizesAttribute.push(' ');
+					sizesAttribute.push_str(&size.length);
+					sizesAttribute.push(',');
+				}
+				sizesAttribute.push_str(&sizes.1);
+				
+				Some("sizes".string_attribute(sizesAttribute))
+			}
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn anchorTitleAttribute(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<Rc<String>>, CordialError>
 	{
@@ -166,6 +238,7 @@ impl ImageMetaData
 	pub(crate) fn siteMapWebPageImage(&self, url: ResourceReference, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<SiteMapWebPageImage, CordialError>
 	{
 		let imageAbstract = self.imageAbstractWithFallback(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?;
+		let (licenseUrl, externalLicenseUrl) = self.licenseUrl(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language);
 		
 		Ok
 		(
@@ -174,9 +247,10 @@ impl ImageMetaData
 				url,
 				licenseUrl: ResourceReference
 				{
-					resource: self.license_url.clone(),
+					resource: licenseUrl.clone(),
 					tag: ResourceTag::default,
 				},
+				externalLicenseUrl: externalLicenseUrl.cloned(),
 				imageAbstract: imageAbstract.clone(),
 			}
 		)
@@ -0,0 +1,166 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// A placeholder computed at build time, shown whilst the real image loads.
+#[derive(Serialize, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub(crate) struct ImagePlaceholder
+{
+	/// A tiny, blurred JPEG (a 'Low Quality Image Placeholder'), as a `data:` URI.
+	dataUri: Option<String>,
+	
+	/// A BlurHash string (see https://blurha.sh/); needs client-side JavaScript to decode.
+	blurHash: Option<String>,
+}
+
+impl ImagePlaceholder
+{
+	#[inline(always)]
+	pub(crate) fn dataUri(&self) -> Option<&str>
+	{
+		self.dataUri.as_ref().map(|dataUri| dataUri.as_str())
+	}
+	
+	#[inline(always)]
+	pub(crate) fn blurHash(&self) -> Option<&str>
+	{
+		self.blurHash.as_ref().map(|blurHash| blurHash.as_str())
+	}
+	
+	#[inline(always)]
+	pub(crate) fn addToImgAttributes(&self, attributes: &mut Vec<Attribute>)
+	{
+		if let Some(ref dataUri) = self.dataUri
+		{
+			attributes.push("style".string_attribute(format!("background-size:cover;background-image:url({})", dataUri)));
+		}
+		
+		if let Some(ref blurHash) = self.blurHash
+		{
+			attributes.push("data-blurhash".str_attribute(blurHash));
+		}
+	}
+	
+	#[inline(always)]
+	fn jpegDataUri(image: &::image::DynamicImage, quality: u8, context: &Path) -> Result<String, CordialError>
+	{
+		let (width, height) = image.dimensions();
+		let rgb = image.to_rgb();
+		
+		let mut jpegBytes = Vec::with_capacity(1024);
+		{
+			let mut jpegEncoder = JPEGEncoder::new_with_quality(&mut jpegBytes, quality);
+			jpegEncoder.encode(&rgb, width, height, ::image::ColorType::RGB(8)).context(context)?;
+		}
+		
+		Ok(format!("data:image/jpeg;base64,{}", base64Encode(&jpegBytes, ::base64::STANDARD)))
+	}
+	
+	/// An implementation of the BlurHash encoding algorithm, as described at https://github.com/woltapp/blurhash/blob/master/Algorithm.md.
+	fn encodeBlurHash(image: &::image::DynamicImage, xComponents: u32, yComponents: u32) -> String
+	{
+		use ::std::f64::consts::PI;
+		
+		let (width, height) = image.dimensions();
+		let rgba = image.to_rgba();
+		
+		let mut factors = Vec::with_capacity((xComponents * yComponents) as usize);
+		for yComponent in 0 .. yComponents
+		{
+			for xComponent in 0 .. xComponents
+			{
+				let normalisation = if xComponent == 0 && yComponent == 0 { 1.0 } else { 2.0 };
+				
+				let mut factor = (0.0, 0.0, 0.0);
+				for (x, y, pixel) in rgba.enumerate_pixels()
+				{
+					let basis = normalisation * (PI * (xComponent * x) as f64 / width as f64).cos() * (PI * (yComponent * y) as f64 / height as f64).cos();
+					factor.0 += basis * Self::sRgbToLinear(pixel.data[0]);
+					factor.1 += basis * Self::sRgbToLinear(pixel.data[1]);
+					factor.2 += basis * Self::sRgbToLinear(pixel.data[2]);
+				}
+				
+				let scale = 1.0 / (width * height) as f64;
+				factors.push((factor.0 * scale, factor.1 * scale, factor.2 * scale));
+			}
+		}
+		
+		let mut blurHash = String::with_capacity(4 + 2 * factors.len());
+		
+		let sizeFlag = (xComponents - 1) + (yComponents - 1) * 9;
+		Self::base83Encode(sizeFlag, 1, &mut blurHash);
+		
+		let (dc, ac) = factors.split_first().unwrap();
+		
+		let maximumValue = if ac.is_empty()
+		{
+			Self::base83Encode(0, 1, &mut blurHash);
+			1.0
+		}
+		else
+		{
+			let actualMaximumValue = ac.iter().fold(0.0f64, |maximum, &(red, green, blue)| maximum.max(red.abs()).max(green.abs()).max(blue.abs()));
+			let quantisedMaximumValue = (actualMaximumValue * 166.0 - 0.5).floor().min(82.0).max(0.0) as u32;
+			Self::base83Encode(quantisedMaximumValue, 1, &mut blurHash);
+			(quantisedMaximumValue + 1) as f64 / 166.0
+		};
+		
+		let encodedDc = (Self::linearToSRgb(dc.0) << 16) + (Self::linearToSRgb(dc.1) << 8) + Self::linearToSRgb(dc.2);
+		Self::base83Encode(encodedDc, 4, &mut blurHash);
+		
+		for &(red, green, blue) in ac.iter()
+		{
+			let quantise = |value: f64|
+			{
+				let normalised = value / maximumValue;
+				let signedSquareRoot = normalised.signum() * normalised.abs().sqrt();
+				(signedSquareRoot * 9.0 + 9.5).floor().min(18.0).max(0.0) as u32
+			};
+			
+			let encodedAc = quantise(red) * 19 * 19 + quantise(green) * 19 + quantise(blue);
+			Self::base83Encode(encodedAc, 2, &mut blurHash);
+		}
+		
+		blurHash
+	}
+	
+	#[inline(always)]
+	fn sRgbToLinear(value: u8) -> f64
+	{
+		let value = value as f64 / 255.0;
+		if value <= 0.04045
+		{
+			value / 12.92
+		}
+		else
+		{
+			((value + 0.055) / 1.055).powf(2.4)
+		}
+	}
+	
+	#[inline(always)]
+	fn linearToSRgb(value: f64) -> u32
+	{
+		let value = value.min(1.0).max(0.0);
+		if value <= 0.0031308
+		{
+			(value * 12.92 * 255.0 + 0.5) as u32
+		}
+		else
+		{
+			((1.055 * value.powf(1.0 / 2.4) - 0.055) * 255.0 + 0.5) as u32
+		}
+	}
+	
+	#[inline(always)]
+	fn base83Encode(value: u32, length: u32, blurHash: &mut String)
+	{
+		const Characters: &'static [u8; 83] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+		
+		for index in 1 .. (length + 1)
+		{
+			let digit = (value / 83u32.pow(length - index)) % 83;
+			blurHash.push(Characters[digit as usize] as char);
+		}
+	}
+}
@@ -0,0 +1,141 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct ImagePlaceholderSettings
+{
+	#[serde(default = "ImagePlaceholderSettings::data_uri_default")] data_uri: bool,
+	#[serde(default = "ImagePlaceholderSettings::data_uri_width_default")] data_uri_width: u32,
+	#[serde(default = "ImagePlaceholderSettings::data_uri_blur_sigma_default")] data_uri_blur_sigma: f32,
+	#[serde(default = "ImagePlaceholderSettings::data_uri_jpeg_quality_default")] data_uri_jpeg_quality: u8,
+	#[serde(default = "ImagePlaceholderSettings::blur_hash_default")] blur_hash: bool,
+	#[serde(default = "ImagePlaceholderSettings::blur_hash_x_components_default")] blur_hash_x_components: u8,
+	#[serde(default = "ImagePlaceholderSettings::blur_hash_y_components_default")] blur_hash_y_components: u8,
+}
+
+impl Default for ImagePlaceholderSettings
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			data_uri: Self::data_uri_default(),
+			data_uri_width: Self::data_uri_width_default(),
+			data_uri_blur_sigma: Self::data_uri_blur_sigma_default(),
+			data_uri_jpeg_quality: Self::data_uri_jpeg_quality_default(),
+			blur_hash: Self::blur_hash_default(),
+			blur_hash_x_components: Self::blur_hash_x_components_default(),
+			blur_hash_y_components: Self::blur_hash_y_components_default(),
+		}
+	}
+}
+
+impl ImagePlaceholderSettings
+{
+	const BlurHashSampleWidth: u32 = 32;
+	
+	#[inline(always)]
+	pub(crate) fn placeholder(&self, primaryImage: &::image::DynamicImage, context: &Path) -> Result<Option<Rc<ImagePlaceholder>>, CordialError>
+	{
+		if !self.data_uri && !self.blur_hash
+		{
+			return Ok(None);
+		}
+		
+		let dataUri = if self.data_uri
+		{
+			let tinyImage = Self::downscale(primaryImage, self.data_uri_width).blur(self.data_uri_blur_sigma);
+			Some(ImagePlaceholder::jpegDataUri(&tinyImage, min(self.data_uri_jpeg_quality, 100), context)?)
+		}
+		else
+		{
+			None
+		};
+		
+		let blurHash = if self.blur_hash
+		{
+			let xComponents = self.blur_hash_x_components;
+			let yComponents = self.blur_hash_y_components;
+			if xComponents < 1 || xComponents > 9 || yComponents < 1 || yComponents > 9
+			{
+				return Err(CordialError::Configuration("blur_hash_x_components and blur_hash_y_components must be between 1 and 9 inclusive".to_owned()));
+			}
+			
+			let sampleImage = Self::downscale(primaryImage, Self::BlurHashSampleWidth);
+			Some(ImagePlaceholder::encodeBlurHash(&sampleImage, xComponents as u32, yComponents as u32))
+		}
+		else
+		{
+			None
+		};
+		
+		Ok
+		(
+			Some
+			(
+				Rc::new
+				(
+					ImagePlaceholder
+					{
+						dataUri,
+						blurHash,
+					}
+				)
+			)
+		)
+	}
+	
+	#[inline(always)]
+	fn downscale(image: &::image::DynamicImage, maximumWidth: u32) -> ::image::DynamicImage
+	{
+		let (width, height) = image.dimensions();
+		let newWidth = max(1, min(width, maximumWidth));
+		let newHeight = max(1, ((height as u64 * newWidth as u64) / (max(width, 1) as u64)) as u32);
+		image.resize_exact(newWidth, newHeight, ::image::FilterType::Triangle)
+	}
+	
+	#[inline(always)]
+	fn data_uri_default() -> bool
+	{
+		true
+	}
+	
+	#[inline(always)]
+	fn data_uri_width_default() -> u32
+	{
+		16
+	}
+	
+	#[inline(always)]
+	fn data_uri_blur_sigma_default() -> f32
+	{
+		1.0
+	}
+	
+	#[inline(always)]
+	fn data_uri_jpeg_quality_default() -> u8
+	{
+		40
+	}
+	
+	#[inline(always)]
+	fn blur_hash_default() -> bool
+	{
+		true
+	}
+	
+	#[inline(always)]
+	fn blur_hash_x_components_default() -> u8
+	{
+		4
+	}
+	
+	#[inline(always)]
+	fn blur_hash_y_components_default() -> u8
+	{
+		3
+	}
+}
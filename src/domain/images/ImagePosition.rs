@@ -0,0 +1,67 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Where an overlay or text is placed on an image; offset inwards from the edges by a margin.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub(crate) enum ImagePosition
+{
+	top_left,
+	top,
+	top_right,
+	left,
+	center,
+	right,
+	bottom_left,
+	bottom,
+	bottom_right,
+	
+	/// Pixels from the top left; `margin` is ignored.
+	at
+	{
+		x: i64,
+		y: i64,
+	},
+}
+
+impl Default for ImagePosition
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		ImagePosition::bottom_right
+	}
+}
+
+impl ImagePosition
+{
+	/// Top left of something `width` by `height` placed on an image `imageWidth` by `imageHeight`; may be negative or lie beyond the image.
+	#[inline(always)]
+	pub(crate) fn origin(&self, imageWidth: u32, imageHeight: u32, width: u32, height: u32, margin: u32) -> (i64, i64)
+	{
+		use self::ImagePosition::*;
+		
+		let margin = margin as i64;
+		let leftX = margin;
+		let centerX = (imageWidth as i64 - width as i64) / 2;
+		let rightX = imageWidth as i64 - width as i64 - margin;
+		let topY = margin;
+		let centerY = (imageHeight as i64 - height as i64) / 2;
+		let bottomY = imageHeight as i64 - height as i64 - margin;
+		
+		match *self
+		{
+			top_left => (leftX, topY),
+			top => (centerX, topY),
+			top_right => (rightX, topY),
+			left => (leftX, centerY),
+			center => (centerX, centerY),
+			right => (rightX, centerY),
+			bottom_left => (leftX, bottomY),
+			bottom => (centerX, bottomY),
+			bottom_right => (rightX, bottomY),
+			at { x, y } => (x, y),
+		}
+	}
+}
@@ -7,18 +7,20 @@ pub(crate) struct ImageSourceSet<'a>
 	inputContentFilePath: &'a Path,
 	imageInputFormat: Option<ImageInputFormat>,
 	resourceRelativeUrlWithoutFileNameExtension: &'a str,
-	jpegQuality: Option<u8>,
-	jpegSpeedOverCompression: bool,
+	encoderSettings: ImageEncoderSettings<'a>,
 	primaryImageWidth: u32,
 	primaryImageHeight: u32,
+	placeholder: Option<Rc<ImagePlaceholder>>,
 	imagesInOrder: BTreeMap<u32, ::image::DynamicImage>,
+	wideGamutImagesInOrder: BTreeMap<u32, ::image::DynamicImage>,
+	artDirection: Option<(usize, String)>,
 	languageData: &'a LanguageData<'a>
 }
 
 impl<'a> ImageSourceSet<'a>
 {
 	#[inline(always)]
-	pub(crate) fn new(inputContentFilePath: &'a Path, imageInputFormat: Option<ImageInputFormat>, resourceUrl: &'a ResourceUrl, jpegQuality: Option<u8>, jpegSpeedOverCompression: bool, primaryImage: ::image::DynamicImage, languageData: &'a LanguageData) -> Self
+	pub(crate) fn new(inputContentFilePath: &'a Path, imageInputFormat: Option<ImageInputFormat>, resourceUrl: &'a ResourceUrl, encoderSettings: ImageEncoderSettings<'a>, primaryImage: ::image::DynamicImage, wideGamutPrimaryImage: Option<::image::DynamicImage>, placeholder: Option<Rc<ImagePlaceholder>>, languageData: &'a LanguageData) -> Self
 	{
 		let resourceRelativeUrlWithoutFileNameExtension = resourceUrl.withoutFileNameExtension();
 		
@@ -28,20 +30,54 @@ impl<'a> ImageSourceSet<'a>
 		let mut imagesInOrder = BTreeMap::new();
 		imagesInOrder.insert(primaryImageWidth, primaryImage);
 		
+		let mut wideGamutImagesInOrder = BTreeMap::new();
+		if let Some(wideGamutPrimaryImage) = wideGamutPrimaryImage
+		{
+			wideGamutImagesInOrder.insert(primaryImageWidth, wideGamutPrimaryImage);
+		}
+		
 		Self
 		{
 			inputContentFilePath,
 			imageInputFormat,
 			resourceRelativeUrlWithoutFileNameExtension,
-			jpegQuality,
-			jpegSpeedOverCompression,
+			encoderSettings,
 			primaryImageWidth,
 			primaryImageHeight,
+			placeholder,
 			imagesInOrder,
+			wideGamutImagesInOrder,
+			artDirection: None,
 			languageData,
 		}
 	}
 	
+	/// An image source set for an art-directed variant of this one's primary image; its URLs are suffixed with `-<name>`.
+	#[inline(always)]
+	pub(crate) fn artDirected(&self, artDirectionIndex: usize, name: &str, primaryImage: ::image::DynamicImage) -> Self
+	{
+		let primaryImageWidth = primaryImage.width();
+		let primaryImageHeight = primaryImage.height();
+		
+		let mut imagesInOrder = BTreeMap::new();
+		imagesInOrder.insert(primaryImageWidth, primaryImage);
+		
+		Self
+		{
+			inputContentFilePath: self.inputContentFilePath,
+			imageInputFormat: self.imageInputFormat,
+			resourceRelativeUrlWithoutFileNameExtension: self.resourceRelativeUrlWithoutFileNameExtension,
+			encoderSettings: self.encoderSettings,
+			primaryImageWidth,
+			primaryImageHeight,
+			placeholder: None,
+			imagesInOrder,
+			wideGamutImagesInOrder: BTreeMap::new(),
+			artDirection: Some((artDirectionIndex, name.to_owned())),
+			languageData: self.languageData,
+		}
+	}
+	
 	#[inline(always)]
 	fn overrideFilePath(&self, imageSourceSetEntry: &ImageSourceSetEntry, primaryImageDimensions: (u32, u32)) -> Result<Option<::image::DynamicImage>, CordialError>
 	{
@@ -73,27 +109,53 @@ impl<'a> ImageSourceSet<'a>
 	}
 	
 	#[inline(always)]
-	pub(crate) fn add(&mut self, imageSourceSetEntries: &[ImageSourceSetEntry]) -> Result<(), CordialError>
+	pub(crate) fn add(&mut self, imageSourceSetEntries: &[ImageSourceSetEntry], focalPoint: &FocalPoint) -> Result<(), CordialError>
 	{
 		let primaryImageDimensions = self.primaryImage().dimensions();
+		let focalPoint = focalPoint.resolve(self.primaryImage())?;
 		
 		for imageSourceSetEntry in imageSourceSetEntries.iter()
 		{
-			let (width, image) = match self.overrideFilePath(imageSourceSetEntry, primaryImageDimensions)?
+			// An override file's content and crop may differ from the primary image's, so there is no wide-gamut equivalent of it.
+			if let Some(overrideImage) = self.overrideFilePath(imageSourceSetEntry, primaryImageDimensions)?
 			{
-				Some(overrideImage) => (overrideImage.width(), overrideImage),
-				None => imageSourceSetEntry.cropAndResize(self.primaryImage())?,
-			};
+				self.imagesInOrder.insert(overrideImage.width(), overrideImage);
+				continue;
+			}
 			
+			let (width, image) = imageSourceSetEntry.cropAndResize(self.primaryImage(), &focalPoint)?;
 			self.imagesInOrder.insert(width, image);
+			
+			let wideGamutImage = match self.wideGamutImagesInOrder.get_mut(&self.primaryImageWidth)
+			{
+				None => None,
+				Some(wideGamutPrimaryImage) => Some(imageSourceSetEntry.cropAndResize(wideGamutPrimaryImage, &focalPoint)?),
+			};
+			if let Some((width, image)) = wideGamutImage
+			{
+				self.wideGamutImagesInOrder.insert(width, image);
+			}
 		}
 		Ok(())
 	}
 	
+	#[inline(always)]
+	fn mimeType(&self) -> &'static str
+	{
+		if self.encoderSettings.jpegQuality.is_some()
+		{
+			"image/jpeg"
+		}
+		else
+		{
+			"image/png"
+		}
+	}
+	
 	#[inline(always)]
 	fn contentTypeAndFileExtension(&self) -> (ContentType, &'static str)
 	{
-		if self.jpegQuality.is_some()
+		if self.encoderSettings.jpegQuality.is_some()
 		{
 			(content_type_image_jpeg(), ".jpg")
 		}
@@ -115,51 +177,107 @@ impl<'a> ImageSourceSet<'a>
 		(self.primaryImageWidth, self.primaryImageHeight)
 	}
 	
-	/// Outputs (Url, Width) pairs
+	/// Outputs (Url, Width) pairs, firstly for the original format and then for each alternative format
 	/// Use as say (note commas MATTER): srcset="/url/elva-fairy-320w.jpg 320w, /url/elva-fairy-480w.jpg 480w, /url/elva-fairy.jpg 800w, /url/elva-fairy-1000w.jpg 1000w,"
 	#[inline(always)]
-	pub(crate) fn processedImageSourceSet(&self, imageSourceSet: &mut Vec<(Url, u32)>) -> Result<(), CordialError>
+	pub(crate) fn processedImageSourceSet(&self, processedImageSourceSet: &mut ProcessedImageSourceSet) -> Result<(), CordialError>
 	{
 		let (_, fileExtension) = self.contentTypeAndFileExtension();
 		
+		self.imageSourceSetForFileExtension(fileExtension, &mut processedImageSourceSet.imageSourceSet)?;
+		
+		let alternativeImageSourceSets = &mut processedImageSourceSet.alternativeImageSourceSets;
+		alternativeImageSourceSets.reserve_exact(self.encoderSettings.alternativeFormats.len());
+		for alternativeFormat in self.encoderSettings.alternativeFormats.iter()
+		{
+			let mut imageSourceSet = Vec::new();
+			self.imageSourceSetForFileExtension(alternativeFormat.fileExtension(), &mut imageSourceSet)?;
+			alternativeImageSourceSets.push((alternativeFormat.mimeType(), imageSourceSet));
+		}
+		
+		processedImageSourceSet.wideGamutImageSourceSet = if self.wideGamutImagesInOrder.is_empty()
+		{
+			None
+		}
+		else
+		{
+			let mut imageSourceSet = Vec::with_capacity(self.wideGamutImagesInOrder.len());
+			for width in self.wideGamutImagesInOrder.keys()
+			{
+				let width = *width;
+				imageSourceSet.push((self.wideGamutUrl(fileExtension, width)?, width))
+			}
+			Some((self.mimeType(), imageSourceSet))
+		};
+		
+		Ok(())
+	}
+	
+	/// For an art-directed image source set created with `artDirected()`; `processedImageSourceSet` is that of the image source set it was created from.
+	#[inline(always)]
+	pub(crate) fn artDirectedProcessedImageSourceSet(&self, media: &str, processedImageSourceSet: &mut ProcessedImageSourceSet) -> Result<(), CordialError>
+	{
+		let mut artDirectedProcessedImageSourceSet = ProcessedImageSourceSet::default();
+		self.processedImageSourceSet(&mut artDirectedProcessedImageSourceSet)?;
+		processedImageSourceSet.artDirectedImageSourceSets.push((media.to_owned(), self.mimeType(), artDirectedProcessedImageSourceSet));
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn imageSourceSetForFileExtension(&self, fileExtension: &'static str, imageSourceSet: &mut Vec<(Url, u32)>) -> Result<(), CordialError>
+	{
 		imageSourceSet.reserve_exact(self.imagesInOrder.len());
 		
 		for width in self.imagesInOrder.keys()
 		{
 			let width = *width;
-			let url = if width == self.primaryImageWidth
-			{
-				ResourceUrl::primaryUrl(&self.resourceRelativeUrlWithoutFileNameExtension, fileExtension, self.languageData)?
-			}
-			else
-			{
-				ResourceUrl::widthUrl(&self.resourceRelativeUrlWithoutFileNameExtension, fileExtension, self.languageData, width)?
-			};
-			
-			imageSourceSet.push((url, width))
+			imageSourceSet.push((self.url(fileExtension, width)?, width))
 		}
 		Ok(())
 	}
 	
+	#[inline(always)]
+	fn url(&self, fileExtension: &'static str, width: u32) -> Result<Url, CordialError>
+	{
+		match self.artDirection
+		{
+			None => self.urlWithoutFileNameExtension(self.resourceRelativeUrlWithoutFileNameExtension, fileExtension, width),
+			Some((_, ref name)) => self.urlWithoutFileNameExtension(&format!("{}-{}", self.resourceRelativeUrlWithoutFileNameExtension, name), fileExtension, width),
+		}
+	}
+	
+	#[inline(always)]
+	fn urlWithoutFileNameExtension(&self, resourceRelativeUrlWithoutFileNameExtension: &str, fileExtension: &'static str, width: u32) -> Result<Url, CordialError>
+	{
+		if width == self.primaryImageWidth
+		{
+			ResourceUrl::primaryUrl(resourceRelativeUrlWithoutFileNameExtension, fileExtension, self.languageData)
+		}
+		else
+		{
+			ResourceUrl::widthUrl(resourceRelativeUrlWithoutFileNameExtension, fileExtension, self.languageData, width)
+		}
+	}
+	
+	#[inline(always)]
+	fn wideGamutUrl(&self, fileExtension: &'static str, width: u32) -> Result<Url, CordialError>
+	{
+		self.urlWithoutFileNameExtension(&format!("{}-p3", self.resourceRelativeUrlWithoutFileNameExtension), fileExtension, width)
+	}
+	
 	#[inline(always)]
 	pub(crate) fn urls<F: FnMut(&Url) -> Result<Vec<(String, String)>, CordialError>>(&self, mut headerGenerator: F) -> Result<Vec<PipelineResponse>, CordialError>
 	{
 		let (contentType, fileExtension) = self.contentTypeAndFileExtension();
 		
-		let mut urls = Vec::with_capacity(self.imagesInOrder.len());
+		let mut urls = Vec::with_capacity(self.imagesInOrder.len() * (1 + self.encoderSettings.alternativeFormats.len()) + self.wideGamutImagesInOrder.len());
 		let mut index = 0;
 		let finalIndex = self.imagesInOrder.len() - 1;
 		for (width, image) in self.imagesInOrder.iter()
 		{
 			let width = *width;
-			let (url, isPrimary) = if width == self.primaryImageWidth
-			{
-				(ResourceUrl::primaryUrl(self.resourceRelativeUrlWithoutFileNameExtension, fileExtension, self.languageData)?, true)
-			}
-			else
-			{
-				(ResourceUrl::widthUrl(self.resourceRelativeUrlWithoutFileNameExtension, fileExtension, self.languageData, width)?, false)
-			};
+			let isPrimary = width == self.primaryImageWidth;
+			let url = self.url(fileExtension, width)?;
 			
 			let body = self.optimize(image)?;
 			let headers = headerGenerator(&url)?;
@@ -172,87 +290,149 @@ impl<'a> ImageSourceSet<'a>
 					width,
 					height,
 					size: body.len() as u64,
+					placeholder: self.placeholder.clone(),
 				}
 			);
 			
 			use self::ResourceTag::*;
-			let mut resourceTags = hashmap!
-			{
-				width_image(width) => urlDataDetails.clone(),
-				height_image(height) => urlDataDetails.clone(),
-				width_height_image(width, height) => urlDataDetails.clone()
-			};
-			
-			if isPrimary
+			let resourceTags = if let Some((artDirectionIndex, _)) = self.artDirection
 			{
-				resourceTags.insert(default, urlDataDetails.clone());
-				resourceTags.insert(primary_image, urlDataDetails.clone());
+				hashmap!
+				{
+					art_direction_image(artDirectionIndex, width) => urlDataDetails.clone(),
+				}
 			}
-			if index == 0
+			else
 			{
-				resourceTags.insert(smallest_image, urlDataDetails.clone());
-			}
-			if index == finalIndex
+				let mut resourceTags = hashmap!
+				{
+					width_image(width) => urlDataDetails.clone(),
+					height_image(height) => urlDataDetails.clone(),
+					width_height_image(width, height) => urlDataDetails.clone()
+				};
+				
+				if isPrimary
+				{
+					resourceTags.insert(default, urlDataDetails.clone());
+					resourceTags.insert(primary_image, urlDataDetails.clone());
+				}
+				if index == 0
+				{
+					resourceTags.insert(smallest_image, urlDataDetails.clone());
+				}
+				if index == finalIndex
+				{
+					resourceTags.insert(largest_image, urlDataDetails.clone());
+				}
+				resourceTags
+			};
+			urls.push((url, resourceTags, StatusCode::Ok, contentType.clone(), headers, ResponseBody::binary(body), None, false));
+			
+			for (alternativeFormatIndex, alternativeFormat) in self.encoderSettings.alternativeFormats.iter().enumerate()
 			{
-				resourceTags.insert(largest_image, urlDataDetails.clone());
+				let url = self.url(alternativeFormat.fileExtension(), width)?;
+				
+				let body = alternativeFormat.encode(image, self.inputContentFilePath)?;
+				let headers = headerGenerator(&url)?;
+				
+				let resourceTag = match self.artDirection
+				{
+					None => alternativeFormat.resourceTag(width),
+					Some((artDirectionIndex, _)) => art_direction_alternative_format_image(artDirectionIndex, alternativeFormatIndex, width),
+				};
+				
+				let resourceTags = hashmap!
+				{
+					resourceTag => Rc::new
+					(
+						UrlDataDetails::Image
+						{
+							width,
+							height,
+							size: body.len() as u64,
+							placeholder: self.placeholder.clone(),
+						}
+					),
+				};
+				
+				urls.push((url, resourceTags, StatusCode::Ok, alternativeFormat.contentType(), headers, ResponseBody::binary(body), None, false));
 			}
-			urls.push((url, resourceTags, StatusCode::Ok, contentType.clone(), headers, ResponseBody::binary(body), None, false));
+			
 			index += 1;
 		}
-		Ok(urls)
-	}
-	
-	fn optimize(&self, image: &::image::DynamicImage) -> Result<Vec<u8>, CordialError>
-	{
-		fn encodeJpeg(image: &::image::DynamicImage, quality: u8, path: &Path) -> Result<Vec<u8>, CordialError>
+		
+		if !self.wideGamutImagesInOrder.is_empty()
 		{
-			let bytes = image.raw_pixels();
-			let mut jpegBytes = Vec::with_capacity(bytes.len() * 2);
+			let displayP3ProfileBytes = IccProfile::displayP3ProfileBytes(self.inputContentFilePath)?;
+			
+			for (width, image) in self.wideGamutImagesInOrder.iter()
 			{
-				let mut writer = BufWriter::with_capacity(jpegBytes.len(), &mut jpegBytes);
+				let width = *width;
+				let url = self.wideGamutUrl(fileExtension, width)?;
+				
+				let body = self.optimize(image)?;
+				let body = if self.encoderSettings.jpegQuality.is_some()
+				{
+					IccProfile::embedInJpeg(&displayP3ProfileBytes, body, self.inputContentFilePath)?
+				}
+				else
+				{
+					IccProfile::embedInPng(&displayP3ProfileBytes, body, self.inputContentFilePath)?
+				};
+				let headers = headerGenerator(&url)?;
 				
-				let mut jpegEncoder = JPEGEncoder::new_with_quality(&mut writer, quality);
+				let resourceTags = hashmap!
+				{
+					ResourceTag::display_p3_image(width) => Rc::new
+					(
+						UrlDataDetails::Image
+						{
+							width,
+							height: image.height(),
+							size: body.len() as u64,
+							placeholder: self.placeholder.clone(),
+						}
+					),
+				};
 				
-				let (width, height) = image.dimensions();
-				let color = image.color();
-				jpegEncoder.encode(&bytes, width, height, color).context(path)?;
+				urls.push((url, resourceTags, StatusCode::Ok, contentType.clone(), headers, ResponseBody::binary(body), None, false));
 			}
-			Ok(jpegBytes)
 		}
 		
-		let bytes = if let Some(jpegQuality) = self.jpegQuality
+		Ok(urls)
+	}
+	
+	fn optimize(&self, image: &::image::DynamicImage) -> Result<Vec<u8>, CordialError>
+	{
+		let bytes = if let Some(jpegQuality) = self.encoderSettings.jpegQuality
 		{
-			if self.jpegSpeedOverCompression
+			if self.encoderSettings.jpegSpeedOverCompression
 			{
 				let quality = match jpegQuality
 				{
 					quality @ 0 ... 100 => quality,
 					_ => 100
 				};
-				encodeJpeg(image, quality, self.inputContentFilePath)?
+				JpegEncoder::baseline(image, quality, self.inputContentFilePath)?
 			}
 			else
 			{
-				let inputJpegBytes = encodeJpeg(image, 100, self.inputContentFilePath)?;
-				
-				let quality = match jpegQuality
-				{
-					quality if quality < 84 => 84,
-					quality @ 84 ... 100 => quality,
-					_ => 100
-				};
-				
-				::guetzli_sys::guetzli(&inputJpegBytes, quality, None)?
+				self.encoderSettings.jpegEncoder.encode(image, jpegQuality, self.inputContentFilePath)?
 			}
 		}
 		else
 		{
-			Self::optimizePngImage(&image, self.inputContentFilePath)?
+			let losslessBytes = Self::optimizePngImage(&image, self.inputContentFilePath)?;
+			match self.encoderSettings.pngQuantization
+			{
+				None => losslessBytes,
+				Some(pngQuantization) => pngQuantization.smallerOfQuantizedOrLossless(image, losslessBytes, self.inputContentFilePath)?,
+			}
 		};
 		Ok(bytes)
 	}
 	
-	fn optimizePngImage(image: &::image::DynamicImage, context: &Path) -> Result<Vec<u8>, CordialError>
+	pub(crate) fn optimizePngImage(image: &::image::DynamicImage, context: &Path) -> Result<Vec<u8>, CordialError>
 	{
 		let mut temporaryFile = Temp::new_file().context(context)?;
 		let temporaryFilePath = temporaryFile.to_path_buf();
@@ -267,4 +447,20 @@ impl<'a> ImageSourceSet<'a>
 		
 		Ok(bytes)
 	}
+	
+	pub(crate) fn optimizePngBytes(pngBytes: &[u8], context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		let mut temporaryFile = Temp::new_file().context(context)?;
+		let temporaryFilePath = temporaryFile.to_path_buf();
+		let bytes =
+		{
+			temporaryFilePath.createFileWithByteContents(pngBytes).context(&temporaryFilePath)?;
+			temporaryFilePath.modifyPngWithOxipng()?;
+			temporaryFilePath.fileContentsAsBytes().context(&temporaryFilePath)?
+		};
+		temporaryFilePath.deleteOverridingPermissions().context(&temporaryFilePath)?;
+		temporaryFile.release();
+		
+		Ok(bytes)
+	}
 }
@@ -13,21 +13,23 @@ pub(crate) struct ImageSourceSetEntry
 
 impl ImageSourceSetEntry
 {
+	/// If the aspect ratio of the scaled image differs from that of the (cropped) image, the image is first cropped to that aspect ratio around the focal point, rather than being distorted.
 	#[inline(always)]
-	pub(crate) fn cropAndResize(&self, image: &mut ::image::DynamicImage) -> Result<(u32, ::image::DynamicImage), CordialError>
+	pub(crate) fn cropAndResize(&self, image: &mut ::image::DynamicImage, focalPoint: &FocalPoint) -> Result<(u32, ::image::DynamicImage), CordialError>
 	{
 		if let Some(ref imageCrop) = self.crop
 		{
-			self.resizeExact(&imageCrop.crop(image))
+			let (mut croppedImage, croppedFocalPoint) = imageCrop.crop(image, focalPoint)?;
+			self.resizeExact(&mut croppedImage, &croppedFocalPoint)
 		}
 		else
 		{
-			self.resizeExact(image)
+			self.resizeExact(image, focalPoint)
 		}
 	}
 	
 	#[inline(always)]
-	fn resizeExact(&self, image: &::image::DynamicImage) -> Result<(u32, ::image::DynamicImage), CordialError>
+	fn resizeExact(&self, image: &mut ::image::DynamicImage, focalPoint: &FocalPoint) -> Result<(u32, ::image::DynamicImage), CordialError>
 	{
 		let oldDimensions = image.dimensions();
 		let newDimensions = self.scale.scale(oldDimensions)?;
@@ -35,7 +37,11 @@ impl ImageSourceSetEntry
 		let newHeight = newDimensions.1;
 		
 		let filter = self.filter.to_FilterType();
-		let resizedImage = image.resize_exact(newWidth, newHeight, filter);
+		let resizedImage = match focalPoint.cropToAspectRatio(image, newDimensions)?
+		{
+			None => image.resize_exact(newWidth, newHeight, filter),
+			Some((croppedImage, _)) => croppedImage.resize_exact(newWidth, newHeight, filter),
+		};
 		
 		Ok((newWidth, resizedImage))
 	}
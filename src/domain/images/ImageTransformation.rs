@@ -3,7 +3,7 @@
 
 
 #[serde(deny_unknown_fields)]
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) enum ImageTransformation
 {
 	invert,
@@ -20,10 +20,11 @@ pub(crate) enum ImageTransformation
 		#[serde(default)] filter: ImageTransformationFilterType,
 	},
 	
+	/// If `x` or `y` are omitted, the crop is positioned around the image's focal point.
 	crop
 	{
-		x: u32,
-		y: u32,
+		#[serde(default)] x: Option<u32>,
+		#[serde(default)] y: Option<u32>,
 		width: u32,
 		height: u32,
 	},
@@ -70,12 +71,36 @@ pub(crate) enum ImageTransformation
 	rotate_180_degrees,
 	
 	rotate_270_degrees,
+	
+	/// Composites another raster image or SVG resource, eg a watermark or logo, on top.
+	overlay
+	{
+		resource: ResourceUrl,
+		#[serde(default)] position: ImagePosition,
+		#[serde(default)] margin: u32,
+		#[serde(default = "ImageTransformation::opacity_default")] opacity: f32,
+		/// Width of the overlay as a fraction of the width of the image; if omitted, the overlay is used at its own size.
+		#[serde(default)] scale: Option<f32>,
+	},
+	
+	/// Draws a single line of text, eg a translated caption or copyright mark, using a TrueType or OpenType font resource.
+	text
+	{
+		text: HashMap<Iso639Dash1Alpha2Language, String>,
+		font: ResourceUrl,
+		#[serde(default)] position: ImagePosition,
+		#[serde(default)] margin: u32,
+		#[serde(default)] color: HexadecimalColor,
+		#[serde(default = "ImageTransformation::opacity_default")] opacity: f32,
+		/// Height of the font in pixels.
+		size: f32,
+	},
 }
 
 impl ImageTransformation
 {
 	#[inline(always)]
-	pub(crate) fn applyTransformations(originalImage: &::image::DynamicImage, transformations: &[Self]) -> Result<Option<::image::DynamicImage>, CordialError>
+	pub(crate) fn applyTransformations(originalImage: &::image::DynamicImage, transformations: &[Self], focalPoint: &mut FocalPoint, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<::image::DynamicImage>, CordialError>
 	{
 		let mut previousImage = None;
 		for transformation in transformations.iter()
@@ -85,12 +110,12 @@ impl ImageTransformation
 			{
 				None =>
 				{
-					thisImage = transformation.transform(originalImage)?;
+					thisImage = transformation.transform(originalImage, focalPoint, resources, configuration, iso639Dash1Alpha2Language)?;
 				}
 				
 				Some(ref mut image) =>
 				{
-					if let Some(transformed) = transformation.transform(image)?
+					if let Some(transformed) = transformation.transform(image, focalPoint, resources, configuration, iso639Dash1Alpha2Language)?
 					{
 						thisImage = Some(transformed);
 					}
@@ -103,7 +128,17 @@ impl ImageTransformation
 	}
 	
 	#[inline(always)]
-	pub(crate) fn transform(&self, image: &::image::DynamicImage) -> Result<Option<::image::DynamicImage>, CordialError>
+	pub(crate) fn overlayResourceUrl(&self) -> Option<&ResourceUrl>
+	{
+		match *self
+		{
+			ImageTransformation::overlay { ref resource, .. } => Some(resource),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn transform(&self, image: &::image::DynamicImage, focalPoint: &mut FocalPoint, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<::image::DynamicImage>, CordialError>
 	{
 		use self::ImageTransformation::*;
 		
@@ -122,6 +157,8 @@ impl ImageTransformation
 			
 			crop { x, y, width, height } =>
 			{
+				let (x, y) = focalPoint.cropOriginUnlessExplicit(image, x, y, width, height)?;
+				
 				if x == 0 && y == 0 && width == image.width() && height == image.height()
 				{
 					Ok(None)
@@ -129,8 +166,9 @@ impl ImageTransformation
 				else
 				{
 					let mut newImage = image.clone();
-					newImage.crop(x, y, width, height);
-					Ok(Some(newImage))
+					let croppedImage = newImage.crop(x, y, width, height);
+					*focalPoint = focalPoint.afterCrop(image, x, y, &croppedImage)?;
+					Ok(Some(croppedImage))
 				}
 			},
 			
@@ -157,6 +195,117 @@ impl ImageTransformation
 			rotate_180_degrees => Ok(Some(image.rotate180())),
 			
 			rotate_270_degrees => Ok(Some(image.rotate270())),
+			
+			overlay { ref resource, position, margin, opacity, scale } =>
+			{
+				let overlayImage =
+				{
+					let overlayResource = resource.resourceMandatory(resources)?;
+					overlayResource.imageForCompositing(resource, resources, configuration, iso639Dash1Alpha2Language)?
+				};
+				
+				let overlayImage = match scale
+				{
+					None => overlayImage,
+					Some(scale) =>
+					{
+						if scale <= 0.0
+						{
+							return Err(CordialError::Configuration(format!("overlay scale for '{:?}' must be positive", resource)));
+						}
+						let width = max(1, (image.width() as f32 * scale).round() as u32);
+						let height = max(1, (overlayImage.height() as f32 * width as f32 / overlayImage.width() as f32).round() as u32);
+						overlayImage.resize_exact(width, height, ::image::FilterType::Lanczos3)
+					}
+				};
+				
+				let mut newImage = image.to_rgba();
+				let (originX, originY) = position.origin(image.width(), image.height(), overlayImage.width(), overlayImage.height(), margin);
+				for (x, y, pixel) in overlayImage.to_rgba().enumerate_pixels()
+				{
+					let alpha = (pixel.data[3] as f32 / 255.0) * opacity;
+					Self::blendPixel(&mut newImage, originX + x as i64, originY + y as i64, [pixel.data[0], pixel.data[1], pixel.data[2]], alpha);
+				}
+				Ok(Some(::image::DynamicImage::ImageRgba8(newImage)))
+			}
+			
+			text { text: ref textByLanguage, ref font, position, margin, color, opacity, size } =>
+			{
+				let text = match textByLanguage.get(&iso639Dash1Alpha2Language)
+				{
+					Some(text) => text,
+					None => match textByLanguage.get(&configuration.fallbackIso639Dash1Alpha2Language())
+					{
+						None => return Err(CordialError::Configuration(format!("Could not find text transformation text for '{:?}' or fallback", iso639Dash1Alpha2Language))),
+						Some(text) => text,
+					},
+				};
+				
+				let fontBytes =
+				{
+					let fontResource = font.resourceMandatory(resources)?;
+					fontResource.fontForCompositing(configuration, iso639Dash1Alpha2Language)?
+				};
+				let font = match ::rusttype::FontCollection::from_bytes(fontBytes).into_font()
+				{
+					None => return Err(CordialError::Configuration(format!("font '{:?}' for text transformation is not a single TrueType or OpenType font", font))),
+					Some(font) => font,
+				};
+				
+				let scale = ::rusttype::Scale::uniform(size);
+				let verticalMetrics = font.v_metrics(scale);
+				let glyphs: Vec<_> = font.layout(text, scale, ::rusttype::point(0.0, verticalMetrics.ascent)).collect();
+				
+				let textWidth = glyphs.iter().filter_map(|glyph| glyph.pixel_bounding_box()).map(|boundingBox| boundingBox.max.x).max().unwrap_or(0);
+				let textHeight = (verticalMetrics.ascent - verticalMetrics.descent).ceil();
+				
+				let mut newImage = image.to_rgba();
+				let (originX, originY) = position.origin(image.width(), image.height(), max(0, textWidth) as u32, textHeight as u32, margin);
+				for glyph in glyphs.iter()
+				{
+					if let Some(boundingBox) = glyph.pixel_bounding_box()
+					{
+						glyph.draw(|x, y, coverage|
+						{
+							Self::blendPixel(&mut newImage, originX + boundingBox.min.x as i64 + x as i64, originY + boundingBox.min.y as i64 + y as i64, color.0, coverage * opacity);
+						});
+					}
+				}
+				Ok(Some(::image::DynamicImage::ImageRgba8(newImage)))
+			}
 		}
 	}
+	
+	/// Source-over compositing of a single pixel; pixels outside the image are ignored.
+	#[inline(always)]
+	fn blendPixel(image: &mut ::image::RgbaImage, x: i64, y: i64, color: [u8; 3], alpha: f32)
+	{
+		if x < 0 || y < 0 || x >= image.width() as i64 || y >= image.height() as i64
+		{
+			return;
+		}
+		
+		let alpha = alpha.max(0.0).min(1.0);
+		if alpha == 0.0
+		{
+			return;
+		}
+		
+		let pixel = image.get_pixel_mut(x as u32, y as u32);
+		let destinationAlpha = pixel.data[3] as f32 / 255.0;
+		let outputAlpha = alpha + destinationAlpha * (1.0 - alpha);
+		for channel in 0 .. 3
+		{
+			let source = color[channel] as f32 * alpha;
+			let destination = pixel.data[channel] as f32 * destinationAlpha * (1.0 - alpha);
+			pixel.data[channel] = ((source + destination) / outputAlpha).round() as u8;
+		}
+		pixel.data[3] = (outputAlpha * 255.0).round() as u8;
+	}
+	
+	#[inline(always)]
+	fn opacity_default() -> f32
+	{
+		1.0
+	}
 }
@@ -0,0 +1,43 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub(crate) enum JpegChromaSubsampling
+{
+	/// 4:4:4; no subsampling, for images with fine colored detail such as screenshots and text.
+	none,
+	
+	/// 4:2:2.
+	horizontal,
+	
+	/// 4:2:0; suitable for most photographs.
+	horizontal_and_vertical,
+}
+
+impl Default for JpegChromaSubsampling
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		JpegChromaSubsampling::horizontal_and_vertical
+	}
+}
+
+impl JpegChromaSubsampling
+{
+	/// Pixel sizes of each chroma sample for the Cb and Cr components.
+	#[inline(always)]
+	pub(crate) fn pixelSizes(&self) -> ((u8, u8), (u8, u8))
+	{
+		use self::JpegChromaSubsampling::*;
+		
+		match *self
+		{
+			none => ((1, 1), (1, 1)),
+			horizontal => ((2, 1), (2, 1)),
+			horizontal_and_vertical => ((2, 2), (2, 2)),
+		}
+	}
+}
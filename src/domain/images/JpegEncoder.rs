@@ -0,0 +1,118 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Used unless `jpeg_speed_over_compression` is set, in which case a fast baseline encoder is used instead.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) enum JpegEncoder
+{
+	/// Perceptual encoding; produces the smallest baseline JPEGs but is extremely slow, so is best kept for production builds.
+	guetzli,
+	
+	/// Trellis-quantized, scan-optimized JPEGs; much faster than guetzli.
+	mozjpeg
+	{
+		#[serde(default = "JpegEncoder::progressive_default")] progressive: bool,
+		#[serde(default)] chroma_subsampling: JpegChromaSubsampling,
+	},
+}
+
+impl Default for JpegEncoder
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		JpegEncoder::guetzli
+	}
+}
+
+impl JpegEncoder
+{
+	/// libjpeg's `JPEG_MAX_DIMENSION`.
+	const MaximumJpegDimension: u32 = 65_500;
+	
+	#[inline(always)]
+	pub(crate) fn encode(&self, image: &::image::DynamicImage, jpegQuality: u8, context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		use self::JpegEncoder::*;
+		
+		match *self
+		{
+			guetzli =>
+			{
+				let inputJpegBytes = Self::baseline(image, 100, context)?;
+				
+				let quality = match jpegQuality
+				{
+					quality if quality < 84 => 84,
+					quality @ 84 ... 100 => quality,
+					_ => 100
+				};
+				
+				Ok(::guetzli_sys::guetzli(&inputJpegBytes, quality, None)?)
+			}
+			
+			mozjpeg { progressive, chroma_subsampling } => Self::mozjpeg(image, min(jpegQuality, 100), progressive, chroma_subsampling, context),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn baseline(image: &::image::DynamicImage, quality: u8, context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		let bytes = image.raw_pixels();
+		let mut jpegBytes = Vec::with_capacity(bytes.len() * 2);
+		{
+			let mut writer = BufWriter::with_capacity(jpegBytes.len(), &mut jpegBytes);
+			
+			let mut jpegEncoder = JPEGEncoder::new_with_quality(&mut writer, quality);
+			
+			let (width, height) = image.dimensions();
+			let color = image.color();
+			jpegEncoder.encode(&bytes, width, height, color).context(context)?;
+		}
+		Ok(jpegBytes)
+	}
+	
+	#[inline(always)]
+	fn mozjpeg(image: &::image::DynamicImage, quality: u8, progressive: bool, chromaSubsampling: JpegChromaSubsampling, context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		let rgbImage = image.to_rgb();
+		let (width, height) = rgbImage.dimensions();
+		
+		// libjpeg's only failures when compressing RGB to memory are for invalid dimensions, which mozjpeg would report by panicking; so check them first.
+		if width == 0 || height == 0 || width > Self::MaximumJpegDimension || height > Self::MaximumJpegDimension
+		{
+			return Err(CordialError::ImageDimensionsInvalidForJpeg(context.to_path_buf(), width, height));
+		}
+		
+		let mut compress = ::mozjpeg::Compress::new(::mozjpeg::ColorSpace::JCS_RGB);
+		compress.set_size(width as usize, height as usize);
+		compress.set_quality(quality as f32);
+		
+		let (cbPixelSizes, crPixelSizes) = chromaSubsampling.pixelSizes();
+		compress.set_chroma_sampling_pixel_sizes(cbPixelSizes, crPixelSizes);
+		
+		compress.set_optimize_coding(true);
+		if progressive
+		{
+			compress.set_progressive_mode();
+			compress.set_optimize_scans(true);
+		}
+		
+		compress.set_mem_dest();
+		compress.start_compress();
+		if !compress.write_scanlines(&rgbImage)
+		{
+			return Err(CordialError::CouldNotEncodeJpegWithMozjpeg(context.to_path_buf()));
+		}
+		compress.finish_compress();
+		compress.data_to_vec().map_err(|_| CordialError::CouldNotEncodeJpegWithMozjpeg(context.to_path_buf()))
+	}
+	
+	#[inline(always)]
+	fn progressive_default() -> bool
+	{
+		true
+	}
+}
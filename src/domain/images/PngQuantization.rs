@@ -0,0 +1,178 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Lossy palette quantization (as done by pngquant) before lossless optimization with oxipng.
+///
+/// The quantized PNG is only used if libimagequant can meet `minimum_quality` and the result is smaller than the losslessly optimized PNG.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct PngQuantization
+{
+	#[serde(default = "PngQuantization::minimum_quality_default")] minimum_quality: u8,
+	#[serde(default = "PngQuantization::maximum_quality_default")] maximum_quality: u8,
+	#[serde(default = "PngQuantization::dithering_level_default")] dithering_level: f32,
+	#[serde(default = "PngQuantization::speed_default")] speed: u8,
+}
+
+impl Default for PngQuantization
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			minimum_quality: Self::minimum_quality_default(),
+			maximum_quality: Self::maximum_quality_default(),
+			dithering_level: Self::dithering_level_default(),
+			speed: Self::speed_default(),
+		}
+	}
+}
+
+impl PngQuantization
+{
+	#[inline(always)]
+	pub(crate) fn smallerOfQuantizedOrLossless(&self, image: &::image::DynamicImage, losslessBytes: Vec<u8>, context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		self.validate()?;
+		
+		let (palette, indices, quality) = match self.quantize(image, context)?
+		{
+			None =>
+			{
+				info!("PNG {:?} was not quantized as quality would have been under {}; kept lossless PNG of {} bytes", context, self.minimum_quality, losslessBytes.len());
+				return Ok(losslessBytes);
+			}
+			Some(quantized) => quantized,
+		};
+		
+		let (width, height) = image.dimensions();
+		let quantizedBytes = ImageSourceSet::optimizePngBytes(&Self::encodeIndexedPng(&palette, &indices, width, height, context)?, context)?;
+		
+		if quantizedBytes.len() < losslessBytes.len()
+		{
+			info!("PNG {:?} quantized to {} colors at quality {} with dithering {}; {} bytes rather than {} bytes lossless", context, palette.len(), quality, self.dithering_level, quantizedBytes.len(), losslessBytes.len());
+			Ok(quantizedBytes)
+		}
+		else
+		{
+			info!("PNG {:?} quantized to {} colors at quality {} was not smaller ({} bytes); kept lossless PNG of {} bytes", context, palette.len(), quality, quantizedBytes.len(), losslessBytes.len());
+			Ok(losslessBytes)
+		}
+	}
+	
+	#[inline(always)]
+	fn validate(&self) -> Result<(), CordialError>
+	{
+		if self.maximum_quality > 100 || self.minimum_quality > self.maximum_quality
+		{
+			return Err(CordialError::Configuration("png_quantization minimum_quality must not exceed maximum_quality, which must not exceed 100".to_owned()));
+		}
+		
+		if self.dithering_level < 0.0 || self.dithering_level > 1.0
+		{
+			return Err(CordialError::Configuration("png_quantization dithering_level must be between 0.0 and 1.0 inclusive".to_owned()));
+		}
+		
+		if self.speed < 1 || self.speed > 10
+		{
+			return Err(CordialError::Configuration("png_quantization speed must be between 1 and 10 inclusive".to_owned()));
+		}
+		
+		Ok(())
+	}
+	
+	/// Returns `None` if `minimum_quality` can not be met.
+	#[inline(always)]
+	fn quantize(&self, image: &::image::DynamicImage, context: &Path) -> Result<Option<(Vec<::rgb::RGBA8>, Vec<u8>, i32)>, CordialError>
+	{
+		use ::imagequant::liq_error::LIQ_QUALITY_TOO_LOW;
+		
+		let error = |error: ::imagequant::liq_error| CordialError::CouldNotQuantizePng(context.to_path_buf(), format!("{:?}", error));
+		
+		let (width, height) = image.dimensions();
+		let pixels: Vec<::rgb::RGBA8> = image.to_rgba().pixels().map(|pixel| ::rgb::RGBA8::new(pixel[0], pixel[1], pixel[2], pixel[3])).collect();
+		
+		let mut attributes = ::imagequant::new();
+		attributes.set_speed(self.speed as i32).map_err(&error)?;
+		attributes.set_quality(self.minimum_quality as u32, self.maximum_quality as u32).map_err(&error)?;
+		
+		let mut quantizationImage = attributes.new_image(&pixels, width as usize, height as usize, 0.0).map_err(&error)?;
+		
+		let mut quantizationResult = match attributes.quantize(&mut quantizationImage)
+		{
+			Ok(quantizationResult) => quantizationResult,
+			Err(LIQ_QUALITY_TOO_LOW) => return Ok(None),
+			Err(liqError) => return Err(error(liqError)),
+		};
+		
+		quantizationResult.set_dithering_level(self.dithering_level).map_err(&error)?;
+		let (palette, indices) = quantizationResult.remapped(&mut quantizationImage).map_err(&error)?;
+		let quality = quantizationResult.quantization_quality();
+		
+		Ok(Some((palette, indices, quality)))
+	}
+	
+	#[inline(always)]
+	fn encodeIndexedPng(palette: &[::rgb::RGBA8], indices: &[u8], width: u32, height: u32, context: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		use ::png::HasParameters;
+		
+		let error = |error: ::png::EncodingError| CordialError::CouldNotQuantizePng(context.to_path_buf(), error.to_string());
+		
+		let mut plte = Vec::with_capacity(palette.len() * 3);
+		let mut trns = Vec::with_capacity(palette.len());
+		for color in palette.iter()
+		{
+			plte.push(color.r);
+			plte.push(color.g);
+			plte.push(color.b);
+			trns.push(color.a);
+		}
+		
+		// Trailing opaque entries can be omitted from tRNS.
+		while trns.last() == Some(&0xFF)
+		{
+			trns.pop();
+		}
+		
+		let mut bytes = Vec::with_capacity(indices.len());
+		{
+			let mut encoder = ::png::Encoder::new(&mut bytes, width, height);
+			encoder.set(::png::ColorType::Indexed).set(::png::BitDepth::Eight);
+			let mut writer = encoder.write_header().map_err(&error)?;
+			writer.write_chunk(*b"PLTE", &plte).map_err(&error)?;
+			if !trns.is_empty()
+			{
+				writer.write_chunk(*b"tRNS", &trns).map_err(&error)?;
+			}
+			writer.write_image_data(indices).map_err(&error)?;
+		}
+		Ok(bytes)
+	}
+	
+	#[inline(always)]
+	fn minimum_quality_default() -> u8
+	{
+		65
+	}
+	
+	#[inline(always)]
+	fn maximum_quality_default() -> u8
+	{
+		80
+	}
+	
+	#[inline(always)]
+	fn dithering_level_default() -> f32
+	{
+		1.0
+	}
+	
+	#[inline(always)]
+	fn speed_default() -> u8
+	{
+		3
+	}
+}
@@ -3,16 +3,96 @@
 
 
 #[derive(Default, Debug, Clone)]
-pub(crate) struct ProcessedImageSourceSet(u32, u32, pub(crate) Vec<(Url, u32)>);
+pub(crate) struct ProcessedImageSourceSet
+{
+	pub(crate) imageSourceSet: Vec<(Url, u32)>,
+	
+	/// Mime type and image source set, in order of preference.
+	pub(crate) alternativeImageSourceSets: Vec<(&'static str, Vec<(Url, u32)>)>,
+	
+	/// Mime type and image source set.
+	pub(crate) wideGamutImageSourceSet: Option<(&'static str, Vec<(Url, u32)>)>,
+	
+	/// Media query, mime type and processed image source set.
+	pub(crate) artDirectedImageSourceSets: Vec<(String, &'static str, ProcessedImageSourceSet)>,
+}
 
 impl ProcessedImageSourceSet
 {
 	#[inline(always)]
 	pub(crate) fn addToImgAttributes(this: &RefCell<Self>, attributes: &mut Vec<Attribute>) -> Result<(), CordialError>
+	{
+		attributes.push(Self::srcsetAttribute(&this.try_borrow()?.imageSourceSet));
+		
+		Ok(())
+	}
+	
+	/// Firstly, for each art direction, one `<source media="..." type="..." srcset="...">` node for each of its alternative formats and then its original format.
+	/// Secondly, one `<source type="..." srcset="...">` node for each alternative format, in order of preference.
+	/// Lastly, a `<source type="..." media="(color-gamut: p3)" srcset="...">` node for any wide-gamut variant.
+	///
+	/// Browsers use the first `<source>` that matches, so alternative formats (which are sRGB) are preferred to the wide-gamut variant (which is in the original format); a browser on a wide-gamut display only gets the wide-gamut variant if it supports none of the alternative formats.
+	#[inline(always)]
+	pub(crate) fn sourceNodes(this: &RefCell<Self>, sizesAttribute: Option<Attribute>) -> Result<Vec<UnattachedNode>, CordialError>
+	{
+		let borrowed = this.try_borrow()?;
+		
+		let mut sourceNodes = Vec::with_capacity(borrowed.alternativeImageSourceSets.len() + 1 + borrowed.artDirectedImageSourceSets.len() * (borrowed.alternativeImageSourceSets.len() + 1));
+		
+		for &(ref media, mimeType, ref artDirectedImageSourceSet) in borrowed.artDirectedImageSourceSets.iter()
+		{
+			for &(alternativeMimeType, ref imageSourceSet) in artDirectedImageSourceSet.alternativeImageSourceSets.iter()
+			{
+				sourceNodes.push(Self::sourceNode(alternativeMimeType, Some(media), imageSourceSet, &sizesAttribute));
+			}
+			sourceNodes.push(Self::sourceNode(mimeType, Some(media), &artDirectedImageSourceSet.imageSourceSet, &sizesAttribute));
+		}
+		
+		for &(mimeType, ref imageSourceSet) in borrowed.alternativeImageSourceSets.iter()
+		{
+			sourceNodes.push(Self::sourceNode(mimeType, None, imageSourceSet, &sizesAttribute));
+		}
+		
+		if let Some((mimeType, ref imageSourceSet)) = borrowed.wideGamutImageSourceSet
+		{
+			sourceNodes.push(Self::sourceNode(mimeType, Some("(color-gamut: p3)"), imageSourceSet, &sizesAttribute));
+		}
+		
+		Ok(sourceNodes)
+	}
+	
+	#[inline(always)]
+	fn sourceNode(mimeType: &str, media: Option<&str>, imageSourceSet: &[(Url, u32)], sizesAttribute: &Option<Attribute>) -> UnattachedNode
+	{
+		let sourceNode = "source".with_type_attribute(mimeType);
+		
+		let sourceNode = match media
+		{
+			None => sourceNode,
+			Some(media) => sourceNode.with_attribute("media".str_attribute(media)),
+		};
+		
+		let sourceNode = sourceNode.with_attribute(Self::srcsetAttribute(imageSourceSet));
+		
+		match *sizesAttribute
+		{
+			None => sourceNode,
+			Some(ref sizesAttribute) => sourceNode.with_attribute(sizesAttribute.clone()),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn processedImageSourceSet_default() -> RefCell<ProcessedImageSourceSet>
+	{
+		RefCell::default()
+	}
+	
+	#[inline(always)]
+	fn srcsetAttribute(imageSourceSet: &[(Url, u32)]) -> Attribute
 	{
 		let mut attribute = String::new();
 		let mut afterFirst = false;
-		for &(ref url, width) in this.try_borrow()?.2.iter()
+		for &(ref url, width) in imageSourceSet.iter()
 		{
 			if afterFirst
 			{
@@ -27,14 +107,6 @@ impl ProcessedImageSourceSet
 			attribute.push_str(&format!("{}w", width));
 		}
 		
-		attributes.push("srcset".string_attribute(attribute));
-		
-		Ok(())
-	}
-	
-	#[inline(always)]
-	pub(crate) fn processedImageSourceSet_default() -> RefCell<ProcessedImageSourceSet>
-	{
-		RefCell::default()
+		"srcset".string_attribute(attribute)
 	}
 }
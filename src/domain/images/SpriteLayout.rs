@@ -0,0 +1,99 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub(crate) enum SpriteLayout
+{
+	vertical,
+	horizontal,
+	packed,
+}
+
+impl Default for SpriteLayout
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		SpriteLayout::packed
+	}
+}
+
+impl SpriteLayout
+{
+	/// Returns the sprite sheet's (width, height) and the (x, y) offset of each image, in the same order as `dimensions`.
+	pub(crate) fn layOut(&self, dimensions: &[(u32, u32)], padding: u32) -> ((u32, u32), Vec<(u32, u32)>)
+	{
+		use self::SpriteLayout::*;
+		
+		let mut offsets = Vec::with_capacity(dimensions.len());
+		
+		match *self
+		{
+			vertical =>
+			{
+				let mut sheetWidth = 0;
+				let mut y = 0;
+				for &(width, height) in dimensions.iter()
+				{
+					offsets.push((0, y));
+					sheetWidth = max(sheetWidth, width);
+					y += height + padding;
+				}
+				((sheetWidth, y.saturating_sub(padding)), offsets)
+			}
+			
+			horizontal =>
+			{
+				let mut sheetHeight = 0;
+				let mut x = 0;
+				for &(width, height) in dimensions.iter()
+				{
+					offsets.push((x, 0));
+					sheetHeight = max(sheetHeight, height);
+					x += width + padding;
+				}
+				((x.saturating_sub(padding), sheetHeight), offsets)
+			}
+			
+			// Shelf packing: tallest images first, in rows about as wide as the square root of the total area
+			packed =>
+			{
+				let mut totalArea = 0u64;
+				let mut widest = 0;
+				for &(width, height) in dimensions.iter()
+				{
+					totalArea += ((width + padding) as u64) * ((height + padding) as u64);
+					widest = max(widest, width);
+				}
+				let rowWidth = max(widest, (totalArea as f64).sqrt().ceil() as u32);
+				
+				let mut tallestFirst: Vec<usize> = (0 .. dimensions.len()).collect();
+				tallestFirst.sort_by(|left, right| dimensions[*right].1.cmp(&dimensions[*left].1));
+				
+				offsets.resize(dimensions.len(), (0, 0));
+				let mut sheetWidth = 0;
+				let mut x = 0;
+				let mut y = 0;
+				let mut rowHeight = 0;
+				for index in tallestFirst
+				{
+					let (width, height) = dimensions[index];
+					if x != 0 && x + width > rowWidth
+					{
+						x = 0;
+						y += rowHeight + padding;
+						rowHeight = 0;
+					}
+					
+					offsets[index] = (x, y);
+					sheetWidth = max(sheetWidth, x + width);
+					rowHeight = max(rowHeight, height);
+					x += width + padding;
+				}
+				((sheetWidth, y + rowHeight), offsets)
+			}
+		}
+	}
+}
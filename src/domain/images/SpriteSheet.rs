@@ -0,0 +1,80 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+pub(crate) struct SpriteSheet
+{
+	image: ::image::DynamicImage,
+	sprites: Vec<(String, u32, u32, u32, u32)>,
+}
+
+impl SpriteSheet
+{
+	/// `images` are (sprite name, image) pairs.
+	#[inline(always)]
+	pub(crate) fn new(images: Vec<(String, ::image::DynamicImage)>, layout: SpriteLayout, padding: u32) -> Self
+	{
+		let dimensions: Vec<(u32, u32)> = images.iter().map(|&(_, ref image)| image.dimensions()).collect();
+		let ((width, height), offsets) = layout.layOut(&dimensions, padding);
+		
+		let mut sheet = ::image::DynamicImage::new_rgba8(width, height);
+		let mut sprites = Vec::with_capacity(images.len());
+		
+		for ((name, image), (x, y)) in images.into_iter().zip(offsets.into_iter())
+		{
+			let (width, height) = image.dimensions();
+			sheet.copy_from(&image, x, y);
+			sprites.push((name, x, y, width, height));
+		}
+		
+		Self
+		{
+			image: sheet,
+			sprites,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn image(&self) -> &::image::DynamicImage
+	{
+		&self.image
+	}
+	
+	/// Use as say `background-position: map-get(map-get($sprites, "name"), "offset-x") map-get(map-get($sprites, "name"), "offset-y");`
+	pub(crate) fn scssMap(&self, mapName: &str) -> String
+	{
+		let (sheetWidth, sheetHeight) = self.image.dimensions();
+		
+		let mut scss = String::with_capacity(128 + self.sprites.len() * 128);
+		scss.push_str(&format!("${}-width: {}px;\n", mapName, sheetWidth));
+		scss.push_str(&format!("${}-height: {}px;\n", mapName, sheetHeight));
+		scss.push_str(&format!("${}: (\n", mapName));
+		for &(ref name, x, y, width, height) in self.sprites.iter()
+		{
+			scss.push_str(&format!("\t\"{}\": (\"offset-x\": {}px, \"offset-y\": {}px, \"width\": {}px, \"height\": {}px),\n", Self::escapeScssQuotedString(name), -(x as i64), -(y as i64), width, height));
+		}
+		scss.push_str(");\n");
+		scss
+	}
+	
+	/// Escapes quotes, backslashes and interpolation (`#{`) so any name is a valid SCSS quoted string.
+	#[inline(always)]
+	fn escapeScssQuotedString(value: &str) -> String
+	{
+		let mut escaped = String::with_capacity(value.len());
+		for character in value.chars()
+		{
+			match character
+			{
+				'"' | '\\' | '#' =>
+				{
+					escaped.push('\\');
+					escaped.push(character);
+				}
+				'\n' => escaped.push_str("\\a "),
+				_ => escaped.push(character),
+			}
+		}
+		escaped
+	}
+}
@@ -7,16 +7,31 @@ use ::image::GenericImage;
 use ::image::jpeg::JPEGEncoder;
 
 
+include!("EmbeddedCreditAndLicenseUrl.rs");
+include!("EmbeddedImageMetaData.rs");
+include!("FocalPoint.rs");
+include!("IccProfile.rs");
 include!("ImageAbstract.rs");
+include!("ImageAlternativeFormat.rs");
+include!("ImageArtDirection.rs");
 include!("ImageCrop.rs");
+include!("ImageEncoderSettings.rs");
 include!("ImageMetaData.rs");
+include!("ImagePosition.rs");
+include!("ImagePlaceholder.rs");
+include!("ImagePlaceholderSettings.rs");
 include!("ImageScale.rs");
 include!("ImageSourceSet.rs");
 include!("ImageSourceSetEntry.rs");
 include!("ImageTransformation.rs");
 include!("ImageTransformationFilterType.rs");
+include!("JpegChromaSubsampling.rs");
+include!("JpegEncoder.rs");
 include!("Length.rs");
 include!("LongDescription.rs");
 include!("MediaQueryAndLength.rs");
+include!("PngQuantization.rs");
 include!("ProcessedImageSourceSet.rs");
 include!("ReferrerPolicy.rs");
+include!("SpriteLayout.rs");
+include!("SpriteSheet.rs");
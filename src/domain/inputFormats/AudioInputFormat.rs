@@ -7,6 +7,10 @@
 pub(crate) enum AudioInputFormat
 {
 	MP4,
+	MP3,
+	Ogg_Vorbis,
+	Ogg_Opus,
+	FLAC,
 }
 
 impl Default for AudioInputFormat
@@ -28,6 +32,10 @@ impl InputFormat for AudioInputFormat
 		match *self
 		{
 			MP4 => &[".mp4"],
+			MP3 => &[".mp3"],
+			Ogg_Vorbis => &[".ogg", ".oga"],
+			Ogg_Opus => &[".opus"],
+			FLAC => &[".flac"],
 		}
 	}
 	
@@ -36,10 +44,98 @@ impl InputFormat for AudioInputFormat
 	{
 		&[
 			".mp4",
+			".mp3",
+			".ogg",
+			".oga",
+			".opus",
+			".flac",
 		]
 	}
 }
 
 impl AudioInputFormat
 {
+	/// Order of `<source>` elements; browsers use the first they support, so the most efficient come first and lossless FLAC last.
+	pub(crate) const SourceOrder: [AudioInputFormat; 5] = [AudioInputFormat::Ogg_Opus, AudioInputFormat::Ogg_Vorbis, AudioInputFormat::MP4, AudioInputFormat::MP3, AudioInputFormat::FLAC];
+	
+	/// Order of compatibility with podcast clients and other players, most compatible first; the most compatible is the `default`, and so the podcast enclosure.
+	pub(crate) const CompatibilityOrder: [AudioInputFormat; 5] = [AudioInputFormat::MP3, AudioInputFormat::MP4, AudioInputFormat::Ogg_Vorbis, AudioInputFormat::Ogg_Opus, AudioInputFormat::FLAC];
+	
+	#[inline(always)]
+	pub(crate) fn fromInputContentFilePath(option: Option<Self>, inputContentFilePath: &Path) -> Self
+	{
+		use self::AudioInputFormat::*;
+		
+		match option
+		{
+			Some(format) => format,
+			None => match inputContentFilePath.extension().unwrap().to_str().unwrap()
+			{
+				"mp4" => MP4,
+				"mp3" => MP3,
+				"ogg" | "oga" => Ogg_Vorbis,
+				"opus" => Ogg_Opus,
+				"flac" => FLAC,
+				_ => panic!("How is this possible?"),
+			}
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn audioDetails(&self, bytes: &[u8], inputContentFilePath: &Path) -> Result<AudioDetails, CordialError>
+	{
+		use self::AudioInputFormat::*;
+		
+		match *self
+		{
+			MP4 => AudioDetails::mp4(bytes),
+			MP3 => AudioDetails::mp3(bytes, inputContentFilePath),
+			Ogg_Vorbis => AudioDetails::ogg(bytes, AudioCodec::vorbis, inputContentFilePath),
+			Ogg_Opus => AudioDetails::ogg(bytes, AudioCodec::opus, inputContentFilePath),
+			FLAC => AudioDetails::flac(bytes, inputContentFilePath),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn contentType(&self) -> ContentType
+	{
+		use self::AudioInputFormat::*;
+		
+		match *self
+		{
+			MP4 => audioMp4TwitterContentType(),
+			MP3 => content_type_audio_mpeg(),
+			Ogg_Vorbis => content_type_audio_ogg(AudioCodec::vorbis),
+			Ogg_Opus => content_type_audio_ogg(AudioCodec::opus),
+			FLAC => content_type_audio_flac(),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn resourceTag(&self) -> ResourceTag
+	{
+		use self::AudioInputFormat::*;
+		
+		match *self
+		{
+			MP4 => ResourceTag::audio_mp4,
+			MP3 => ResourceTag::audio_mp3,
+			Ogg_Vorbis => ResourceTag::audio_ogg_vorbis,
+			Ogg_Opus => ResourceTag::audio_ogg_opus,
+			FLAC => ResourceTag::audio_flac,
+		}
+	}
+	
+	/// The input file for this format sitting alongside an input file of another format, eg `podcast.mp3` next to `podcast.mp4`.
+	#[inline(always)]
+	pub(crate) fn companionInputContentFilePath(&self, inputContentFilePath: &Path) -> PathBuf
+	{
+		inputContentFilePath.with_extension(&self.fileExtensions()[0][1..])
+	}
+	
+	#[inline(always)]
+	pub(crate) fn url(&self, resourceUrl: &ResourceUrl, languageData: &LanguageData) -> Result<Url, CordialError>
+	{
+		resourceUrl.replaceFileNameExtension(self.fileExtensions()[0]).url(languageData)
+	}
 }
@@ -8,6 +8,8 @@ pub(crate) enum FontInputFormat
 {
 	TTF,
 	OTF,
+	WOFF,
+	WOFF2,
 }
 
 impl Default for FontInputFormat
@@ -30,6 +32,8 @@ impl InputFormat for FontInputFormat
 		{
 			TTF => &[".ttf"],
 			OTF => &[".otf"],
+			WOFF => &[".woff"],
+			WOFF2 => &[".woff2"],
 		}
 	}
 	
@@ -39,41 +43,78 @@ impl InputFormat for FontInputFormat
 		&[
 			".ttf",
 			".otf",
+			".woff",
+			".woff2",
 		]
 	}
 }
 
 impl FontInputFormat
 {
+	/// WOFF and WOFF2 inputs are decompressed back to sfnt (TTF or OTF) so they can be instanced, subset and re-encoded.
 	#[inline(always)]
-	pub(crate) fn toWebFonts(option: Option<Self>, resourceUrl: &ResourceUrl, inputContentFilePath: &Path, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, maximumAgeInSeconds: u32, isDownloadable: bool, utf8_xml_metadata: &[u8], woff1_private_data: &[u8], woff1_iterations: u16, woff2_brotli_quality: u8, woff2_disallow_transforms: bool, include_ttf: Option<TtfMimeType>) -> Result<Vec<PipelineResponse>, CordialError>
+	pub(crate) fn sfntBytes(option: Option<Self>, inputContentFilePath: &Path) -> Result<Vec<u8>, CordialError>
 	{
+		use self::FontInputFormat::*;
+		
 		let format = match option
 		{
 			Some(format) => format,
-			None =>
+			None => match inputContentFilePath.extension().unwrap().to_str().unwrap()
 			{
-				use self::FontInputFormat::*;
-				
-				match inputContentFilePath.extension().unwrap().to_str().unwrap()
-				{
-					"ttf" => TTF,
-					"otf" => OTF,
-					_ => panic!("How is this possible?"),
-				}
+				"ttf" => TTF,
+				"otf" => OTF,
+				"woff" => WOFF,
+				"woff2" => WOFF2,
+				_ => panic!("How is this possible?"),
 			}
 		};
 		
-		format.process(resourceUrl, inputContentFilePath, headerGenerator, languageData, maximumAgeInSeconds, isDownloadable, &utf8_xml_metadata[..], &woff1_private_data[..], woff1_iterations, woff2_brotli_quality, woff2_disallow_transforms, include_ttf)
+		let bytes = inputContentFilePath.fileContentsAsBytes().context(inputContentFilePath)?;
+		
+		match format
+		{
+			TTF | OTF => Ok(bytes),
+			
+			WOFF => match decodeWoff(&bytes)
+			{
+				Err(error) => Err(CordialError::CouldNotDecodeWebFont(inputContentFilePath.to_path_buf(), format!("{}", error))),
+				Ok(sfntBytes) => Ok(sfntBytes.as_ref().to_vec()),
+			},
+			
+			WOFF2 => match ::wuff::decompress_woff2(&bytes)
+			{
+				Err(error) => Err(CordialError::CouldNotDecodeWebFont(inputContentFilePath.to_path_buf(), format!("{:?}", error))),
+				Ok(sfntBytes) => Ok(sfntBytes),
+			},
+		}
 	}
 	
+	/// Static instances and unicode range chunks are named like `font-wght400-latin.woff2`.
 	#[inline(always)]
-	fn process(&self, resourceUrl: &ResourceUrl, inputContentFilePath: &Path, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, maximumAgeInSeconds: u32, isDownloadable: bool, utf8_xml_metadata: &[u8], woff1_private_data: &[u8], woff1_iterations: u16, woff2_brotli_quality: u8, woff2_disallow_transforms: bool, includeTrueTypeFont: Option<TtfMimeType>) -> Result<Vec<PipelineResponse>, CordialError>
+	pub(crate) fn toWebFonts(ttfBytes: Vec<u8>, staticInstance: Option<(usize, &str)>, unicodeRange: Option<(usize, &str)>, resourceUrl: &ResourceUrl, inputContentFilePath: &Path, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, maximumAgeInSeconds: u32, isDownloadable: bool, utf8_xml_metadata: &[u8], woff1_private_data: &[u8], woff1_iterations: u16, woff2_brotli_quality: u8, woff2_disallow_transforms: bool, includeTrueTypeFont: Option<TtfMimeType>) -> Result<Vec<PipelineResponse>, CordialError>
 	{
+		use self::ResourceTag::*;
+		
 		const CanBeCompressed: bool = true;
 		const CanNotBeCompressed: bool = false;
 		
-		let ttfBytes = inputContentFilePath.fileContentsAsBytes().context(inputContentFilePath)?;
+		let isWholeFont = staticInstance.is_none() && unicodeRange.is_none();
+		
+		let resourceUrl = if isWholeFont
+		{
+			resourceUrl.clone()
+		}
+		else
+		{
+			let mut fileNameWithoutExtension = resourceUrl.withoutFileNameExtension().to_owned();
+			for &(_, name) in staticInstance.iter().chain(unicodeRange.iter())
+			{
+				fileNameWithoutExtension.push('-');
+				fileNameWithoutExtension.push_str(name);
+			}
+			ResourceUrl::string(format!("{}.ttf", fileNameWithoutExtension))
+		};
 		
 		let mut urls = Vec::with_capacity(3);
 		
@@ -84,11 +125,19 @@ impl FontInputFormat
 				woffNumberOfIterations @ 0 ... 5000 => woffNumberOfIterations,
 				_ => 5000,
 			};
-			let woffUrl = resourceUrl.replaceFileNameExtension(".woff2").url(languageData)?;
+			let woffUrl = resourceUrl.replaceFileNameExtension(".woff").url(languageData)?;
 			let woffHeaders = headerGenerator.generateHeadersForAsset(CanNotBeCompressed, maximumAgeInSeconds, isDownloadable, &woffUrl)?;
 			let woffBody = encodeWoff(&ttfBytes, woffNumberOfIterations, DefaultFontMajorVersion, DefaultFontMinorVersion, utf8_xml_metadata, woff1_private_data).context(inputContentFilePath)?.as_ref().to_vec();
+			let urlDataDetails = Rc::new(UrlDataDetails::generic(&woffBody));
+			let tags = match (staticInstance, unicodeRange)
+			{
+				(None, None) => hashmap! { default => urlDataDetails.clone(), font_woff => urlDataDetails },
+				(None, Some((rangeIndex, _))) => hashmap! { font_woff_unicode_range(rangeIndex) => urlDataDetails },
+				(Some((instanceIndex, _)), None) => hashmap! { font_woff_static_instance(instanceIndex) => urlDataDetails },
+				(Some((instanceIndex, _)), Some((rangeIndex, _))) => hashmap! { font_woff_static_instance_unicode_range(instanceIndex, rangeIndex) => urlDataDetails },
+			};
 			let NoPjax = None;
-			urls.push((woffUrl, Self::defaultHashMap(&woffBody), StatusCode::Ok, content_type_font_woff(), woffHeaders, ResponseBody::binary(woffBody), NoPjax, CanNotBeCompressed));
+			urls.push((woffUrl, tags, StatusCode::Ok, content_type_font_woff(), woffHeaders, ResponseBody::binary(woffBody), NoPjax, CanNotBeCompressed));
 		}
 		
 		// woff2
@@ -106,24 +155,30 @@ impl FontInputFormat
 				Err(()) => return Err(CordialError::Configuration("Could not encode font to WOFF2".to_owned())),
 				Ok(body) => body,
 			};
+			let urlDataDetails = Rc::new(UrlDataDetails::generic(&woff2Body));
+			let tags = match (staticInstance, unicodeRange)
+			{
+				(None, None) => hashmap! { default => urlDataDetails.clone(), font_woff2 => urlDataDetails },
+				(None, Some((rangeIndex, _))) => hashmap! { font_woff2_unicode_range(rangeIndex) => urlDataDetails },
+				(Some((instanceIndex, _)), None) => hashmap! { font_woff2_static_instance(instanceIndex) => urlDataDetails },
+				(Some((instanceIndex, _)), Some((rangeIndex, _))) => hashmap! { font_woff2_static_instance_unicode_range(instanceIndex, rangeIndex) => urlDataDetails },
+			};
 			let NoPjax = None;
-			urls.push((woff2Url, Self::defaultHashMap(&woff2Body), StatusCode::Ok, content_type_font_woff2(), woff2Headers, ResponseBody::binary(woff2Body), NoPjax, CanNotBeCompressed));
+			urls.push((woff2Url, tags, StatusCode::Ok, content_type_font_woff2(), woff2Headers, ResponseBody::binary(woff2Body), NoPjax, CanNotBeCompressed));
 		}
 		
-		if let Some(trueTypeFontContentType) = includeTrueTypeFont
+		if isWholeFont
 		{
-			let ttfUrl = resourceUrl.url(languageData)?;
-			let ttfHeaders =  headerGenerator.generateHeadersForAsset(CanBeCompressed, maximumAgeInSeconds, isDownloadable, &ttfUrl)?;
-			let NoPjax = None;
-			urls.push((ttfUrl, Self::defaultHashMap(&ttfBytes), StatusCode::Ok, trueTypeFontContentType.contentType(), ttfHeaders, ResponseBody::binary(ttfBytes), NoPjax, CanBeCompressed));
+			if let Some(trueTypeFontContentType) = includeTrueTypeFont
+			{
+				let ttfUrl = resourceUrl.url(languageData)?;
+				let ttfHeaders =  headerGenerator.generateHeadersForAsset(CanBeCompressed, maximumAgeInSeconds, isDownloadable, &ttfUrl)?;
+				let NoPjax = None;
+				let urlDataDetails = Rc::new(UrlDataDetails::generic(&ttfBytes));
+				urls.push((ttfUrl, hashmap! { default => urlDataDetails.clone(), font_ttf => urlDataDetails }, StatusCode::Ok, trueTypeFontContentType.contentType(), ttfHeaders, ResponseBody::binary(ttfBytes), NoPjax, CanBeCompressed));
+			}
 		}
 		
 		Ok(urls)
 	}
-	
-	#[inline(always)]
-	fn defaultHashMap(body: &[u8]) -> HashMap<ResourceTag, Rc<UrlDataDetails>>
-	{
-		hashmap! { ResourceTag::default => Rc::new(UrlDataDetails::generic(body)) }
-	}
 }
@@ -74,7 +74,7 @@ impl ImageInputFormat
 	{
 		if let Some(imageInputFormat) = option
 		{
-			Some(path.fileContentsAsImage(imageInputFormat))
+			Some(imageInputFormat.loadAutoOriented(path))
 		}
 		else if let Some(osStrExtension) = path.extension()
 		{
@@ -103,7 +103,7 @@ impl ImageInputFormat
 					"vst" => Targa,
 					_ => return None,
 				};
-				Some(path.fileContentsAsImage(imageInputFormat))
+				Some(imageInputFormat.loadAutoOriented(path))
 			}
 			else
 			{
@@ -115,4 +115,19 @@ impl ImageInputFormat
 			None
 		}
 	}
+	
+	/// JPEG and TIFF images are rotated and flipped upright using their EXIF `Orientation` tag.
+	#[inline(always)]
+	fn loadAutoOriented(self, path: &Path) -> Result<::image::DynamicImage, CordialError>
+	{
+		use self::ImageInputFormat::*;
+		
+		let image = path.fileContentsAsImage(self)?;
+		
+		match self
+		{
+			JPEG | TIFF => Ok(EmbeddedImageMetaData::read(path)?.autoOrient(image)),
+			_ => Ok(image),
+		}
+	}
 }
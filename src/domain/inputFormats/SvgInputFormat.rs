@@ -73,7 +73,7 @@ impl InputFormat for SvgInputFormat
 impl SvgInputFormat
 {
 	#[inline(always)]
-	pub(crate) fn svgString(&self, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, configuration: &Configuration) -> Result<String, CordialError>
+	pub(crate) fn svgString(&self, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<String, CordialError>
 	{
 		use self::SvgInputFormat::*;
 		
@@ -95,7 +95,7 @@ impl SvgInputFormat
 			
 			BARCODE(ref barcode) => barcode.svgString(),
 			
-			PLOT(ref plotSettings) => plotSettings.svgString(),
+			PLOT(ref plotSettings) => plotSettings.svgString(configuration, iso639Dash1Alpha2Language),
 		}
 	}
 }
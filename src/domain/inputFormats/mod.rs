@@ -18,8 +18,12 @@ include!("BrowserConfigInputFormat.rs");
 include!("CssInputFormat.rs");
 include!("FontInputFormat.rs");
 include!("HtmlInputFormat.rs");
+include!("IconSetInputFormat.rs");
 include!("ImageInputFormat.rs");
 include!("InputFormat.rs");
+include!("SocialCardInputFormat.rs");
+include!("SpriteInputFormat.rs");
 include!("SvgInputFormat.rs");
+include!("SvgSpriteInputFormat.rs");
 include!("VideoInputFormat.rs");
 include!("WebAppManifestInputFormat.rs");
@@ -122,6 +122,45 @@ impl Localization
 		Ok(serverHostNames)
 	}
 	
+	/// Finds the resource an absolute URL on one of the site's own hosts refers to; `None` if the URL is external to the site.
+	pub(crate) fn resourceUrlForSiteUrl(&self, url: &Url) -> Option<ResourceUrl>
+	{
+		if url.scheme() != "https"
+		{
+			return None;
+		}
+		
+		let host = match url.host_str()
+		{
+			None => return None,
+			Some(host) => host,
+		};
+		
+		let path = url.path();
+		let mut longestRelativeRootUrlLength = None;
+		for (iso639Dash1Alpha2Language, language) in self.languages.iter()
+		{
+			if language.host() != host
+			{
+				continue;
+			}
+			
+			for relativeRootUrl in language.robotsTxtRelativeRootUrls(*iso639Dash1Alpha2Language)
+			{
+				if path.starts_with(relativeRootUrl.as_ref())
+				{
+					let length = relativeRootUrl.len();
+					if longestRelativeRootUrlLength.map(|longest| length > longest).unwrap_or(true)
+					{
+						longestRelativeRootUrlLength = Some(length);
+					}
+				}
+			}
+		}
+		
+		longestRelativeRootUrlLength.map(|length| ResourceUrl::string(&path[length..]))
+	}
+	
 	#[inline(always)]
 	fn language_tool_base_url_default() -> Url
 	{
@@ -120,6 +120,157 @@ impl<'a> ImageMarkdownPluginData<'a>
 		Ok("img".with_attributes(self.imgLikeAttributes(false, false)?))
 	}
 	
+	/// Not used for AMP, as AMP forbids inline `style` attributes and has its own placeholder mechanism.
+	#[inline(always)]
+	fn imgNodeWithPlaceholder(&self) -> Result<UnattachedNode, CordialError>
+	{
+		let mut attributes = self.imgLikeAttributes(false, false)?;
+		if let Some(placeholder) = self.primaryImageUrlData.placeholder()
+		{
+			placeholder.addToImgAttributes(&mut attributes);
+		}
+		Ok("img".with_attributes(attributes))
+	}
+	
+	/// Falls back to just an `<img>` if the image has no alternative formats (eg it is a SVG).
+	/// A GIF animation with a muted video is wrapped in an autoplaying `<video>`, with the `<picture>` as fallback content.
+	//noinspection SpellCheckingInspection
+	pub(crate) fn pictureNode(&self) -> Result<UnattachedNode, CordialError>
+	{
+		let pictureNode = self.pictureNodeWithoutVideo()?;
+		
+		match self.imageResource.animationMutedVideo()
+		{
+			None => Ok(pictureNode),
+			Some((mutedVideoResourceUrl, loops)) => self.mutedVideoNode(mutedVideoResourceUrl, loops, pictureNode),
+		}
+	}
+	
+	/// The AMP equivalent of a GIF animation's muted video, an autoplaying `<amp-video>` with the `<amp-anim>` as fallback; `None` if the animation has no muted video.
+	//noinspection SpellCheckingInspection
+	pub(crate) fn ampMutedVideoNode(&self, displayAmpLoadingIndicator: bool) -> Result<Option<UnattachedNode>, CordialError>
+	{
+		/*
+			<amp-video autoplay noaudio loop width="245" height="300" poster="/img/gopher-placeholder.gif" layout="responsive">
+				<source type="video/mp4" src="/img/gopher.mp4">
+				<amp-anim fallback ...>...</amp-anim>
+			</amp-video>
+		*/
+		
+		let (mutedVideoResourceUrl, loops) = match self.imageResource.animationMutedVideo()
+		{
+			None => return Ok(None),
+			Some(mutedVideo) => mutedVideo,
+		};
+		
+		let mut attributes = vec!
+		[
+			"autoplay".empty_attribute(),
+			"noaudio".empty_attribute(),
+		];
+		self.addMutedVideoAttributes(&mut attributes, loops)?;
+		attributes.push(AmpLayout::responsive.toAttribute());
+		
+		let ampVideoNode = "amp-video"
+			.with_attributes(attributes)
+			.with_child_element(self.mutedVideoSourceNode(mutedVideoResourceUrl)?)
+			.with_child_element(self.ampAnimNode(displayAmpLoadingIndicator)?.with_empty_attribute("fallback"));
+		
+		Ok(Some(ampVideoNode))
+	}
+	
+	//noinspection SpellCheckingInspection
+	fn mutedVideoNode(&self, mutedVideoResourceUrl: &ResourceUrl, loops: bool, fallbackNode: UnattachedNode) -> Result<UnattachedNode, CordialError>
+	{
+		/*
+			<video autoplay muted playsinline loop width="245" height="300" poster="/img/gopher-placeholder.gif">
+				<source type="video/mp4" src="/img/gopher.mp4">
+				<picture>...</picture>
+			</video>
+		*/
+		
+		let mut attributes = vec!
+		[
+			"autoplay".empty_attribute(),
+			"muted".empty_attribute(),
+			"playsinline".empty_attribute(),
+		];
+		self.addMutedVideoAttributes(&mut attributes, loops)?;
+		
+		let videoNode = "video"
+			.with_attributes(attributes)
+			.with_child_element(self.mutedVideoSourceNode(mutedVideoResourceUrl)?)
+			.with_child_element(fallbackNode);
+		
+		Ok(videoNode)
+	}
+	
+	#[inline(always)]
+	fn addMutedVideoAttributes(&self, attributes: &mut Vec<Attribute>, loops: bool) -> Result<(), CordialError>
+	{
+		if loops
+		{
+			attributes.push("loop".empty_attribute());
+		}
+		if self.isAnimated()
+		{
+			attributes.push("poster".str_attribute(self.url(true)?));
+		}
+		let (width, height) = self.primaryImageUrlData.dimensions()?;
+		attributes.push("width".u32_attribute(width));
+		attributes.push("height".u32_attribute(height));
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn mutedVideoSourceNode(&self, mutedVideoResourceUrl: &ResourceUrl) -> Result<UnattachedNode, CordialError>
+	{
+		let mp4UrlData = ResourceReference
+		{
+			resource: mutedVideoResourceUrl.clone(),
+			tag: ResourceTag::video_mp4,
+		}.urlDataMandatory(self.resources(), self.fallbackIso639Dash1Alpha2Language(), Some(self.iso639Dash1Alpha2Language()))?;
+		
+		Ok
+		(
+			"source"
+				.with_type_attribute(mp4UrlData.mimeType().as_ref())
+				.with_attribute("src".str_attribute(mp4UrlData.url_str()))
+		)
+	}
+	
+	//noinspection SpellCheckingInspection
+	fn pictureNodeWithoutVideo(&self) -> Result<UnattachedNode, CordialError>
+	{
+		/*
+			<picture>
+				<source type="image/avif" srcset="/img/amp.avif 1080w, /img/amp-900w.avif 900w" sizes="(min-width: 36em) 33.3vw, 100vw">
+				<source type="image/webp" srcset="/img/amp.webp 1080w, /img/amp-900w.webp 900w" sizes="(min-width: 36em) 33.3vw, 100vw">
+				<img alt="AMP" width="1080" height="610" src="/img/amp.jpg" srcset="/img/amp.jpg 1080w, /img/amp-900w.jpg 900w" sizes="(min-width: 36em) 33.3vw, 100vw" style="background-size:cover;background-image:url(data:image/jpeg;base64,...)" data-blurhash="LEHV6nWB2yk8pyo0adR*.7kCMdnj">
+			</picture>
+		*/
+		
+		let imgNode = self.imgNodeWithPlaceholder()?;
+		
+		let sourceNodes = match self.imageResource.processedImageSourceSet()
+		{
+			Err(_) => return Ok(imgNode),
+			Ok(processedImageSourceSet) => ProcessedImageSourceSet::sourceNodes(processedImageSourceSet, self.imageMetaData.sizesAttribute())?,
+		};
+		
+		if sourceNodes.is_empty()
+		{
+			return Ok(imgNode)
+		}
+		
+		let mut pictureNode = "picture".with_attributes(vec![]);
+		for sourceNode in sourceNodes
+		{
+			pictureNode = pictureNode.with_child_element(sourceNode);
+		}
+		Ok(pictureNode.with_child_element(imgNode))
+	}
+	
 	//noinspection SpellCheckingInspection
 	pub(crate) fn figcaptionNode(&self) -> Result<UnattachedNode, CordialError>
 	{
@@ -214,7 +365,7 @@ impl<'a> ImageMarkdownPluginData<'a>
 	#[inline(always)]
 	fn credit(&self) -> &str
 	{
-		self.imageMetaData.credit.as_str()
+		self.imageMetaData.credit(self.fallbackIso639Dash1Alpha2Language(), self.iso639Dash1Alpha2Language()).as_str()
 	}
 	
 	#[inline(always)]
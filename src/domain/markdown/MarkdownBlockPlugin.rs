@@ -8,9 +8,19 @@ pub(crate) enum MarkdownBlockPlugin
 {
 	csv,
 	
+	plot,
+	
 	svgbob,
 
 	lua,
+	
+	qrcode,
+	
+	barcode,
+	
+	meme,
+	
+	mon_artist,
 }
 
 impl MarkdownBlockPlugin
@@ -23,8 +33,13 @@ impl MarkdownBlockPlugin
 		hashmap!
 		{
 			b"csv".to_vec() => csv,
+			b"plot".to_vec() => plot,
 			b"svgbob".to_vec() => svgbob,
 			b"lua".to_vec() => lua,
+			b"qrcode".to_vec() => qrcode,
+			b"barcode".to_vec() => barcode,
+			b"meme".to_vec() => meme,
+			b"mon-artist".to_vec() => mon_artist,
 		}
 	}
 	
@@ -42,8 +57,13 @@ impl MarkdownBlockPlugin
 		match *self
 		{
 			csv => MarkdownPluginResult::fromHtmlFragment(Self::csv(arguments, string)?),
+			plot => MarkdownPluginResult::fromHtmlFragment(Self::plot(arguments, string, markdownPluginData)?),
 			svgbob => MarkdownPluginResult::fromHtmlFragment(Self::svgbob(arguments, string)?),
 			lua => Self::lua(arguments, string, markdownPluginData, isForAmp),
+			qrcode => MarkdownPluginResult::fromHtmlFragment(Self::qrcode(arguments, string)?),
+			barcode => MarkdownPluginResult::fromHtmlFragment(Self::barcode(arguments, string)?),
+			meme => MarkdownPluginResult::fromHtmlFragment(Self::meme(arguments, string)?),
+			mon_artist => MarkdownPluginResult::fromHtmlFragment(Self::mon_artist(arguments, string, markdownPluginData)?),
 		}
 	}
 	
@@ -88,6 +108,102 @@ impl MarkdownBlockPlugin
 		Ok(buffer)
 	}
 	
+	/// The block is the HJSON for the plot settings, as used by the `plot` input format of the `svg` pipeline; use `csv_data_file` so the chart is updated when its data is.
+	fn plot(arguments: &[u8], block: &str, markdownPluginData: &MarkdownPluginData) -> Result<String, CordialError>
+	{
+		if !arguments.is_empty()
+		{
+			return Err(CordialError::Configuration("Markdown block plugin plot takes no arguments".to_owned()));
+		}
+		
+		let plotSettings: PlotSettings = match ::serde_hjson::from_str::<HjsonValue>(block)
+		{
+			Err(error) => return Err(CordialError::Configuration(format!("Markdown block plugin plot has invalid HJSON: {}", error))),
+			Ok(hjsonValue) => deserializeHjson(hjsonValue)?,
+		};
+		
+		Ok(Self::inlineSvg(plotSettings.svgString(markdownPluginData.configuration, markdownPluginData.iso639Dash1Alpha2Language())?))
+	}
+	
+	/// The block is the data to encode; the optional arguments are the HJSON for the other QR code settings, eg ` ```qrcode {error_correction_level: H}`.
+	fn qrcode(arguments: &[u8], block: &str) -> Result<String, CordialError>
+	{
+		let qrCodeData: QrCodeData = Self::settings("qrcode", arguments, Some(("data", block.trim_right_matches('\n'))))?;
+		
+		Ok(Self::inlineSvg(qrCodeData.svgString()?))
+	}
+	
+	/// The block is the data to encode; the arguments are the HJSON for the other barcode settings, eg ` ```barcode {variant: Code128, height: 80, narrow_bar_width: 2}`.
+	fn barcode(arguments: &[u8], block: &str) -> Result<String, CordialError>
+	{
+		let barcode: Barcode = Self::settings("barcode", arguments, Some(("data", block.trim_right_matches('\n'))))?;
+		
+		Ok(Self::inlineSvg(barcode.svgString()?))
+	}
+	
+	/// The block is the meme text; the optional arguments are the HJSON for the meme settings.
+	fn meme(arguments: &[u8], block: &str) -> Result<String, CordialError>
+	{
+		let meme: Meme = Self::settings("meme", arguments, None)?;
+		
+		Ok(Self::inlineSvg(meme.svgStringFromStr(block)))
+	}
+	
+	/// The block is the mon-artist drawing; the optional arguments are the HJSON for the mon-artist settings.
+	/// Unless a `name` is configured, the name (which prefixes the ids in the SVG) is derived from a hash of the block and its arguments, so that several drawings can be inlined into one page.
+	fn mon_artist(arguments: &[u8], block: &str, markdownPluginData: &MarkdownPluginData) -> Result<String, CordialError>
+	{
+		use ::std::collections::hash_map::DefaultHasher;
+		use ::std::hash::Hash;
+		use ::std::hash::Hasher;
+		
+		let monArtist: MonArtist = Self::settings("mon-artist", arguments, None)?;
+		
+		let mut hasher = DefaultHasher::new();
+		arguments.hash(&mut hasher);
+		block.hash(&mut hasher);
+		let defaultName = format!("mon-artist-{:016x}", hasher.finish());
+		
+		Ok(Self::inlineSvg(monArtist.svgStringFromStr(block, &defaultName, markdownPluginData.configuration)?))
+	}
+	
+	/// Arguments, if any, are a HJSON object of settings; `data`, if any, is added to them.
+	#[inline(always)]
+	fn settings<T: DeserializeOwned>(pluginName: &str, arguments: &[u8], data: Option<(&str, &str)>) -> Result<T, CordialError>
+	{
+		let mut settings = if arguments.is_empty()
+		{
+			HjsonMap::new()
+		}
+		else
+		{
+			match ::serde_hjson::from_slice::<HjsonValue>(arguments)
+			{
+				Err(error) => return Err(CordialError::Configuration(format!("Markdown block plugin {} has invalid HJSON arguments: {}", pluginName, error))),
+				Ok(HjsonValue::Object(settings)) => settings,
+				Ok(_) => return Err(CordialError::Configuration(format!("Markdown block plugin {} arguments must be a HJSON object", pluginName))),
+			}
+		};
+		
+		if let Some((key, value)) = data
+		{
+			settings.insert(key.to_owned(), HjsonValue::String(value.to_owned()));
+		}
+		
+		deserializeHjson(HjsonValue::Object(settings))
+	}
+	
+	/// Generated SVG is inlined in the HTML (which AMP permits), so any XML declaration or DOCTYPE before the root element is removed.
+	#[inline(always)]
+	fn inlineSvg(svgString: String) -> String
+	{
+		match svgString.find("<svg")
+		{
+			None | Some(0) => svgString,
+			Some(index) => svgString[index..].to_owned(),
+		}
+	}
+	
 	//noinspection SpellCheckingInspection
 	fn svgbob(arguments: &[u8], block: &str) -> Result<String, CordialError>
 	{
@@ -11,6 +11,7 @@ pub(crate) enum MarkdownInlinePlugin
 	audio,
 	video,
 	shortcode,
+	svg_symbol,
 }
 
 impl MarkdownInlinePlugin
@@ -27,6 +28,7 @@ impl MarkdownInlinePlugin
 			b"audio".to_vec() => audio,
 			b"video".to_vec() => video,
 			b"shortcode".to_vec() => shortcode,
+			b"svg_symbol".to_vec() => svg_symbol,
 		}
 	}
 	
@@ -42,6 +44,7 @@ impl MarkdownInlinePlugin
 			audio => Self::audio(&mut parseQueryString(arguments), nodesForOtherPlacesInHtml, markdownPluginData, isForAmp),
 			video => Self::video(&mut parseQueryString(arguments), nodesForOtherPlacesInHtml, markdownPluginData, isForAmp),
 			shortcode => Self::shortcode(arguments, nodesForOtherPlacesInHtml, markdownPluginData, isForAmp),
+			svg_symbol => Self::svg_symbol(&mut parseQueryString(arguments), nodesForOtherPlacesInHtml, markdownPluginData, isForAmp),
 		}
 	}
 	
@@ -102,7 +105,15 @@ impl MarkdownInlinePlugin
 			if image.isAnimated()
 			{
 				nodesForOtherPlacesInHtml.ampScript("amp-anim", "https://cdn.ampproject.org/v0/amp-anim-0.1.js");
-				image.ampAnimNode(displayAmpLoadingIndicator)?
+				match image.ampMutedVideoNode(displayAmpLoadingIndicator)?
+				{
+					None => image.ampAnimNode(displayAmpLoadingIndicator)?,
+					Some(ampVideoNode) =>
+					{
+						nodesForOtherPlacesInHtml.ampScript("amp-video", "https://cdn.ampproject.org/v0/amp-video-0.1.js");
+						ampVideoNode
+					}
+				}
 			}
 			else
 			{
@@ -116,7 +127,7 @@ impl MarkdownInlinePlugin
 		}
 		else
 		{
-			image.imgNode()?
+			image.pictureNode()?
 		};
 		
 		let figureNode = match captionPosition
@@ -236,4 +247,63 @@ impl MarkdownInlinePlugin
 		
 		MarkdownPluginResult::fromBytes(bytes)
 	}
+	
+	fn svg_symbol(arguments: &mut ParsedQueryString, _nodesForOtherPlacesInHtml: &mut NodesForOtherPlacesInHtml, markdownPluginData: &MarkdownPluginData, isForAmp: bool) -> Result<MarkdownPluginResult, CordialError>
+	{
+		let mut spriteResourceUrl = None;
+		let mut symbolId = None;
+		let mut class = None;
+		let mut title = None;
+		for (name, value) in arguments
+		{
+			match name.deref()
+			{
+				"url" =>
+				{
+					spriteResourceUrl = Some(ResourceUrl(Rc::new(value.to_string())))
+				}
+				
+				"id" =>
+				{
+					symbolId = Some(value.to_string())
+				}
+				
+				"class" =>
+				{
+					class = Some(value.to_string())
+				}
+				
+				"title" =>
+				{
+					title = Some(value.to_string())
+				}
+				
+				_ => return Err(CordialError::Configuration(format!("svg_symbol inline plugin does not take the argument '{}'", name))),
+			}
+		}
+		
+		let spriteResourceUrl = match spriteResourceUrl
+		{
+			None => return Err(CordialError::Configuration("svg_symbol inline plugin resource can not be omitted".to_owned())),
+			Some(spriteResourceUrl) => spriteResourceUrl,
+		};
+		
+		let symbolId = match symbolId
+		{
+			None => return Err(CordialError::Configuration("svg_symbol inline plugin id can not be omitted".to_owned())),
+			Some(symbolId) => symbolId,
+		};
+		
+		// AMP forbids `<use>` of another document.
+		if isForAmp
+		{
+			MarkdownPluginResult::fromHtmlFragment(markdownPluginData.svgSymbolInlineHtml(spriteResourceUrl, &symbolId, class.as_ref().map(String::as_str), title.as_ref().map(String::as_str))?)
+		}
+		else
+		{
+			let svgSymbolNode = markdownPluginData.svgSymbolNode(spriteResourceUrl, &symbolId, class.as_ref().map(String::as_str), title.as_ref().map(String::as_str))?;
+			
+			MarkdownPluginResult::ok(vec![svgSymbolNode])
+		}
+	}
 }
@@ -79,4 +79,24 @@ impl<'a> MarkdownPluginData<'a>
 		let videoPipeline = videoResource.videoPipeline()?;
 		videoPipeline.videoNode(isForAmp, self.resources, self.configuration, self.languageData)
 	}
+	
+	#[inline(always)]
+	pub(crate) fn svgSymbolNode(&'a self, spriteResourceUrl: ResourceUrl, symbolId: &str, class: Option<&str>, title: Option<&str>) -> Result<UnattachedNode, CordialError>
+	{
+		let spriteUrlData = ResourceReference
+		{
+			resource: spriteResourceUrl,
+			tag: ResourceTag::default,
+		}.urlDataMandatory(self.resources, self.fallbackIso639Dash1Alpha2Language(), Some(self.iso639Dash1Alpha2Language()))?;
+		
+		Ok(SvgSpritePipeline::useNode(spriteUrlData.url_str(), symbolId, class, title))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn svgSymbolInlineHtml(&'a self, spriteResourceUrl: ResourceUrl, symbolId: &str, class: Option<&str>, title: Option<&str>) -> Result<String, CordialError>
+	{
+		let spriteResource = spriteResourceUrl.resourceMandatory(self.resources)?;
+		let svgSpritePipeline = spriteResource.svgSpritePipeline()?;
+		svgSpritePipeline.inlineSymbolHtml(self.resources, &spriteResourceUrl, symbolId, class, title, self.configuration, self.iso639Dash1Alpha2Language())
+	}
 }
@@ -3,6 +3,11 @@
 
 
 use super::*;
+use super::svg::Barcode;
+use super::svg::Meme;
+use super::svg::MonArtist;
+use super::svg::PlotSettings;
+use super::svg::QrCodeData;
 use ::comrak::ComrakOptions;
 use ::comrak::format_html;
 use ::comrak::parse_document;
@@ -11,6 +16,7 @@ use ::comrak::nodes::NodeValue::*;
 use ::csv::Reader;
 use ::either::Either;
 use ::either::Either::*;
+use ::serde::de::DeserializeOwned;
 use ::std::ops::Deref;
 use ::std::iter::Iterator;
 use ::std::str::from_utf8;
@@ -5,6 +5,8 @@
 use super::*;
 use super::hjson::*;
 use super::serde_with::*;
+use super::webm::*;
+use super::webp::*;
 use super::woff::*;
 use super::xmlExtra::*;
 use super::webserver::RsaManyServersResolvesServerCert;
@@ -9,6 +9,7 @@ pub(crate) struct AudioPipeline
 	#[serde(default = "max_age_in_seconds_long_default")] max_age_in_seconds: u32,
 	#[serde(default = "is_versioned_true_default")] is_versioned: bool,
 	#[serde(default)] input_format: Option<AudioInputFormat>,
+	#[serde(default)] alternative_formats: Vec<AudioInputFormat>,
 	
 	#[serde(default)] pub(crate) metadata: Rc<AudioVideoMetaData>,
 	#[serde(default = "AudioPipeline::width_default")] pub(crate) width: u16,
@@ -17,6 +18,7 @@ pub(crate) struct AudioPipeline
 	
 	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) durationInSeconds: Cell<u64>,
 	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) mp4Url: RefCell<Option<Url>>,
+	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) sources: RefCell<Vec<(AudioInputFormat, Url)>>,
 }
 
 impl Default for AudioPipeline
@@ -29,6 +31,7 @@ impl Default for AudioPipeline
 			max_age_in_seconds: max_age_in_seconds_long_default(),
 			is_versioned: is_versioned_true_default(),
 			input_format: None,
+			alternative_formats: Default::default(),
 			
 			metadata: Default::default(),
 			width: Self::width_default(),
@@ -37,6 +40,7 @@ impl Default for AudioPipeline
 			
 			durationInSeconds: Default::default(),
 			mp4Url: Default::default(),
+			sources: Default::default(),
 		}
 	}
 }
@@ -70,16 +74,69 @@ impl Pipeline for AudioPipeline
 		
 		let durationInSeconds = if isPrimaryLanguage
 		{
-			let mp4Url = self.mp4Url(resourceUrl, configuration)?;
-			*self.mp4Url.borrow_mut() = Some(mp4Url);
+			let primaryLanguageData = configuration.primaryLanguageData()?;
 			
-			let mp4Body = inputContentFilePath.fileContentsAsBytes().context(inputContentFilePath)?;
+			let inputFormat = AudioInputFormat::fromInputContentFilePath(self.input_format, inputContentFilePath);
+			let mostCompatibleFormat = self.mostCompatibleFormat(inputFormat);
 			
-			let durationInSeconds = onlyAudioTrackDuration(&mp4Body)?;
+			let mut durationInSeconds = None;
+			let mut sources = Vec::with_capacity(1 + self.alternative_formats.len());
+			for audioInputFormat in self.formats(inputFormat)
+			{
+				let audioInputContentFilePath = if audioInputFormat == inputFormat
+				{
+					inputContentFilePath.to_path_buf()
+				}
+				else
+				{
+					audioInputFormat.companionInputContentFilePath(inputContentFilePath)
+				};
+				
+				let body = audioInputContentFilePath.fileContentsAsBytes().context(&audioInputContentFilePath)?;
+				let audioDetails = audioInputFormat.audioDetails(&body, &audioInputContentFilePath)?;
+				
+				match durationInSeconds
+				{
+					None =>
+					{
+						durationInSeconds = Some(audioDetails.durationInSeconds);
+						*self.metadata.embeddedAudioTags.borrow_mut() = audioDetails.tags.clone();
+					}
+					Some(expectedDurationInSeconds) =>
+					{
+						let difference = if audioDetails.durationInSeconds > expectedDurationInSeconds
+						{
+							audioDetails.durationInSeconds - expectedDurationInSeconds
+						}
+						else
+						{
+							expectedDurationInSeconds - audioDetails.durationInSeconds
+						};
+						
+						if difference > 1
+						{
+							return Err(CordialError::Configuration(format!("Audio file {:?} has a duration of {} seconds but other formats have a duration of {} seconds", audioInputContentFilePath, audioDetails.durationInSeconds, expectedDurationInSeconds)));
+						}
+					}
+				}
+				
+				let url = audioInputFormat.url(resourceUrl, &primaryLanguageData)?;
+				if audioInputFormat == AudioInputFormat::MP4
+				{
+					*self.mp4Url.borrow_mut() = Some(url.clone());
+				}
+				
+				self.metadata.createAudio(audioInputFormat, &audioDetails, audioInputFormat == mostCompatibleFormat, url.clone(), headerGenerator, body, &mut result, self.max_age_in_seconds)?;
+				sources.push((audioInputFormat, url));
+			}
+			
+			sources.sort_by_key(|&(audioInputFormat, _)| AudioInputFormat::SourceOrder.iter().position(|sourceFormat| *sourceFormat == audioInputFormat).unwrap());
+			*self.sources.borrow_mut() = sources;
+			
+			let durationInSeconds = durationInSeconds.unwrap();
 			self.durationInSeconds.set(durationInSeconds);
 			
 			self.metadata.createWebVttTracks(inputContentFilePath, resourceUrl, configuration, headerGenerator, &mut result, self.max_age_in_seconds)?;
-			self.metadata.createAudioMp4(durationInSeconds, self.mp4Url.borrow().as_ref().unwrap().clone(), headerGenerator, mp4Body, &mut result, self.max_age_in_seconds)?;
 			
 			durationInSeconds
 		}
@@ -88,11 +145,7 @@ impl Pipeline for AudioPipeline
 			self.durationInSeconds.get()
 		};
 		
-		let audioNode =
-		{
-			let mp4UrlBorrow = self.mp4Url.borrow();
-			self.metadata.createAudioNode(configuration, languageData, mp4UrlBorrow.as_ref().unwrap(), durationInSeconds, self.volume)?
-		};
+		let audioNode = self.metadata.createAudioNode(configuration, languageData, &self.sources.borrow(), durationInSeconds, self.volume)?;
 		
 		AudioVideoMetaData::createIFramePlayer(resourceUrl, audioNode, self.width, languageData, headerGenerator, &mut result, self.max_age_in_seconds)?;
 		
@@ -110,7 +163,7 @@ impl AudioPipeline
 			SiteMapWebPageAudioVideo
 			{
 				durationInSeconds: Some(self.durationInSeconds.get()),
-				mediaUrl: self.mp4Url(resourceUrl, configuration)?,
+				mediaUrl: self.mostCompatibleUrl(resourceUrl, configuration)?,
 				iFrameUrl: self.iFramePlayerUrl(resourceUrl, languageData)?,
 				
 				audioVideoMetaData: self.metadata.clone(),
@@ -131,9 +184,41 @@ impl AudioPipeline
 	}
 	
 	#[inline(always)]
-	fn mp4Url(&self, resourceUrl: &ResourceUrl, configuration: &Configuration) -> Result<Url, CordialError>
+	fn mostCompatibleUrl(&self, resourceUrl: &ResourceUrl, configuration: &Configuration) -> Result<Url, CordialError>
+	{
+		let mostCompatibleFormat = match self.sources.borrow().iter().map(|&(audioInputFormat, _)| audioInputFormat).min_by_key(|audioInputFormat| Self::compatibility(*audioInputFormat))
+		{
+			None => self.mostCompatibleFormat(self.input_format.unwrap_or_default()),
+			Some(mostCompatibleFormat) => mostCompatibleFormat,
+		};
+		mostCompatibleFormat.url(resourceUrl, &configuration.primaryLanguageData()?)
+	}
+	
+	#[inline(always)]
+	fn formats(&self, inputFormat: AudioInputFormat) -> Vec<AudioInputFormat>
+	{
+		let mut formats = Vec::with_capacity(1 + self.alternative_formats.len());
+		formats.push(inputFormat);
+		for alternativeFormat in self.alternative_formats.iter()
+		{
+			if !formats.contains(alternativeFormat)
+			{
+				formats.push(*alternativeFormat);
+			}
+		}
+		formats
+	}
+	
+	#[inline(always)]
+	fn mostCompatibleFormat(&self, inputFormat: AudioInputFormat) -> AudioInputFormat
+	{
+		self.formats(inputFormat).into_iter().min_by_key(|audioInputFormat| Self::compatibility(*audioInputFormat)).unwrap()
+	}
+	
+	#[inline(always)]
+	fn compatibility(audioInputFormat: AudioInputFormat) -> usize
 	{
-		resourceUrl.replaceFileNameExtension(".mp4").url(&configuration.primaryLanguageData()?)
+		AudioInputFormat::CompatibilityOrder.iter().position(|compatibleFormat| *compatibleFormat == audioInputFormat).unwrap()
 	}
 	
 	#[inline(always)]
@@ -145,17 +230,17 @@ impl AudioPipeline
 	#[inline(always)]
 	pub(crate) fn audioNode(&self, isForAmp: bool, resources: &Resources, configuration: &Configuration, languageData: &LanguageData) -> Result<UnattachedNode, CordialError>
 	{
-		let mp4UrlBorrow = self.mp4Url.borrow();
+		let sources = self.sources.borrow();
 		
 		let durationInSeconds = self.durationInSeconds.get();
 		
 		if isForAmp
 		{
-			self.metadata.createAmpAudioNode(resources, configuration, languageData, &mp4UrlBorrow.as_ref().unwrap(), durationInSeconds, self.volume, self.width, self.height)
+			self.metadata.createAmpAudioNode(resources, configuration, languageData, &sources, durationInSeconds, self.volume, self.width, self.height)
 		}
 		else
 		{
-			self.metadata.createAudioNode(configuration, languageData, &mp4UrlBorrow.as_ref().unwrap(), durationInSeconds, self.volume)
+			self.metadata.createAudioNode(configuration, languageData, &sources, durationInSeconds, self.volume)
 		}
 	}
 	
@@ -17,6 +17,9 @@ pub(crate) struct CssPipeline
 	#[serde(default = "CssPipeline::maximum_release_age_from_can_i_use_database_last_updated_in_weeks_default")] maximum_release_age_from_can_i_use_database_last_updated_in_weeks: u16,
 	#[serde(default = "CssPipeline::minimum_usage_threshold_default", skip_serializing)] minimum_usage_threshold: UsagePercentage,
 	#[serde(default = "CssPipeline::regional_usages_default", skip_serializing)] regional_usages: Vec<RegionalUsages>,
+	
+	/// The critical fonts' URLs this stylesheet uses by language, found when it is rendered and used by HTML pages linking to it later.
+	#[serde(default, skip_deserializing, skip_serializing)] criticalFontUrls: RefCell<HashMap<Iso639Dash1Alpha2Language, Vec<Rc<Url>>>>,
 }
 
 impl Default for CssPipeline
@@ -36,6 +39,7 @@ impl Default for CssPipeline
 			maximum_release_age_from_can_i_use_database_last_updated_in_weeks: Self::maximum_release_age_from_can_i_use_database_last_updated_in_weeks_default(),
 			minimum_usage_threshold: Self::minimum_usage_threshold_default(),
 			regional_usages: Self::regional_usages_default(),
+			criticalFontUrls: Default::default(),
 		}
 	}
 }
@@ -61,7 +65,7 @@ impl Pipeline for CssPipeline
 	}
 
 	#[inline(always)]
-	fn execute(&self, _resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	fn execute(&self, resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
 	{
 		let url = resourceUrl.replaceFileNameExtension(".css").url(languageData)?;
 		
@@ -78,6 +82,8 @@ impl Pipeline for CssPipeline
 		};
 		
 		let body = CssInputFormat::toCss(self.input_format, inputContentFilePath, self.precision, &handlebarsTemplate, self.maximum_release_age_from_can_i_use_database_last_updated_in_weeks, self.minimum_usage_threshold, &self.regional_usages[..])?;
+		
+		self.findCriticalFontUrls(&body, resources, configuration, languageData.iso639Dash1Alpha2Language);
 
 		Ok(vec![(url, hashmap! { default => Rc::new(UrlDataDetails::generic(&body)) }, StatusCode::Ok, content_type_text_css_utf8(), headers, ResponseBody::utf8(body), None, CanBeCompressed)])
 	}
@@ -85,6 +91,64 @@ impl Pipeline for CssPipeline
 
 impl CssPipeline
 {
+	/// Versioned WOFF2 URLs of critical fonts used by this stylesheet, so HTML pages linking to it can preload them.
+	#[inline(always)]
+	pub(crate) fn criticalFontUrls(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Vec<Rc<Url>>
+	{
+		let criticalFontUrls = self.criticalFontUrls.borrow();
+		match criticalFontUrls.get(&iso639Dash1Alpha2Language).or_else(|| criticalFontUrls.get(&fallbackIso639Dash1Alpha2Language))
+		{
+			None => Vec::new(),
+			Some(fontUrls) => fontUrls.clone(),
+		}
+	}
+	
+	#[inline(always)]
+	fn findCriticalFontUrls(&self, css: &[u8], resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language)
+	{
+		let css = String::from_utf8_lossy(css);
+		let cssUrls = Self::urls(&css);
+		
+		let mut criticalFontUrls = Vec::new();
+		for resource in resources.values()
+		{
+			// This stylesheet is already mutably borrowed.
+			if let Ok(resource) = resource.try_borrow()
+			{
+				for fontUrl in resource.criticalFontUrls(configuration.fallbackIso639Dash1Alpha2Language(), iso639Dash1Alpha2Language)
+				{
+					// Only matches the versioned URLs a font writes into its `@font-face` SCSS partial.
+					if cssUrls.contains(fontUrl.as_str())
+					{
+						criticalFontUrls.push(fontUrl);
+					}
+				}
+			}
+		}
+		
+		self.criticalFontUrls.borrow_mut().insert(iso639Dash1Alpha2Language, criticalFontUrls);
+	}
+	
+	/// The values of `url()` functions, with any quotes removed.
+	#[inline(always)]
+	fn urls(css: &str) -> HashSet<&str>
+	{
+		let mut urls = HashSet::new();
+		let mut remaining = css;
+		while let Some(start) = remaining.find("url(")
+		{
+			remaining = &remaining[start + 4 ..];
+			let end = match remaining.find(')')
+			{
+				None => break,
+				Some(end) => end,
+			};
+			urls.insert(remaining[.. end].trim().trim_matches(&['"', '\''][..]));
+			remaining = &remaining[end + 1 ..];
+		}
+		urls
+	}
+	
 	#[inline(always)]
 	fn precision_default() -> u8
 	{
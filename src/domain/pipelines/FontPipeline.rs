@@ -17,6 +17,18 @@ pub(crate) struct FontPipeline
 	#[serde(default = "FontPipeline::woff2_brotli_quality_default")] woff2_brotli_quality: u8,
 	#[serde(default)] woff2_disallow_transforms: bool,
 	#[serde(default)] include_ttf: Option<TtfMimeType>,
+	#[serde(default)] subsetting: Option<FontSubsetting>,
+	#[serde(default)] unicode_ranges: Vec<FontUnicodeRange>,
+	#[serde(default, deserialize_with = "FontPipeline::static_instances_deserialize_with")] static_instances: Vec<FontStaticInstance>,
+	#[serde(default)] font_face: Option<FontFace>,
+	#[serde(default)] critical: bool,
+	#[serde(default)] critical_unicode_ranges: BTreeSet<String>,
+	
+	/// The versioned WOFF2 URLs of critical fonts written into this font's `@font-face` SCSS partial by language, so that stylesheets importing it can find them.
+	#[serde(default, skip_deserializing, skip_serializing)] criticalFontUrls: RefCell<HashMap<Iso639Dash1Alpha2Language, Vec<Rc<Url>>>>,
+	
+	/// The characters this font was subset to by language, so that it can be found to lack characters used by the rendered HTML pages.
+	#[serde(default, skip_deserializing, skip_serializing)] subsetCharacters: RefCell<HashMap<Iso639Dash1Alpha2Language, BTreeSet<char>>>,
 }
 
 impl Default for FontPipeline
@@ -38,6 +50,14 @@ impl Default for FontPipeline
 			woff2_brotli_quality: Self::woff2_brotli_quality_default(),
 			woff2_disallow_transforms: false,
 			include_ttf: None,
+			subsetting: None,
+			unicode_ranges: Default::default(),
+			static_instances: Default::default(),
+			font_face: None,
+			critical: false,
+			critical_unicode_ranges: Default::default(),
+			criticalFontUrls: Default::default(),
+			subsetCharacters: Default::default(),
 		}
 	}
 }
@@ -59,18 +79,277 @@ impl Pipeline for FontPipeline
 	#[inline(always)]
 	fn is<'a>(&self) -> (bool, bool)
 	{
-		(self.is_versioned, self.language_aware)
+		// Subsets are per language.
+		(self.is_versioned, self.language_aware || self.subsetting.is_some())
 	}
 	
 	#[inline(always)]
-	fn execute(&self, _resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, _configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	fn execute(&self, resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
 	{
-		FontInputFormat::toWebFonts(self.input_format, resourceUrl, inputContentFilePath, headerGenerator, languageData, self.max_age_in_seconds,self. is_downloadable, &self.utf8_xml_metadata[..], &self.woff1_private_data[..], self.woff1_iterations, self.woff2_brotli_quality, self.woff2_disallow_transforms, self.include_ttf)
+		let fontBytes = self.sfntBytes(inputContentFilePath)?;
+		
+		let usedCharacters = match self.subsetting
+		{
+			None => None,
+			Some(ref subsetting) =>
+			{
+				let usedCharacters = subsetting.characters(resources, configuration, languageData.iso639Dash1Alpha2Language)?;
+				self.subsetCharacters.try_borrow_mut()?.insert(languageData.iso639Dash1Alpha2Language, usedCharacters.clone());
+				Some(usedCharacters)
+			}
+		};
+		
+		let mut responses = Vec::new();
+		
+		// Variable font axes are preserved.
+		let mut fontFaceRules = Vec::new();
+		self.toWebFontsForUnicodeRanges(&fontBytes, None, &usedCharacters, inputContentFilePath, resourceUrl, headerGenerator, languageData, &mut responses, &mut fontFaceRules)?;
+		
+		// Static instances are not preloaded as only browsers without variable font support use them.
+		let criticalFontUrls = if self.critical
+		{
+			fontFaceRules.iter().filter(|&&(_, _, ref unicodeRangeName, _)| match *unicodeRangeName
+			{
+				None => true,
+				Some(unicodeRangeName) => self.critical_unicode_ranges.contains(unicodeRangeName),
+			}).flat_map(|&(ref sources, _, _, _)| sources.iter().filter(|&&(_, format)| format == "woff2").map(|&(ref url, _)| Rc::new(url.clone()))).collect()
+		}
+		else
+		{
+			Vec::new()
+		};
+		
+		let mut staticInstanceFontFaceRules = Vec::new();
+		for (index, staticInstance) in self.static_instances.iter().enumerate()
+		{
+			let staticInstanceFontBytes = staticInstance.instance(&fontBytes, inputContentFilePath)?;
+			self.toWebFontsForUnicodeRanges(&staticInstanceFontBytes, Some((index, staticInstance)), &usedCharacters, inputContentFilePath, resourceUrl, headerGenerator, languageData, &mut responses, &mut staticInstanceFontFaceRules)?;
+		}
+		
+		if self.font_face.is_some() || !self.unicode_ranges.is_empty() || !self.static_instances.is_empty()
+		{
+			let fontFace = self.font_face.clone().unwrap_or_default();
+			
+			let mut css = String::new();
+			if staticInstanceFontFaceRules.is_empty()
+			{
+				for (sources, staticInstance, _, unicodeRange) in fontFaceRules
+				{
+					fontFace.writeRule(&mut css, resourceUrl, &sources, staticInstance, unicodeRange)?;
+				}
+			}
+			else
+			{
+				// Browsers without `@supports` (or variable fonts) still get the static instances; those with variable fonts get later rules that override them.
+				for (sources, staticInstance, _, unicodeRange) in staticInstanceFontFaceRules
+				{
+					fontFace.writeRule(&mut css, resourceUrl, &sources, staticInstance, unicodeRange)?;
+				}
+				
+				let mut nestedCss = String::new();
+				for (sources, staticInstance, _, unicodeRange) in fontFaceRules
+				{
+					fontFace.writeRule(&mut nestedCss, resourceUrl, &sources, staticInstance, unicodeRange)?;
+				}
+				
+				css.push_str("@supports (font-variation-settings: normal)\n{\n");
+				for line in nestedCss.lines()
+				{
+					css.push('\t');
+					css.push_str(line);
+					css.push('\n');
+				}
+				css.push_str("}\n");
+			}
+			
+			self.writeScssPartial(&css, resourceUrl, languageData, configuration)?;
+			self.criticalFontUrls.try_borrow_mut()?.insert(languageData.iso639Dash1Alpha2Language, criticalFontUrls);
+			
+			const CanBeCompressed: bool = true;
+			let cssUrl = resourceUrl.replaceFileNameExtension(".css").url(languageData)?;
+			let headers = headerGenerator.generateHeadersForAsset(CanBeCompressed, self.max_age_in_seconds, self.is_downloadable, &cssUrl)?;
+			let body = css.into_bytes();
+			
+			let urlDataDetails = Rc::new(UrlDataDetails::generic(&body));
+			
+			// When split, there is no one font file to be the default.
+			let tags = if self.unicode_ranges.is_empty()
+			{
+				hashmap! { font_face_css => urlDataDetails }
+			}
+			else
+			{
+				hashmap! { default => urlDataDetails.clone(), font_face_css => urlDataDetails }
+			};
+			responses.push((cssUrl, tags, StatusCode::Ok, content_type_text_css_utf8(), headers, ResponseBody::utf8(body), None, CanBeCompressed));
+		}
+		
+		Ok(responses)
 	}
 }
 
 impl FontPipeline
 {
+	/// Versioned WOFF2 URLs to preload for a critical font; only those in its `@font-face` SCSS partial, and, if split into unicode ranges, only the chunks named in `critical_unicode_ranges`.
+	#[inline(always)]
+	pub(crate) fn criticalFontUrls(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Vec<Rc<Url>>
+	{
+		let criticalFontUrls = self.criticalFontUrls.borrow();
+		match criticalFontUrls.get(&iso639Dash1Alpha2Language).or_else(|| criticalFontUrls.get(&fallbackIso639Dash1Alpha2Language))
+		{
+			None => Vec::new(),
+			Some(fontUrls) => fontUrls.clone(),
+		}
+	}
+	
+	/// Once HTML pages have been rendered, the characters they use are known; if any are missing from a subset, the font needs to be rendered again.
+	#[inline(always)]
+	pub(crate) fn subsetLacksRenderedCharacters(&self, resources: &Resources, configuration: &Configuration) -> Result<bool, CordialError>
+	{
+		let subsetting = match self.subsetting
+		{
+			None => return Ok(false),
+			Some(ref subsetting) => subsetting,
+		};
+		
+		for (iso639Dash1Alpha2Language, subsetCharacters) in self.subsetCharacters.try_borrow()?.iter()
+		{
+			if !subsetting.characters(resources, configuration, *iso639Dash1Alpha2Language)?.is_subset(subsetCharacters)
+			{
+				return Ok(true);
+			}
+		}
+		Ok(false)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn sfntBytes(&self, inputContentFilePath: &Path) -> Result<Vec<u8>, CordialError>
+	{
+		FontInputFormat::sfntBytes(self.input_format, inputContentFilePath)
+	}
+	
+	/// `critical_unicode_ranges` refers to `unicode_ranges`, so can not be validated when it is deserialized; this is called once a resource has been deserialized instead.
+	#[inline(always)]
+	pub(crate) fn validateCriticalUnicodeRanges(&self) -> Result<(), CordialError>
+	{
+		for criticalUnicodeRange in self.critical_unicode_ranges.iter()
+		{
+			if !self.unicode_ranges.iter().any(|unicodeRange| unicodeRange.name() == criticalUnicodeRange)
+			{
+				return Err(CordialError::Configuration(format!("Font critical unicode range '{}' is not one of the font's unicode_ranges", criticalUnicodeRange)));
+			}
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn toWebFontsForUnicodeRanges<'a>(&'a self, fontBytes: &[u8], staticInstance: Option<(usize, &'a FontStaticInstance)>, usedCharacters: &Option<BTreeSet<char>>, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, responses: &mut Vec<PipelineResponse>, fontFaceRules: &mut Vec<(Vec<(Url, &'static str)>, Option<&'a FontStaticInstance>, Option<&'a str>, Option<String>)>) -> Result<(), CordialError>
+	{
+		let staticInstanceName = match staticInstance
+		{
+			None => None,
+			Some((index, staticInstance)) => Some((index, staticInstance.name()?)),
+		};
+		let staticInstanceName = staticInstanceName.as_ref().map(|&(index, ref name)| (index, name.as_str()));
+		let staticInstance = staticInstance.map(|(_, staticInstance)| staticInstance);
+		
+		if self.unicode_ranges.is_empty()
+		{
+			let fontBytes = match *usedCharacters
+			{
+				None => fontBytes.to_vec(),
+				Some(ref usedCharacters) => FontSubsetting::subsetToCharacters(fontBytes, usedCharacters, inputContentFilePath)?,
+			};
+			
+			let webFonts = self.toWebFonts(fontBytes, staticInstanceName, None, inputContentFilePath, resourceUrl, headerGenerator, languageData)?;
+			fontFaceRules.push((self.fontFaceSources(&webFonts), staticInstance, None, None));
+			responses.extend(webFonts);
+		}
+		else
+		{
+			for (index, unicodeRange) in self.unicode_ranges.iter().enumerate()
+			{
+				let mut characters = unicodeRange.characters()?;
+				if let Some(ref usedCharacters) = *usedCharacters
+				{
+					characters = characters.intersection(usedCharacters).cloned().collect();
+				}
+				
+				// No page uses this chunk, so there is no need for it.
+				if characters.is_empty()
+				{
+					continue;
+				}
+				
+				let chunkFontBytes = FontSubsetting::subsetToCharacters(fontBytes, &characters, inputContentFilePath)?;
+				let webFonts = self.toWebFonts(chunkFontBytes, staticInstanceName, Some((index, unicodeRange.name())), inputContentFilePath, resourceUrl, headerGenerator, languageData)?;
+				fontFaceRules.push((self.fontFaceSources(&webFonts), staticInstance, Some(unicodeRange.name()), Some(unicodeRange.css()?)));
+				responses.extend(webFonts);
+			}
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn toWebFonts(&self, fontBytes: Vec<u8>, staticInstance: Option<(usize, &str)>, unicodeRange: Option<(usize, &str)>, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		FontInputFormat::toWebFonts(fontBytes, staticInstance, unicodeRange, resourceUrl, inputContentFilePath, headerGenerator, languageData, self.max_age_in_seconds, self.is_downloadable, &self.utf8_xml_metadata[..], &self.woff1_private_data[..], self.woff1_iterations, self.woff2_brotli_quality, self.woff2_disallow_transforms, self.include_ttf)
+	}
+	
+	/// WOFF2 first, as browsers use the first format they support.
+	///
+	/// The stylesheet is generated before `Resource` versions the web fonts' URLs, so they are versioned here with the same entity tag.
+	#[inline(always)]
+	fn fontFaceSources(&self, webFonts: &[PipelineResponse]) -> Vec<(Url, &'static str)>
+	{
+		let mut sources = Vec::with_capacity(2);
+		for &(ref contentType, format) in [(content_type_font_woff2(), "woff2"), (content_type_font_woff(), "woff")].iter()
+		{
+			for &(ref url, _, _, ref webFontContentType, ref headers, ref body, _, _) in webFonts.iter()
+			{
+				if webFontContentType == contentType
+				{
+					let mut url = url.clone();
+					if self.is_versioned
+					{
+						Resource::versionUrl(&mut url, &StaticResponse::entityTagOf(webFontContentType, headers, body));
+					}
+					sources.push((url, format));
+				}
+			}
+		}
+		sources
+	}
+	
+	#[inline(always)]
+	fn writeScssPartial(&self, css: &str, resourceUrl: &ResourceUrl, languageData: &LanguageData, configuration: &Configuration) -> Result<(), CordialError>
+	{
+		let relativePath = Path::new(resourceUrl.withoutFileNameExtension().trim_left_matches('/'));
+		let fileStem = match relativePath.file_name()
+		{
+			None => return Err(CordialError::Configuration(format!("Font resource URL '{}' does not have a file name", resourceUrl))),
+			Some(fileStem) => fileStem.to_string_lossy(),
+		};
+		let partialFileName = if self.is().1
+		{
+			format!("_{}-{}.scss", fileStem, languageData.iso639Dash1Alpha2Language.to_iso_639_1_alpha_2_language_code())
+		}
+		else
+		{
+			format!("_{}.scss", fileStem)
+		};
+		
+		let partialFilePath = match relativePath.parent()
+		{
+			None => configuration.generatedSassImportsFolderPath.join(partialFileName),
+			Some(parent) => configuration.generatedSassImportsFolderPath.join(parent).join(partialFileName),
+		};
+		partialFilePath.createParentFolderForFilePath().context(&partialFilePath)?;
+		partialFilePath.createFileWithStringContents(css).context(&partialFilePath)?;
+		Ok(())
+	}
+	
 	#[inline(always)]
 	fn woff1_iterations_default() -> u16
 	{
@@ -82,4 +361,23 @@ impl FontPipeline
 	{
 		11
 	}
+	
+	/// Static instances' names are used in file names, so must be unique.
+	#[inline(always)]
+	fn static_instances_deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<FontStaticInstance>, D::Error>
+	{
+		let staticInstances = Vec::<FontStaticInstance>::deserialize(deserializer)?;
+		
+		let mut names = HashSet::with_capacity(staticInstances.len());
+		for staticInstance in staticInstances.iter()
+		{
+			let name = staticInstance.name().map_err(D::Error::custom)?;
+			if !names.insert(name.clone())
+			{
+				return Err(D::Error::custom(format!("Font static instance name '{}' is used more than once", name)));
+			}
+		}
+		
+		Ok(staticInstances)
+	}
 }
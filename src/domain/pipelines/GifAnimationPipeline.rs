@@ -17,6 +17,13 @@ pub(crate) struct GifAnimationPipeline
 
 	#[serde(default)] quantizer: EngiffenQuantizer,
 	#[serde(default)] loops: EngiffenLoops,
+	#[serde(default)] animated_webp: bool,
+	#[serde(default = "GifAnimationPipeline::animated_webp_quality_default")] animated_webp_quality: u8,
+	#[serde(default)] animated_webp_lossless: bool,
+	
+	/// A `video` resource of the same animation; if present, HTML uses an autoplaying, muted `<video>` (or `<amp-video>`) in preference to the GIF or WebP.
+	/// A `<video>` can only play once or loop forever, so it is not used if `loops` is a finite number of repetitions other than zero.
+	#[serde(default)] muted_video: Option<ResourceUrl>,
 
 	#[serde(default = "ProcessedImageSourceSet::processedImageSourceSet_default", skip_deserializing, skip_serializing)] pub(crate) processedImageSourceSet: RefCell<ProcessedImageSourceSet>,
 }
@@ -37,6 +44,10 @@ impl Default for GifAnimationPipeline
 			source_set: Default::default(),
 			quantizer: Default::default(),
 			loops: Default::default(),
+			animated_webp: false,
+			animated_webp_quality: Self::animated_webp_quality_default(),
+			animated_webp_lossless: false,
+			muted_video: None,
 			processedImageSourceSet: ProcessedImageSourceSet::processedImageSourceSet_default(),
 		}
 	}
@@ -86,9 +97,9 @@ impl Pipeline for GifAnimationPipeline
 	}
 
 	#[inline(always)]
-	fn execute(&self, _resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, _configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	fn execute(&self, resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
 	{
-		let engiffen = Engiffen::new(inputContentFilePath, &self.source_set, &self.quantizer, self.loops, self.input_format, resourceUrl, languageData)?;
+		let engiffen = Engiffen::new(inputContentFilePath, &self.source_set, &self.quantizer, self.loops, self.animatedWebP(), self.input_format, resourceUrl, languageData, resources, configuration)?;
 
 		engiffen.processedImageSourceSet(self.processedImageSourceSet.try_borrow_mut()?.deref_mut())?;
 
@@ -96,3 +107,42 @@ impl Pipeline for GifAnimationPipeline
 		engiffen.process(|url| headerGenerator.generateHeadersForAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, url))
 	}
 }
+
+impl GifAnimationPipeline
+{
+	/// Returns the muted video resource and whether it should loop forever.
+	/// `None` if the animation repeats a finite number of times, as a video can not.
+	#[inline(always)]
+	pub(crate) fn mutedVideo(&self) -> Option<(&ResourceUrl, bool)>
+	{
+		match self.muted_video
+		{
+			None => None,
+			Some(ref mutedVideo) => match self.loops
+			{
+				EngiffenLoops::infinite => Some((mutedVideo, true)),
+				EngiffenLoops::finite { repetitions: 0 } => Some((mutedVideo, false)),
+				EngiffenLoops::finite { .. } => None,
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn animatedWebP(&self) -> Option<(u8, bool)>
+	{
+		if self.animated_webp
+		{
+			Some((self.animated_webp_quality, self.animated_webp_lossless))
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	#[inline(always)]
+	fn animated_webp_quality_default() -> u8
+	{
+		DefaultWebPQuality
+	}
+}
@@ -44,7 +44,17 @@ pub(crate) struct HtmlPipeline
 	#[serde(default)] favicon: Option<FavIcon>,
 	#[serde(default)] svg_favicon: Option<ResourceUrl>,
 	#[serde(default)] safari_styling: Option<SafariStyling>,
+	#[serde(default)] icon_set: Option<ResourceUrl>,
 	#[serde(default)] windows_tiles_browser_config: Option<ResourceUrl>,
+	// stylesheets linked to by the template; critical fonts they use are preloaded
+	#[serde(default)] stylesheets: Vec<ResourceUrl>,
+	#[serde(default)] preload_fonts_using_link_header: bool,
+	
+	/// Registered by a `social_card` resource for this page; used for `og:image` and `twitter:image` if there is no `article_image`.
+	#[serde(default, skip_deserializing, skip_serializing)] socialCard: RefCell<Option<ResourceUrl>>,
+	
+	/// The characters of this page's rendered HTML by language, recorded so that fonts can be subset to them if rendering happens a second time.
+	#[serde(default, skip_deserializing, skip_serializing)] renderedCharacters: RefCell<HashMap<Iso639Dash1Alpha2Language, BTreeSet<char>>>,
 }
 
 impl Default for HtmlPipeline
@@ -87,7 +97,12 @@ impl Default for HtmlPipeline
 			favicon: None,
 			svg_favicon: None,
 			safari_styling: None,
+			icon_set: None,
 			windows_tiles_browser_config: None,
+			stylesheets: Default::default(),
+			preload_fonts_using_link_header: false,
+			socialCard: Default::default(),
+			renderedCharacters: Default::default(),
 		}
 	}
 }
@@ -118,15 +133,7 @@ impl Pipeline for HtmlPipeline
 	#[inline(always)]
 	fn anchorTitleAttribute(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<Rc<String>>, CordialError>
 	{
-		match self.abstracts.get(&iso639Dash1Alpha2Language)
-		{
-			Some(htmlAbstract) => Ok(Some(htmlAbstract.description.clone())),
-			None => match self.abstracts.get(&fallbackIso639Dash1Alpha2Language)
-			{
-				Some(htmlAbstract) => Ok(Some(htmlAbstract.description.clone())),
-				None => Err(CordialError::Configuration("No HTML abstract".to_owned())),
-			}
-		}
+		Ok(Some(self.htmlAbstract(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?.description.clone()))
 	}
 	
 	#[inline(always)]
@@ -152,21 +159,8 @@ impl Pipeline for HtmlPipeline
 				languageData,
 			},
 			htmlAbstract: htmlAbstract.clone(),
-			articleImage: match self.article_image
-			{
-				None => None,
-				Some(ref resourceUrl) =>
-				{
-					let resourceRef = resourceUrl.resourceMandatory(resources)?;
-					Some
-					(
-						(
-							resourceUrl.clone(),
-							resourceRef.imageMetaData()?.clone(),
-						)
-					)
-				}
-			},
+			articleImage: Self::image(self.article_image.as_ref(), resources)?,
+			socialCardImage: Self::image(self.socialCard.try_borrow()?.as_ref(), resources)?,
 			siteMapImages: &self.site_map_images,
 			siteMapAudios: &self.site_map_audios,
 			siteMapVideos: &self.site_map_videos,
@@ -204,7 +198,10 @@ impl Pipeline for HtmlPipeline
 			favIcon: self.favicon.as_ref(),
 			svgFavIcon: self.svg_favicon.as_ref(),
 			safariStyling: self.safari_styling.as_ref(),
+			iconSet: self.icon_set.as_ref(),
 			windowsTilesBrowserConfig: self.windows_tiles_browser_config.as_ref(),
+			stylesheets: &self.stylesheets,
+			preloadFontsUsingLinkHeader: self.preload_fonts_using_link_header,
 		};
 		
 		htmlDocumentData.addToRssChannels(resources, rssChannelsToRssItems, &self.rss, &self.rss_channels, inputContentFilePath, handlebars)?;
@@ -212,7 +209,19 @@ impl Pipeline for HtmlPipeline
 		{
 			htmlDocumentData.addToSiteMaps(resources, siteMapWebPages, self.site_map_change_frequency, self.site_map_priority)?;
 		}
-		self.output_format.renderHtmlDocumentsAndRedirects(resources, &htmlDocumentData, headerGenerator, self.max_age_in_seconds, inputContentFilePath, handlebars)
+		let responses = self.output_format.renderHtmlDocumentsAndRedirects(resources, &htmlDocumentData, headerGenerator, self.max_age_in_seconds, inputContentFilePath, handlebars)?;
+		
+		let mut renderedCharacters = BTreeSet::new();
+		for &(_, _, _, ref contentType, _, ref body, _, _) in responses.iter()
+		{
+			if *contentType == content_type_text_html_utf8()
+			{
+				FontSubsetting::addCharactersInHtml(body.as_ref(), &mut renderedCharacters);
+			}
+		}
+		self.renderedCharacters.try_borrow_mut()?.insert(iso639Dash1Alpha2Language, renderedCharacters);
+		
+		Ok(responses)
 	}
 }
 
@@ -224,6 +233,78 @@ impl HtmlPipeline
 		self.open_graph.hasFacebookOpenGraphTypeDiscriminant(facebookOpenGraphTypeDiscriminant)
 	}
 	
+	#[inline(always)]
+	pub(crate) fn htmlAbstract(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<&Rc<HtmlAbstract>, CordialError>
+	{
+		match self.abstracts.get(&iso639Dash1Alpha2Language)
+		{
+			Some(htmlAbstract) => Ok(htmlAbstract),
+			None => match self.abstracts.get(&fallbackIso639Dash1Alpha2Language)
+			{
+				Some(htmlAbstract) => Ok(htmlAbstract),
+				None => Err(CordialError::Configuration("No HTML abstract".to_owned())),
+			}
+		}
+	}
+	
+	/// `None` until this page has been rendered in this language.
+	#[inline(always)]
+	pub(crate) fn renderedCharacters(&self, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<String>, CordialError>
+	{
+		Ok(self.renderedCharacters.try_borrow()?.get(&iso639Dash1Alpha2Language).map(|renderedCharacters| renderedCharacters.iter().cloned().collect()))
+	}
+	
+	/// A page has at most one social card; social cards are rendered before HTML pages.
+	#[inline(always)]
+	pub(crate) fn registerSocialCard(&self, socialCardResourceUrl: &ResourceUrl) -> Result<(), CordialError>
+	{
+		let mut socialCard = self.socialCard.try_borrow_mut()?;
+		if let Some(ref registeredSocialCardResourceUrl) = *socialCard
+		{
+			if registeredSocialCardResourceUrl != socialCardResourceUrl
+			{
+				return Err(CordialError::Configuration(format!("HTML page has more than one social card, '{}' and '{}'", registeredSocialCardResourceUrl, socialCardResourceUrl)));
+			}
+		}
+		*socialCard = Some(socialCardResourceUrl.clone());
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn image(resourceUrl: Option<&ResourceUrl>, resources: &Resources) -> Result<Option<(ResourceUrl, Rc<ImageMetaData>)>, CordialError>
+	{
+		match resourceUrl
+		{
+			None => Ok(None),
+			Some(resourceUrl) =>
+			{
+				let resourceRef = resourceUrl.resourceMandatory(resources)?;
+				Ok
+				(
+					Some
+					(
+						(
+							resourceUrl.clone(),
+							resourceRef.imageMetaData()?.clone(),
+						)
+					)
+				)
+			}
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn author(&self) -> Option<&ResourceUrl>
+	{
+		self.author.as_ref()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn publicationDate(&self) -> Option<DateTime<Utc>>
+	{
+		self.publication_date
+	}
+	
 	#[inline(always)]
 	fn modifications(&self, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<BTreeMap<DateTime<Utc>, Rc<String>>, CordialError>
 	{
@@ -0,0 +1,468 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Generates favicons (including a multi-resolution `favicon.ico`), Apple touch icons, web app manifest icons (including maskable ones) and Windows tiles from a single SVG or large PNG.
+/// Use as a HTML page's `icon_set`, a web app manifest's `icon_set` and a browser config's `tile_url`.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct IconSetPipeline
+{
+	#[serde(default = "max_age_in_seconds_long_default")] max_age_in_seconds: u32,
+	#[serde(default = "is_downloadable_false_default")] is_downloadable: bool,
+	#[serde(default = "is_versioned_true_default")] is_versioned: bool,
+	#[serde(default)] language_aware: bool,
+	#[serde(default)] input_format: Option<IconSetInputFormat>,
+	
+	#[serde(default)] metadata: Rc<ImageMetaData>,
+	
+	#[serde(default = "IconSetPipeline::favicon_ico_sizes_default")] favicon_ico_sizes: BTreeSet<u32>,
+	#[serde(default = "FavIcon::sizes_default")] favicon_sizes: BTreeSet<u32>,
+	#[serde(default = "IconSetPipeline::apple_touch_icon_sizes_default")] apple_touch_icon_sizes: BTreeSet<u32>,
+	#[serde(default = "IconSetPipeline::web_app_manifest_icon_sizes_default")] web_app_manifest_icon_sizes: BTreeSet<u32>,
+	#[serde(default = "IconSetPipeline::web_app_manifest_icon_sizes_default")] maskable_icon_sizes: BTreeSet<u32>,
+	#[serde(default = "IconSetPipeline::windows_tiles_default")] windows_tiles: bool,
+	
+	/// Fills the background of favicons and web app manifest icons; if omitted, they are transparent.
+	/// Windows tiles are always transparent, as they use the browser config's `tile_color`.
+	#[serde(default)] background_color: Option<HexadecimalColor>,
+	
+	/// iOS fills a transparent Apple touch icon with black, so they are always opaque; if omitted, `background_color` or white is used.
+	#[serde(default)] apple_touch_icon_background_color: Option<HexadecimalColor>,
+	
+	/// Maskable icons are always opaque; if omitted, `background_color` or white is used.
+	#[serde(default)] maskable_background_color: Option<HexadecimalColor>,
+	
+	/// Percentage of a maskable icon's width occupied by the image; the W3C safe zone is a circle of 80%.
+	#[serde(default = "IconSetPipeline::maskable_safe_zone_percentage_default")] maskable_safe_zone_percentage: u8,
+}
+
+impl Default for IconSetPipeline
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			max_age_in_seconds: max_age_in_seconds_long_default(),
+			is_downloadable: is_downloadable_false_default(),
+			is_versioned: is_versioned_true_default(),
+			language_aware: false,
+			input_format: None,
+			metadata: Default::default(),
+			favicon_ico_sizes: Self::favicon_ico_sizes_default(),
+			favicon_sizes: FavIcon::sizes_default(),
+			apple_touch_icon_sizes: Self::apple_touch_icon_sizes_default(),
+			web_app_manifest_icon_sizes: Self::web_app_manifest_icon_sizes_default(),
+			maskable_icon_sizes: Self::web_app_manifest_icon_sizes_default(),
+			windows_tiles: Self::windows_tiles_default(),
+			background_color: None,
+			apple_touch_icon_background_color: None,
+			maskable_background_color: None,
+			maskable_safe_zone_percentage: Self::maskable_safe_zone_percentage_default(),
+		}
+	}
+}
+
+impl Pipeline for IconSetPipeline
+{
+	#[inline(always)]
+	fn imageMetaData(&self) -> Result<&Rc<ImageMetaData>, CordialError>
+	{
+		Ok(&self.metadata)
+	}
+	
+	#[inline(always)]
+	fn processingPriority(&self) -> ProcessingPriority
+	{
+		NoDependenciesEgImage
+	}
+	
+	#[inline(always)]
+	fn resourceInputContentFileNamesWithExtension(&self, resourceInputName: &str) -> Vec<String>
+	{
+		self.input_format.resourceInputContentFileNamesWithExtension(resourceInputName)
+	}
+	
+	#[inline(always)]
+	fn is<'a>(&self) -> (bool, bool)
+	{
+		(self.is_versioned, self.language_aware)
+	}
+	
+	#[inline(always)]
+	fn anchorTitleAttribute(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<Rc<String>>, CordialError>
+	{
+		self.metadata.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)
+	}
+	
+	#[inline(always)]
+	fn execute(&self, _resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, _configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		if self.maskable_safe_zone_percentage == 0 || self.maskable_safe_zone_percentage > 100
+		{
+			return Err(CordialError::Configuration("maskable_safe_zone_percentage must be between 1 and 100 inclusive".to_owned()));
+		}
+		
+		let master = self.master(inputContentFilePath)?;
+		
+		const CanNotBeCompressed: bool = false;
+		let mut result = Vec::new();
+		
+		// favicon.ico
+		if !self.favicon_ico_sizes.is_empty()
+		{
+			let url = resourceUrl.replaceFileNameExtension(".ico").url(languageData)?;
+			
+			let mut pngs = Vec::with_capacity(self.favicon_ico_sizes.len());
+			for size in self.favicon_ico_sizes.iter()
+			{
+				let size = *size;
+				if size == 0 || size > 256
+				{
+					return Err(CordialError::Configuration("favicon_ico_sizes must be between 1 and 256 inclusive".to_owned()));
+				}
+				let icon = Self::icon(&master, size, size, 100, self.background_color);
+				pngs.push((size, ImageSourceSet::optimizePngImage(&icon, inputContentFilePath)?));
+			}
+			let body = Self::icoFile(&pngs);
+			
+			let headers = headerGenerator.generateHeadersForAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, &url)?;
+			let largestSize = *self.favicon_ico_sizes.iter().rev().next().unwrap();
+			let urlDataDetails = Rc::new
+			(
+				UrlDataDetails::Image
+				{
+					width: largestSize,
+					height: largestSize,
+					size: body.len() as u64,
+					placeholder: None,
+				}
+			);
+			let tags = hashmap!
+			{
+				default => urlDataDetails.clone(),
+				favicon_ico => urlDataDetails,
+			};
+			result.push((url, tags, StatusCode::Ok, content_type_image_x_icon(), headers, ResponseBody::binary(body), None, CanNotBeCompressed));
+		}
+		
+		// Square PNGs; a size used for more than one purpose is only generated once, so those purposes must share a background.
+		let mut squareSizes = BTreeMap::new();
+		Self::addSquareSizes(&mut squareSizes, self.favicon_sizes.iter(), self.background_color, "favicon_sizes")?;
+		Self::addSquareSizes(&mut squareSizes, self.web_app_manifest_icon_sizes.iter(), self.background_color, "web_app_manifest_icon_sizes")?;
+		Self::addSquareSizes(&mut squareSizes, self.apple_touch_icon_sizes.iter(), Some(self.appleTouchIconBackgroundColor()), "apple_touch_icon_sizes")?;
+		if self.windows_tiles
+		{
+			Self::addSquareSizes(&mut squareSizes, Self::WindowsSquareTileSizes.iter(), None, "windows_tiles")?;
+		}
+		for (size, &(backgroundColor, _)) in squareSizes.iter()
+		{
+			let size = *size;
+			let icon = Self::icon(&master, size, size, 100, backgroundColor);
+			let url = ResourceUrl::widthUrl(resourceUrl.withoutFileNameExtension(), ".png", languageData, size)?;
+			result.push(self.pngResponse(&icon, url, width_height_image(size, size), inputContentFilePath, headerGenerator)?);
+		}
+		
+		// Microsoft's recommended wide tile is 558x270 for a 310x150 tile.
+		if self.windows_tiles
+		{
+			let (width, height) = Self::WindowsWideTileDimensions;
+			let icon = Self::icon(&master, width, height, 100, None);
+			let url = resourceUrl.replaceFileNameExtension(&format!("-{}x{}.png", width, height)).url(languageData)?;
+			result.push(self.pngResponse(&icon, url, width_height_image(width, height), inputContentFilePath, headerGenerator)?);
+		}
+		
+		let maskableBackgroundColor = self.maskable_background_color.unwrap_or_else(|| self.opaqueBackgroundColor());
+		for size in self.maskable_icon_sizes.iter()
+		{
+			let size = *size;
+			let icon = Self::icon(&master, size, size, self.maskable_safe_zone_percentage, Some(maskableBackgroundColor));
+			let url = resourceUrl.replaceFileNameExtension(&format!("-maskable-{}w.png", size)).url(languageData)?;
+			result.push(self.pngResponse(&icon, url, maskable_width_height_image(size, size), inputContentFilePath, headerGenerator)?);
+		}
+		
+		Ok(result)
+	}
+}
+
+impl IconSetPipeline
+{
+	const WindowsSquareTileSizes: [u32; 3] = [128, 270, 558];
+	
+	const WindowsWideTileDimensions: (u32, u32) = (558, 270);
+	
+	#[inline(always)]
+	pub(crate) fn faviconIcoSizes(&self) -> String
+	{
+		let sizes: Vec<String> = self.favicon_ico_sizes.iter().map(|size| format!("{}x{}", size, size)).collect();
+		sizes.join(" ")
+	}
+	
+	#[inline(always)]
+	pub(crate) fn favIcon(&self, iconSetResourceUrl: &ResourceUrl) -> FavIcon
+	{
+		FavIcon::new(iconSetResourceUrl.clone(), self.favicon_sizes.clone())
+	}
+	
+	#[inline(always)]
+	pub(crate) fn appleTouchIcon(&self, iconSetResourceUrl: &ResourceUrl) -> FavIcon
+	{
+		FavIcon::new(iconSetResourceUrl.clone(), self.apple_touch_icon_sizes.clone())
+	}
+	
+	#[inline(always)]
+	pub(crate) fn webAppManifestIcons(&self, iconSetResourceUrl: &ResourceUrl) -> Vec<WebAppManifestIcon>
+	{
+		let mut webAppManifestIcons = Vec::with_capacity(self.web_app_manifest_icon_sizes.len() + self.maskable_icon_sizes.len());
+		
+		for size in self.web_app_manifest_icon_sizes.iter()
+		{
+			webAppManifestIcons.push(WebAppManifestIcon::new(ResourceReference { resource: iconSetResourceUrl.clone(), tag: width_height_image(*size, *size) }, WebAppManifestIconPurpose::any));
+		}
+		
+		for size in self.maskable_icon_sizes.iter()
+		{
+			webAppManifestIcons.push(WebAppManifestIcon::new(ResourceReference { resource: iconSetResourceUrl.clone(), tag: maskable_width_height_image(*size, *size) }, WebAppManifestIconPurpose::maskable));
+		}
+		
+		webAppManifestIcons
+	}
+	
+	#[inline(always)]
+	fn addSquareSizes<'a, I: Iterator<Item=&'a u32>>(squareSizes: &mut BTreeMap<u32, (Option<HexadecimalColor>, &'static str)>, sizes: I, backgroundColor: Option<HexadecimalColor>, purpose: &'static str) -> Result<(), CordialError>
+	{
+		for size in sizes
+		{
+			let size = *size;
+			if size == 0
+			{
+				return Err(CordialError::Configuration(format!("{} can not contain a zero size", purpose)));
+			}
+			
+			if let Some(&(existingBackgroundColor, existingPurpose)) = squareSizes.get(&size)
+			{
+				if existingBackgroundColor != backgroundColor
+				{
+					return Err(CordialError::Configuration(format!("icon size {} is used by both {} and {}, which have different backgrounds", size, existingPurpose, purpose)));
+				}
+				continue;
+			}
+			squareSizes.insert(size, (backgroundColor, purpose));
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn appleTouchIconBackgroundColor(&self) -> HexadecimalColor
+	{
+		self.apple_touch_icon_background_color.unwrap_or_else(|| self.opaqueBackgroundColor())
+	}
+	
+	#[inline(always)]
+	fn opaqueBackgroundColor(&self) -> HexadecimalColor
+	{
+		self.background_color.unwrap_or(HexadecimalColor([0xFF, 0xFF, 0xFF]))
+	}
+	
+	/// The largest width or height of any icon; a PNG master smaller than this would be upscaled.
+	#[inline(always)]
+	fn largestIconDimension(&self) -> u32
+	{
+		let mut largestIconDimension = 0;
+		{
+			let mut consider = |sizes: &BTreeSet<u32>|
+			{
+				if let Some(largest) = sizes.iter().rev().next()
+				{
+					largestIconDimension = max(largestIconDimension, *largest);
+				}
+			};
+			consider(&self.favicon_ico_sizes);
+			consider(&self.favicon_sizes);
+			consider(&self.apple_touch_icon_sizes);
+			consider(&self.web_app_manifest_icon_sizes);
+			consider(&self.maskable_icon_sizes);
+		}
+		
+		if self.windows_tiles
+		{
+			let (wideWidth, wideHeight) = Self::WindowsWideTileDimensions;
+			for dimension in Self::WindowsSquareTileSizes.iter().chain([wideWidth, wideHeight].iter())
+			{
+				largestIconDimension = max(largestIconDimension, *dimension);
+			}
+		}
+		
+		largestIconDimension
+	}
+	
+	#[inline(always)]
+	fn pngResponse(&self, icon: &::image::DynamicImage, url: Url, resourceTag: ResourceTag, inputContentFilePath: &Path, headerGenerator: &mut HeaderGenerator) -> Result<PipelineResponse, CordialError>
+	{
+		let (width, height) = icon.dimensions();
+		let body = ImageSourceSet::optimizePngImage(icon, inputContentFilePath)?;
+		
+		const CanNotBeCompressed: bool = false;
+		let headers = headerGenerator.generateHeadersForAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, &url)?;
+		
+		let urlDataDetails = Rc::new
+		(
+			UrlDataDetails::Image
+			{
+				width,
+				height,
+				size: body.len() as u64,
+				placeholder: None,
+			}
+		);
+		
+		Ok((url, hashmap! { resourceTag => urlDataDetails }, StatusCode::Ok, content_type_image_png(), headers, ResponseBody::binary(body), None, CanNotBeCompressed))
+	}
+	
+	/// Large enough to be scaled down for every icon.
+	#[inline(always)]
+	fn master(&self, inputContentFilePath: &Path) -> Result<::image::DynamicImage, CordialError>
+	{
+		const MinimumMasterDimension: u32 = 1024;
+		
+		match self.input_format.unwrap_or_default()
+		{
+			IconSetInputFormat::svg =>
+			{
+				let svgString = inputContentFilePath.fileContentsAsString().context(inputContentFilePath)?;
+				let original = SvgPipeline::rasterize(&svgString, ::resvg::FitTo::Original, inputContentFilePath)?;
+				let (width, height) = original.dimensions();
+				if width >= height
+				{
+					SvgPipeline::rasterize(&svgString, ::resvg::FitTo::Width(MinimumMasterDimension), inputContentFilePath)
+				}
+				else
+				{
+					SvgPipeline::rasterize(&svgString, ::resvg::FitTo::Height(MinimumMasterDimension), inputContentFilePath)
+				}
+			}
+			
+			IconSetInputFormat::png =>
+			{
+				let master = inputContentFilePath.fileContentsAsImage(ImageInputFormat::PNG)?;
+				
+				let (width, height) = master.dimensions();
+				let largestIconDimension = self.largestIconDimension();
+				if max(width, height) < largestIconDimension
+				{
+					return Err(CordialError::Configuration(format!("icon_set PNG master '{:?}' is {}x{} but must be at least {} pixels wide or high so that icons are not upscaled", inputContentFilePath, width, height, largestIconDimension)));
+				}
+				
+				Ok(master)
+			}
+		}
+	}
+	
+	/// `percentage` is of the shorter of `width` and `height`; the master is centered.
+	#[inline(always)]
+	fn icon(master: &::image::DynamicImage, width: u32, height: u32, percentage: u8, backgroundColor: Option<HexadecimalColor>) -> ::image::DynamicImage
+	{
+		let boundingBox = max(1, min(width, height) * (percentage as u32) / 100);
+		
+		let (masterWidth, masterHeight) = master.dimensions();
+		let (scaledWidth, scaledHeight) = if masterWidth >= masterHeight
+		{
+			(boundingBox, max(1, (masterHeight as u64 * boundingBox as u64 / masterWidth as u64) as u32))
+		}
+		else
+		{
+			(max(1, (masterWidth as u64 * boundingBox as u64 / masterHeight as u64) as u32), boundingBox)
+		};
+		let scaled = master.resize_exact(scaledWidth, scaledHeight, ::image::FilterType::Lanczos3).to_rgba();
+		
+		let background = match backgroundColor
+		{
+			None => ::image::Rgba([0, 0, 0, 0]),
+			Some(HexadecimalColor(rgb)) => ::image::Rgba([rgb[0], rgb[1], rgb[2], 0xFF]),
+		};
+		let mut canvas = ::image::RgbaImage::from_pixel(width, height, background);
+		::image::imageops::overlay(&mut canvas, &scaled, (width - scaledWidth) / 2, (height - scaledHeight) / 2);
+		
+		::image::DynamicImage::ImageRgba8(canvas)
+	}
+	
+	/// Each image is stored as PNG, which all browsers that support `.ico` sizes other than 16x16 understand.
+	#[inline(always)]
+	fn icoFile(pngs: &[(u32, Vec<u8>)]) -> Vec<u8>
+	{
+		const IconDirectorySize: usize = 6;
+		const IconDirectoryEntrySize: usize = 16;
+		
+		let dataSize = pngs.iter().map(|&(_, ref png)| png.len()).sum::<usize>();
+		let mut ico = Vec::with_capacity(IconDirectorySize + IconDirectoryEntrySize * pngs.len() + dataSize);
+		
+		#[inline(always)]
+		fn u16LittleEndian(ico: &mut Vec<u8>, value: u16)
+		{
+			ico.extend_from_slice(&[value as u8, (value >> 8) as u8]);
+		}
+		
+		#[inline(always)]
+		fn u32LittleEndian(ico: &mut Vec<u8>, value: u32)
+		{
+			ico.extend_from_slice(&[value as u8, (value >> 8) as u8, (value >> 16) as u8, (value >> 24) as u8]);
+		}
+		
+		// ICONDIR: reserved, type (1 is icon), count
+		u16LittleEndian(&mut ico, 0);
+		u16LittleEndian(&mut ico, 1);
+		u16LittleEndian(&mut ico, pngs.len() as u16);
+		
+		// ICONDIRENTRY: width, height (0 is 256), colour count, reserved, colour planes, bits per pixel, size and offset
+		let mut offset = IconDirectorySize + IconDirectoryEntrySize * pngs.len();
+		for &(size, ref png) in pngs.iter()
+		{
+			let dimension = if size == 256 { 0 } else { size as u8 };
+			ico.extend_from_slice(&[dimension, dimension, 0, 0]);
+			u16LittleEndian(&mut ico, 1);
+			u16LittleEndian(&mut ico, 32);
+			u32LittleEndian(&mut ico, png.len() as u32);
+			u32LittleEndian(&mut ico, offset as u32);
+			offset += png.len();
+		}
+		
+		for &(_, ref png) in pngs.iter()
+		{
+			ico.extend_from_slice(png);
+		}
+		
+		ico
+	}
+	
+	#[inline(always)]
+	fn favicon_ico_sizes_default() -> BTreeSet<u32>
+	{
+		btreeset! { 16, 32, 48 }
+	}
+	
+	/// iPhone, iPad, iPad Pro and iPhone Retina respectively.
+	#[inline(always)]
+	fn apple_touch_icon_sizes_default() -> BTreeSet<u32>
+	{
+		btreeset! { 120, 152, 167, 180 }
+	}
+	
+	#[inline(always)]
+	fn web_app_manifest_icon_sizes_default() -> BTreeSet<u32>
+	{
+		btreeset! { 192, 512 }
+	}
+	
+	#[inline(always)]
+	fn windows_tiles_default() -> bool
+	{
+		true
+	}
+	
+	#[inline(always)]
+	fn maskable_safe_zone_percentage_default() -> u8
+	{
+		80
+	}
+}
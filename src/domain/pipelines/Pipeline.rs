@@ -25,6 +25,13 @@ pub(crate) trait Pipeline
 		vec![resourceInputName.to_owned()]
 	}
 	
+	/// If false, a resource's input content file is optional, and `executeWithoutInputContentFile()` is called instead of `execute()` if there is none.
+	#[inline(always)]
+	fn requiresInputContentFile(&self) -> bool
+	{
+		true
+	}
+	
 	#[inline(always)]
 	fn is<'a>(&self) -> (bool, bool);
 	
@@ -36,4 +43,10 @@ pub(crate) trait Pipeline
 	
 	#[inline(always)]
 	fn execute(&self, resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>;
+	
+	#[inline(always)]
+	fn executeWithoutInputContentFile(&self, _resources: &Resources, _resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, _headerGenerator: &mut HeaderGenerator, _languageData: &LanguageData, _configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		unreachable!("Only called if requiresInputContentFile() is false")
+	}
 }
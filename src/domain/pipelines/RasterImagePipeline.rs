@@ -13,14 +13,27 @@ pub(crate) struct RasterImagePipeline
 	#[serde(default)] input_format: Option<ImageInputFormat>,
 
 	#[serde(default)] metadata: Rc<ImageMetaData>,
+	#[serde(default)] import_credit_and_license_url_from_embedded_metadata: bool,
 	#[serde(default)] source_set_excluding_original: Vec<ImageSourceSetEntry>,
+	#[serde(default, deserialize_with = "RasterImagePipeline::art_direction_deserialize_with")] art_direction: Vec<ImageArtDirection>,
 
 	#[serde(default)] jpeg_quality: Option<u8>,
 	#[serde(default)] jpeg_speed_over_compression: bool,
+	#[serde(default)] jpeg_encoder: JpegEncoder,
+	#[serde(default)] png_quantization: Option<PngQuantization>,
+	#[serde(default)] alternative_formats: Vec<ImageAlternativeFormat>,
+	#[serde(default)] negotiate_alternative_formats: bool,
+	#[serde(default = "RasterImagePipeline::convert_color_profile_to_srgb_default")] convert_color_profile_to_srgb: bool,
+	#[serde(default)] wide_gamut_variant: bool,
 	#[serde(default)] transformations: Vec<ImageTransformation>,
+	/// Off by default; the data URI placeholder is an inline `style`, which a Content Security Policy without `style-src 'unsafe-inline'` blocks.
+	#[serde(default)] placeholder: Option<ImagePlaceholderSettings>,
 
 	#[serde(default, skip_deserializing, skip_serializing)] primaryImageDimensions: Cell<(u32, u32)>,
 	#[serde(default = "ProcessedImageSourceSet::processedImageSourceSet_default", skip_deserializing, skip_serializing)] pub(crate) processedImageSourceSet: RefCell<ProcessedImageSourceSet>,
+	
+	/// Embedded credits and license URLs by input file, so that each input file is only read once however many languages and renders use it.
+	#[serde(default, skip_deserializing, skip_serializing)] embeddedCreditsAndLicenseUrls: HashMap<PathBuf, Rc<EmbeddedCreditAndLicenseUrl>>,
 }
 
 impl Default for RasterImagePipeline
@@ -36,12 +49,22 @@ impl Default for RasterImagePipeline
 			language_aware: false,
 			input_format: None,
 			metadata: Default::default(),
+			import_credit_and_license_url_from_embedded_metadata: false,
 			source_set_excluding_original: Default::default(),
+			art_direction: Default::default(),
 			jpeg_quality: None,
 			jpeg_speed_over_compression: false,
+			jpeg_encoder: Default::default(),
+			png_quantization: None,
+			alternative_formats: Default::default(),
+			negotiate_alternative_formats: false,
+			convert_color_profile_to_srgb: Self::convert_color_profile_to_srgb_default(),
+			wide_gamut_variant: false,
 			transformations: Default::default(),
+			placeholder: None,
 			primaryImageDimensions: Default::default(),
 			processedImageSourceSet: ProcessedImageSourceSet::processedImageSourceSet_default(),
+			embeddedCreditsAndLicenseUrls: Default::default(),
 		}
 	}
 }
@@ -96,40 +119,211 @@ impl Pipeline for RasterImagePipeline
 	}
 	
 	#[inline(always)]
-	fn execute(&self, _resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, _configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	fn execute(&self, resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
 	{
-		// load original
-		let mut imageBeforeTransformation = match ImageInputFormat::load(self.input_format, inputContentFilePath)
+		let iso639Dash1Alpha2Language = languageData.iso639Dash1Alpha2Language;
+		let (imageAfterTransformation, focalPoint) = self.transformedImageAndFocalPoint(inputContentFilePath, resources, configuration, iso639Dash1Alpha2Language)?;
+		let wideGamutImageAfterTransformation = self.wideGamutTransformedImage(inputContentFilePath, resources, configuration, iso639Dash1Alpha2Language)?;
+
+		let placeholder = match self.placeholder
 		{
-			Some(result) => match result
-			{
-				Err(error) => return Err(error),
-				Ok(image) => image,
-			}
-			None => panic!("Should not be possible"),
+			None => None,
+			Some(ref placeholderSettings) => placeholderSettings.placeholder(&imageAfterTransformation, inputContentFilePath)?,
 		};
 
-		// transform
-		let imageAfterTransformation = if let Some(transformedImage) = ImageTransformation::applyTransformations(&mut imageBeforeTransformation, &self.transformations)?
+		let focalPoint = focalPoint.resolve(&imageAfterTransformation)?;
+		let mut artDirectedImages = Vec::with_capacity(self.art_direction.len());
+		for artDirection in self.art_direction.iter()
 		{
-			transformedImage
+			artDirectedImages.push(artDirection.artDirectedImage(&imageAfterTransformation, &focalPoint, resources, configuration, iso639Dash1Alpha2Language)?);
 		}
-		else
-		{
-			imageBeforeTransformation
-		};
 
 		// generate image src set
-		let mut imageSourceSet = ImageSourceSet::new(inputContentFilePath, self.input_format, &resourceUrl, self.jpeg_quality, self.jpeg_speed_over_compression, imageAfterTransformation, languageData);
-		imageSourceSet.add(&self.source_set_excluding_original)?;
+		let mut imageSourceSet = ImageSourceSet::new(inputContentFilePath, self.input_format, &resourceUrl, self.imageEncoderSettings(), imageAfterTransformation, wideGamutImageAfterTransformation, placeholder, languageData);
+		imageSourceSet.add(&self.source_set_excluding_original, &focalPoint)?;
+
+		let mut artDirectedImageSourceSets = Vec::with_capacity(self.art_direction.len());
+		for ((artDirectionIndex, artDirection), (artDirectedImage, artDirectedFocalPoint)) in self.art_direction.iter().enumerate().zip(artDirectedImages.into_iter())
+		{
+			artDirectedImageSourceSets.push((artDirection.media(), artDirection.imageSourceSet(artDirectionIndex, &imageSourceSet, artDirectedImage, &artDirectedFocalPoint)?));
+		}
 
 		self.primaryImageDimensions.set(imageSourceSet.primaryImageDimensions());
 		{
 			let mut borrowed = self.processedImageSourceSet.try_borrow_mut()?;
-			imageSourceSet.processedImageSourceSet(&mut borrowed.2)?;
+			imageSourceSet.processedImageSourceSet(&mut borrowed)?;
+			for &(media, ref artDirectedImageSourceSet) in artDirectedImageSourceSets.iter()
+			{
+				artDirectedImageSourceSet.artDirectedProcessedImageSourceSet(media, &mut borrowed)?;
+			}
 		}
 
 		const CanNotBeCompressed: bool = false;
-		imageSourceSet.urls(|url| headerGenerator.generateHeadersForAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, url))
+		let negotiatesAlternativeImageFormats = self.negotiatesAlternativeImageFormats();
+		let mut generateHeaders = |url: &Url| if negotiatesAlternativeImageFormats
+		{
+			headerGenerator.generateHeadersForNegotiatedAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, url)
+		}
+		else
+		{
+			headerGenerator.generateHeadersForAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, url)
+		};
+
+		let mut urls = imageSourceSet.urls(&mut generateHeaders)?;
+		for &(_, ref artDirectedImageSourceSet) in artDirectedImageSourceSets.iter()
+		{
+			urls.extend(artDirectedImageSourceSet.urls(&mut generateHeaders)?);
+		}
+		Ok(urls)
+	}
+}
+
+impl RasterImagePipeline
+{
+	/// Converted to sRGB if the image has an embedded ICC profile (unless `convert_color_profile_to_srgb` is false).
+	#[inline(always)]
+	pub(crate) fn transformedImage(&self, inputContentFilePath: &Path, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<::image::DynamicImage, CordialError>
+	{
+		self.transformedImageAndFocalPoint(inputContentFilePath, resources, configuration, iso639Dash1Alpha2Language).map(|(image, _)| image)
+	}
+	
+	/// The focal point is translated by any crops in `transformations`.
+	#[inline(always)]
+	fn transformedImageAndFocalPoint(&self, inputContentFilePath: &Path, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<(::image::DynamicImage, FocalPoint), CordialError>
+	{
+		let image = self.loadedImage(inputContentFilePath)?;
+		
+		let image = if self.convert_color_profile_to_srgb
+		{
+			match IccProfile::read(inputContentFilePath)?
+			{
+				None => image,
+				Some(iccProfile) => iccProfile.convertToSRgb(image, inputContentFilePath)?,
+			}
+		}
+		else
+		{
+			image
+		};
+		
+		let mut focalPoint = self.metadata.focal_point;
+		let transformedImage = self.transformed(image, &mut focalPoint, resources, configuration, iso639Dash1Alpha2Language)?;
+		Ok((transformedImage, focalPoint))
+	}
+	
+	/// Only produced if `wide_gamut_variant` is true and the image has an embedded ICC profile with a wider gamut than sRGB.
+	#[inline(always)]
+	fn wideGamutTransformedImage(&self, inputContentFilePath: &Path, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<::image::DynamicImage>, CordialError>
+	{
+		if !self.wide_gamut_variant
+		{
+			return Ok(None);
+		}
+		
+		match IccProfile::read(inputContentFilePath)?
+		{
+			None => Ok(None),
+			Some(ref iccProfile) if !iccProfile.isWiderThanSRgb(inputContentFilePath)? => Ok(None),
+			Some(iccProfile) =>
+			{
+				let image = iccProfile.convertToDisplayP3(self.loadedImage(inputContentFilePath)?, inputContentFilePath)?;
+				let mut focalPoint = self.metadata.focal_point;
+				Ok(Some(self.transformed(image, &mut focalPoint, resources, configuration, iso639Dash1Alpha2Language)?))
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn loadedImage(&self, inputContentFilePath: &Path) -> Result<::image::DynamicImage, CordialError>
+	{
+		match ImageInputFormat::load(self.input_format, inputContentFilePath)
+		{
+			Some(result) => result,
+			None => panic!("Should not be possible"),
+		}
+	}
+	
+	#[inline(always)]
+	fn transformed(&self, mut imageBeforeTransformation: ::image::DynamicImage, focalPoint: &mut FocalPoint, resources: &Resources, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<::image::DynamicImage, CordialError>
+	{
+		if let Some(transformedImage) = ImageTransformation::applyTransformations(&mut imageBeforeTransformation, &self.transformations, focalPoint, resources, configuration, iso639Dash1Alpha2Language)?
+		{
+			Ok(transformedImage)
+		}
+		else
+		{
+			Ok(imageBeforeTransformation)
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn importCreditAndLicenseUrlFromEmbeddedMetaData(&mut self, inputContentFilePath: &Path, resources: &Resources, localization: &Localization, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<(), CordialError>
+	{
+		if self.import_credit_and_license_url_from_embedded_metadata
+		{
+			let embeddedCreditAndLicenseUrl = match self.embeddedCreditsAndLicenseUrls.get(inputContentFilePath)
+			{
+				Some(embeddedCreditAndLicenseUrl) => embeddedCreditAndLicenseUrl.clone(),
+				None =>
+				{
+					let embeddedImageMetaData = EmbeddedImageMetaData::read(inputContentFilePath)?;
+					Rc::new(EmbeddedCreditAndLicenseUrl::import(&embeddedImageMetaData, resources, localization))
+				}
+			};
+			self.embeddedCreditsAndLicenseUrls.insert(inputContentFilePath.to_path_buf(), embeddedCreditAndLicenseUrl.clone());
+			Rc::make_mut(&mut self.metadata).importCreditAndLicenseUrl(iso639Dash1Alpha2Language, embeddedCreditAndLicenseUrl);
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn imageEncoderSettings(&self) -> ImageEncoderSettings
+	{
+		ImageEncoderSettings
+		{
+			jpegQuality: self.jpeg_quality,
+			jpegSpeedOverCompression: self.jpeg_speed_over_compression,
+			jpegEncoder: &self.jpeg_encoder,
+			pngQuantization: self.png_quantization.as_ref(),
+			alternativeFormats: &self.alternative_formats,
+		}
+	}
+	
+	/// Resources overlaid by this image's transformations and those of its art directions.
+	#[inline(always)]
+	pub(crate) fn overlayResourceUrls(&self) -> Vec<ResourceUrl>
+	{
+		let transformations = self.art_direction.iter().flat_map(|artDirection| artDirection.transformations().iter());
+		self.transformations.iter().chain(transformations).filter_map(|transformation| transformation.overlayResourceUrl()).cloned().collect()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn negotiatesAlternativeImageFormats(&self) -> bool
+	{
+		self.negotiate_alternative_formats && !self.alternative_formats.is_empty()
+	}
+	
+	#[inline(always)]
+	fn convert_color_profile_to_srgb_default() -> bool
+	{
+		true
+	}
+	
+	#[inline(always)]
+	fn art_direction_deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<ImageArtDirection>, D::Error>
+	{
+		let artDirections = Vec::<ImageArtDirection>::deserialize(deserializer)?;
+		
+		let mut names = HashSet::with_capacity(artDirections.len());
+		for artDirection in artDirections.iter()
+		{
+			let name = artDirection.validatedName().map_err(D::Error::custom)?;
+			if !names.insert(name)
+			{
+				return Err(D::Error::custom(format!("art direction name '{}' is used more than once", name)));
+			}
+		}
+		
+		Ok(artDirections)
 	}
 }
@@ -0,0 +1,205 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Renders a handlebars SVG template, filled in with a HTML page's title, description, author and publication date, to PNG images suitable for Facebook Open Graph and Twitter Cards.
+/// The HTML page uses this resource for `og:image` and `twitter:image` unless it has an `article_image`.
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct SocialCardPipeline
+{
+	#[serde(default = "max_age_in_seconds_long_default")] max_age_in_seconds: u32,
+	#[serde(default = "is_downloadable_false_default")] is_downloadable: bool,
+	#[serde(default = "is_versioned_true_default")] is_versioned: bool,
+	#[serde(default)] input_format: Option<SocialCardInputFormat>,
+	
+	#[serde(default)] metadata: Rc<ImageMetaData>,
+	
+	/// The HTML page this is a social card for.
+	#[serde(default)] html: Option<ResourceUrl>,
+	
+	/// Width then height; the first is the primary image.
+	#[serde(default = "SocialCardPipeline::sizes_default")] sizes: Vec<(u32, u32)>,
+	
+	#[serde(default)] template_parameters: Option<JsonMap<String, JsonValue>>,
+	
+	#[serde(default, skip_deserializing, skip_serializing)] primaryImageDimensions: Cell<(u32, u32)>,
+}
+
+impl Default for SocialCardPipeline
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			max_age_in_seconds: max_age_in_seconds_long_default(),
+			is_downloadable: is_downloadable_false_default(),
+			is_versioned: is_versioned_true_default(),
+			input_format: None,
+			metadata: Default::default(),
+			html: None,
+			sizes: Self::sizes_default(),
+			template_parameters: None,
+			primaryImageDimensions: Default::default(),
+		}
+	}
+}
+
+impl Pipeline for SocialCardPipeline
+{
+	#[inline(always)]
+	fn imageMetaData(&self) -> Result<&Rc<ImageMetaData>, CordialError>
+	{
+		Ok(&self.metadata)
+	}
+	
+	#[inline(always)]
+	fn addToImgAttributes(&self, attributes: &mut Vec<Attribute>) -> Result<(), CordialError>
+	{
+		let dimensions = self.primaryImageDimensions.get();
+		attributes.push("width".u32_attribute(dimensions.0));
+		attributes.push("height".u32_attribute(dimensions.1));
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn processingPriority(&self) -> ProcessingPriority
+	{
+		NoDependenciesEgImage
+	}
+	
+	#[inline(always)]
+	fn resourceInputContentFileNamesWithExtension(&self, resourceInputName: &str) -> Vec<String>
+	{
+		self.input_format.resourceInputContentFileNamesWithExtension(resourceInputName)
+	}
+	
+	#[inline(always)]
+	fn is<'a>(&self) -> (bool, bool)
+	{
+		const IsLanguageAware: bool = true;
+		
+		(self.is_versioned, IsLanguageAware)
+	}
+	
+	#[inline(always)]
+	fn anchorTitleAttribute(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<Rc<String>>, CordialError>
+	{
+		self.metadata.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)
+	}
+	
+	#[inline(always)]
+	fn execute(&self, resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		if self.sizes.is_empty()
+		{
+			return Err(CordialError::Configuration("social_card pipeline has no sizes".to_owned()));
+		}
+		
+		let htmlResourceUrl = match self.html
+		{
+			None => return Err(CordialError::Configuration("social_card pipeline requires a html resource".to_owned())),
+			Some(ref htmlResourceUrl) => htmlResourceUrl,
+		};
+		
+		let fallbackIso639Dash1Alpha2Language = configuration.fallbackIso639Dash1Alpha2Language();
+		let iso639Dash1Alpha2Language = languageData.iso639Dash1Alpha2Language;
+		
+		let (htmlAbstract, author, publicationDate) =
+		{
+			let htmlResource = htmlResourceUrl.resourceMandatory(resources)?;
+			htmlResource.registerSocialCard(resourceUrl)?;
+			htmlResource.socialCardData(resources, fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?
+		};
+		
+		let template = inputContentFilePath.fileContentsAsString().context(inputContentFilePath)?;
+		
+		let smallestSize = self.sizes.iter().min_by_key(|&&(width, height)| width as u64 * height as u64).map(|size| *size);
+		let largestSize = self.sizes.iter().max_by_key(|&&(width, height)| width as u64 * height as u64).map(|size| *size);
+		
+		let mut result = Vec::with_capacity(self.sizes.len());
+		let mut isPrimary = true;
+		for &(width, height) in self.sizes.iter()
+		{
+			let json = &json!
+			({
+				"configuration": configuration,
+				"iso_639_1 alpha_2_language_code": iso639Dash1Alpha2Language,
+				"template_parameters": self.template_parameters,
+				"width": width,
+				"height": height,
+				"title": htmlAbstract.title,
+				"description": htmlAbstract.description,
+				"author": author,
+				"publication_date": publicationDate.map(|publicationDate| publicationDate.format("%Y-%m-%d").to_string()),
+			});
+			let svgString = handlebars.renderWithHtmlEscapeFunction(|templateRenderer| templateRenderer.template_render(&template, json))?;
+			
+			let image = SvgPipeline::rasterize(&svgString, ::resvg::FitTo::Width(width), inputContentFilePath)?;
+			if image.height() != height
+			{
+				return Err(CordialError::Configuration(format!("social card template rendered at width {} is {} pixels high, not {}; use {{{{width}}}} and {{{{height}}}} in the template", width, image.height(), height)));
+			}
+			
+			let url = if isPrimary
+			{
+				self.primaryImageDimensions.set((width, height));
+				resourceUrl.replaceFileNameExtension(".png").url(languageData)?
+			}
+			else
+			{
+				resourceUrl.replaceFileNameExtension(&format!("-{}x{}.png", width, height)).url(languageData)?
+			};
+			
+			let body = ImageSourceSet::optimizePngImage(&image, inputContentFilePath)?;
+			
+			const CanNotBeCompressed: bool = false;
+			let headers = headerGenerator.generateHeadersForAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, &url)?;
+			
+			let urlDataDetails = Rc::new
+			(
+				UrlDataDetails::Image
+				{
+					width,
+					height,
+					size: body.len() as u64,
+					placeholder: None,
+				}
+			);
+			
+			let mut tags = hashmap!
+			{
+				width_height_image(width, height) => urlDataDetails.clone(),
+			};
+			if isPrimary
+			{
+				tags.insert(default, urlDataDetails.clone());
+				tags.insert(primary_image, urlDataDetails.clone());
+			}
+			if smallestSize == Some((width, height))
+			{
+				tags.insert(smallest_image, urlDataDetails.clone());
+			}
+			if largestSize == Some((width, height))
+			{
+				tags.insert(largest_image, urlDataDetails.clone());
+			}
+			
+			result.push((url, tags, StatusCode::Ok, content_type_image_png(), headers, ResponseBody::binary(body), None, CanNotBeCompressed));
+			isPrimary = false;
+		}
+		
+		Ok(result)
+	}
+}
+
+impl SocialCardPipeline
+{
+	/// Facebook Open Graph's recommended size and Twitter's `summary_large_image` ratio of 2:1.
+	#[inline(always)]
+	fn sizes_default() -> Vec<(u32, u32)>
+	{
+		vec![(1200, 630), (1200, 600)]
+	}
+}
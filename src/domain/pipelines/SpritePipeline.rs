@@ -0,0 +1,188 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct SpritePipeline
+{
+	#[serde(default = "max_age_in_seconds_long_default")] max_age_in_seconds: u32,
+	#[serde(default = "is_downloadable_false_default")] is_downloadable: bool,
+	#[serde(default = "is_versioned_true_default")] is_versioned: bool,
+	#[serde(default)] language_aware: bool,
+	#[serde(default)] input_format: Option<SpriteInputFormat>,
+
+	#[serde(default)] metadata: Rc<ImageMetaData>,
+	#[serde(default)] sprites: OrderMap<String, ResourceUrl>,
+	#[serde(default)] layout: SpriteLayout,
+	#[serde(default = "SpritePipeline::padding_default")] padding: u32,
+	#[serde(default = "SpritePipeline::scss_map_name_default")] scss_map_name: String,
+
+	#[serde(default, skip_deserializing, skip_serializing)] primaryImageDimensions: Cell<(u32, u32)>,
+}
+
+impl Default for SpritePipeline
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			max_age_in_seconds: max_age_in_seconds_long_default(),
+			is_downloadable: is_downloadable_false_default(),
+			is_versioned: is_versioned_true_default(),
+			language_aware: false,
+			input_format: None,
+			metadata: Default::default(),
+			sprites: Default::default(),
+			layout: Default::default(),
+			padding: Self::padding_default(),
+			scss_map_name: Self::scss_map_name_default(),
+			primaryImageDimensions: Default::default(),
+		}
+	}
+}
+
+impl Pipeline for SpritePipeline
+{
+	#[inline(always)]
+	fn imageMetaData(&self) -> Result<&Rc<ImageMetaData>, CordialError>
+	{
+		Ok(&self.metadata)
+	}
+
+	#[inline(always)]
+	fn addToImgAttributes(&self, attributes: &mut Vec<Attribute>) -> Result<(), CordialError>
+	{
+		let dimensions = self.primaryImageDimensions.get();
+		attributes.push("width".u32_attribute(dimensions.0));
+		attributes.push("height".u32_attribute(dimensions.1));
+		Ok(())
+	}
+
+	#[inline(always)]
+	fn processingPriority(&self) -> ProcessingPriority
+	{
+		CompositesEgCssSpriteImages
+	}
+
+	#[inline(always)]
+	fn resourceInputContentFileNamesWithExtension(&self, resourceInputName: &str) -> Vec<String>
+	{
+		self.input_format.resourceInputContentFileNamesWithExtension(resourceInputName)
+	}
+
+	#[inline(always)]
+	fn is<'a>(&self) -> (bool, bool)
+	{
+		(self.is_versioned, self.language_aware)
+	}
+	
+	#[inline(always)]
+	fn anchorTitleAttribute(&self, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<Option<Rc<String>>, CordialError>
+	{
+		self.metadata.anchorTitleAttribute(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)
+	}
+
+	#[inline(always)]
+	fn execute(&self, resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		if self.sprites.is_empty()
+		{
+			return Err(CordialError::Configuration("A sprite sheet must have at least one sprite".to_owned()));
+		}
+		
+		let url = resourceUrl.replaceFileNameExtension(".png").url(languageData)?;
+		
+		let mut images = Vec::with_capacity(self.sprites.len());
+		for (name, spriteResourceUrl) in self.sprites.iter()
+		{
+			let resource = spriteResourceUrl.resourceMandatory(resources)?;
+			images.push((name.to_owned(), resource.imageForCompositing(spriteResourceUrl, resources, configuration, languageData.iso639Dash1Alpha2Language)?));
+		}
+		let spriteSheet = SpriteSheet::new(images, self.layout, self.padding);
+		
+		self.writeScssPartial(&spriteSheet, inputContentFilePath, resourceUrl, languageData, configuration)?;
+		
+		let (width, height) = spriteSheet.image().dimensions();
+		self.primaryImageDimensions.set((width, height));
+		
+		let body = ImageSourceSet::optimizePngImage(spriteSheet.image(), inputContentFilePath)?;
+		
+		const CanNotBeCompressed: bool = false;
+		let headers = headerGenerator.generateHeadersForAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, &url)?;
+		
+		let urlDataDetails = Rc::new
+		(
+			UrlDataDetails::Image
+			{
+				width,
+				height,
+				size: body.len() as u64,
+				placeholder: None,
+			}
+		);
+
+		let tags = hashmap!
+		{
+			default => urlDataDetails.clone(),
+
+			smallest_image => urlDataDetails.clone(),
+			largest_image => urlDataDetails.clone(),
+			primary_image => urlDataDetails.clone(),
+			width_image(width) => urlDataDetails.clone(),
+			height_image(height) => urlDataDetails.clone(),
+			width_height_image(width, height) => urlDataDetails.clone(),
+		};
+		
+		Ok(vec![(url, tags, StatusCode::Ok, content_type_image_png(), headers, ResponseBody::binary(body), None, CanNotBeCompressed)])
+	}
+}
+
+impl SpritePipeline
+{
+	/// Writes a SASS partial, eg `_icons.scss` for the resource `images/icons.png`, that can be imported as `@import "images/icons";`.
+	/// The partial consists of the generated map of offsets followed by the input content file, which may use that map.
+	/// Language aware sprites have the language code appended, eg `_icons-en.scss`.
+	fn writeScssPartial(&self, spriteSheet: &SpriteSheet, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, languageData: &LanguageData, configuration: &Configuration) -> Result<(), CordialError>
+	{
+		let mut scss = spriteSheet.scssMap(&self.scss_map_name);
+		scss.push_str(&inputContentFilePath.fileContentsAsString().context(inputContentFilePath)?);
+		
+		let relativePath = Path::new(resourceUrl.withoutFileNameExtension().trim_left_matches('/'));
+		let fileStem = match relativePath.file_name()
+		{
+			None => return Err(CordialError::Configuration(format!("Sprite resource URL '{}' does not have a file name", resourceUrl))),
+			Some(fileStem) => fileStem.to_string_lossy(),
+		};
+		let partialFileName = if self.language_aware
+		{
+			format!("_{}-{}.scss", fileStem, languageData.iso639Dash1Alpha2Language.to_iso_639_1_alpha_2_language_code())
+		}
+		else
+		{
+			format!("_{}.scss", fileStem)
+		};
+		
+		let partialFilePath = match relativePath.parent()
+		{
+			None => configuration.generatedSassImportsFolderPath.join(partialFileName),
+			Some(parent) => configuration.generatedSassImportsFolderPath.join(parent).join(partialFileName),
+		};
+		partialFilePath.createParentFolderForFilePath().context(&partialFilePath)?;
+		partialFilePath.createFileWithStringContents(&scss).context(&partialFilePath)?;
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn padding_default() -> u32
+	{
+		2
+	}
+	
+	#[inline(always)]
+	fn scss_map_name_default() -> String
+	{
+		"sprites".to_owned()
+	}
+}
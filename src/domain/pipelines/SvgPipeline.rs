@@ -15,6 +15,9 @@ pub(crate) struct SvgPipeline
 	#[serde(default)] metadata: Rc<ImageMetaData>,
 
 	#[serde(default = "SvgPipeline::optimize_default")] optimize: Option<CleaningSettings>,
+	
+	/// Widths in pixels at which to also rasterize to PNG, eg for iTunes artwork or Windows tiles, which do not accept SVG.
+	#[serde(default)] rasterize_to_png_widths: Vec<u32>,
 
 	// Responsive tips: https://useiconic.com/guides/using-iconic-responsively
 	// SVG can be an 'icon-stack' (ie multiple images in one file), typically with less complexity for smaller sizes
@@ -37,6 +40,7 @@ impl Default for SvgPipeline
 			input_format: Default::default(),
 			metadata: Default::default(),
 			optimize: Self::optimize_default(),
+			rasterize_to_png_widths: Default::default(),
 			primaryImageDimensions: Default::default(),
 		}
 	}
@@ -95,7 +99,7 @@ impl Pipeline for SvgPipeline
 	{
 		let url = resourceUrl.replaceFileNameExtension(".svg").url(languageData)?;
 
-		let svgString = self.input_format.svgString(inputContentFilePath, resourceUrl, configuration)?;
+		let svgString = self.input_format.svgString(inputContentFilePath, resourceUrl, configuration, languageData.iso639Dash1Alpha2Language)?;
 
 		let document = Self::parseSvg(&svgString)?;
 
@@ -107,10 +111,21 @@ impl Pipeline for SvgPipeline
 
 		self.primaryImageDimensions.set((width, height));
 
+		let rasterizedPngs = self.rasterizedPngs(&svgString, inputContentFilePath, resourceUrl, headerGenerator, languageData)?;
+		
 		let body = match self.optimize
 		{
-			None => svgString.into_bytes(),
-			Some(ref cleaningSettings) => Self::clean(document, svgString, cleaningSettings)?,
+			None => if self.addAccessibleText(&document, resourceUrl, languageData, configuration)?
+			{
+				let mut buffer = Vec::with_capacity(svgString.len());
+				::svgcleaner::cleaner::write_buffer(&document, &::svgdom::WriteOptions::default(), &mut buffer);
+				buffer
+			}
+			else
+			{
+				svgString.into_bytes()
+			},
+			Some(ref cleaningSettings) => self.clean(document, svgString, cleaningSettings, resourceUrl, languageData, configuration)?,
 		};
 
 		let urlDataDetails = Rc::new
@@ -120,6 +135,7 @@ impl Pipeline for SvgPipeline
 				width,
 				height,
 				size: body.len() as u64,
+				placeholder: None,
 			}
 		);
 
@@ -135,12 +151,184 @@ impl Pipeline for SvgPipeline
 			width_height_image(width, height) => urlDataDetails.clone(),
 		};
 		
-		Ok(vec![(url, tags, StatusCode::Ok, content_type_image_svg_xml_utf8(), headers, ResponseBody::utf8(body), None, CanBeCompressed)])
+		let mut result = Vec::with_capacity(1 + rasterizedPngs.len());
+		result.push((url, tags, StatusCode::Ok, content_type_image_svg_xml_utf8(), headers, ResponseBody::utf8(body), None, CanBeCompressed));
+		
+		result.extend(rasterizedPngs);
+		
+		Ok(result)
 	}
 }
 
 impl SvgPipeline
 {
+	#[inline(always)]
+	pub(crate) fn rasterizedImage(&self, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<::image::DynamicImage, CordialError>
+	{
+		let svgString = self.input_format.svgString(inputContentFilePath, resourceUrl, configuration, iso639Dash1Alpha2Language)?;
+		
+		Self::rasterize(&svgString, ::resvg::FitTo::Original, inputContentFilePath)
+	}
+	
+	/// This SVG's `viewBox` and children, for a `<symbol>` in a SVG sprite; ids within it are prefixed with `symbolId` so that they are unique within the sprite.
+	/// The `viewBox` is preserved, or, if absent, created from the `width` and `height`.
+	pub(crate) fn symbol(&self, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, symbolId: &str) -> Result<(String, String), CordialError>
+	{
+		let svgString = self.input_format.svgString(inputContentFilePath, resourceUrl, configuration, iso639Dash1Alpha2Language)?;
+		
+		let document = Self::parseSvg(&svgString)?;
+		
+		if let Some(ref cleaningSettings) = self.optimize
+		{
+			if let Err(error) = ::svgcleaner::cleaner::clean_doc(&document, &cleaningSettings.toSvgCleanOptions(), &::svgdom::WriteOptions::default())
+			{
+				return Err(CordialError::CouldNotCleanSvg(error));
+			}
+		}
+		
+		// svgdom holds references as links to nodes, so they are renamed too.
+		for node in document.descendants()
+		{
+			if node.has_id()
+			{
+				let prefixedId = format!("{}-{}", symbolId, node.id());
+				node.set_id(prefixedId);
+			}
+		}
+		
+		let width = Self::svgDimensionInPixels(&document, "width");
+		let height = Self::svgDimensionInPixels(&document, "height");
+		
+		let mut buffer = Vec::with_capacity(svgString.len());
+		::svgcleaner::cleaner::write_buffer(&document, &::svgdom::WriteOptions::default(), &mut buffer);
+		let written = String::from_utf8(buffer).expect("svgdom always writes UTF-8");
+		
+		let couldNotFindRoot = || CordialError::Configuration(format!("SVG '{}' has no root svg element for a symbol", resourceUrl));
+		
+		let rootStartTagIndex = written.find("<svg").ok_or_else(&couldNotFindRoot)?;
+		let rootStartTagLength = written[rootStartTagIndex..].find('>').ok_or_else(&couldNotFindRoot)?;
+		let rootStartTag = &written[rootStartTagIndex .. rootStartTagIndex + rootStartTagLength];
+		
+		let children = if rootStartTag.ends_with('/')
+		{
+			""
+		}
+		else
+		{
+			let rootEndTagIndex = written.rfind("</svg>").ok_or_else(&couldNotFindRoot)?;
+			&written[rootStartTagIndex + rootStartTagLength + 1 .. rootEndTagIndex]
+		};
+		
+		const ViewBoxAttribute: &'static str = "viewBox=\"";
+		let viewBox = match rootStartTag.find(ViewBoxAttribute)
+		{
+			Some(index) =>
+			{
+				let value = &rootStartTag[index + ViewBoxAttribute.len() ..];
+				value[.. value.find('"').unwrap_or(value.len())].to_owned()
+			}
+			None => match (width, height)
+			{
+				(Some(width), Some(height)) => format!("0 0 {} {}", width, height),
+				_ => return Err(CordialError::Configuration(format!("SVG '{}' needs either a viewBox or both a width and height to be a symbol", resourceUrl))),
+			}
+		};
+		
+		Ok((viewBox, children.to_owned()))
+	}
+	
+	fn rasterizedPngs(&self, svgString: &str, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		let mut widths = HashSet::with_capacity(self.rasterize_to_png_widths.len());
+		let mut result = Vec::with_capacity(self.rasterize_to_png_widths.len());
+		for width in self.rasterize_to_png_widths.iter()
+		{
+			let width = *width;
+			if width == 0
+			{
+				return Err(CordialError::Configuration("rasterize_to_png_widths can not contain a zero width".to_owned()));
+			}
+			if !widths.insert(width)
+			{
+				return Err(CordialError::Configuration(format!("rasterize_to_png_widths contains the width '{}' more than once", width)));
+			}
+			
+			let image = Self::rasterize(svgString, ::resvg::FitTo::Width(width), inputContentFilePath)?;
+			let (width, height) = image.dimensions();
+			
+			let url = ResourceUrl::widthUrl(resourceUrl.withoutFileNameExtension(), ".png", languageData, width)?;
+			
+			let body = ImageSourceSet::optimizePngImage(&image, inputContentFilePath)?;
+			
+			const CanNotBeCompressed: bool = false;
+			let headers = headerGenerator.generateHeadersForAsset(CanNotBeCompressed, self.max_age_in_seconds, self.is_downloadable, &url)?;
+			
+			let urlDataDetails = Rc::new
+			(
+				UrlDataDetails::Image
+				{
+					width,
+					height,
+					size: body.len() as u64,
+					placeholder: None,
+				}
+			);
+			
+			let tags = hashmap!
+			{
+				png_width_height_image(width, height) => urlDataDetails,
+			};
+			
+			result.push((url, tags, StatusCode::Ok, content_type_image_png(), headers, ResponseBody::binary(body), None, CanNotBeCompressed));
+		}
+		Ok(result)
+	}
+	
+	/// Uses resvg's cairo backend.
+	pub(crate) fn rasterize(svgString: &str, fitTo: ::resvg::FitTo, context: &Path) -> Result<::image::DynamicImage, CordialError>
+	{
+		let options = ::resvg::Options
+		{
+			usvg: ::resvg::usvg::Options
+			{
+				path: Some(context.to_path_buf()),
+				.. Default::default()
+			},
+			fit_to: fitTo,
+			.. Default::default()
+		};
+		
+		let tree = match ::resvg::usvg::Tree::from_data(svgString, &options.usvg)
+		{
+			Err(error) => return Err(CordialError::CouldNotRasterizeSvg(context.to_path_buf(), format!("{:?}", error))),
+			Ok(tree) => tree,
+		};
+		
+		let imageSurface = match ::resvg::backend_cairo::render_to_image(&tree, &options)
+		{
+			None => return Err(CordialError::CouldNotRasterizeSvg(context.to_path_buf(), "the backend could not render an image".to_owned())),
+			Some(imageSurface) => imageSurface,
+		};
+		
+		let mut pngBytes = Vec::new();
+		if let Err(error) = imageSurface.write_to_png(&mut pngBytes)
+		{
+			return Err(CordialError::CouldNotRasterizeSvg(context.to_path_buf(), format!("the rendered image could not be encoded as PNG ({:?})", error)));
+		}
+		
+		let mut temporaryFile = Temp::new_file().context(context)?;
+		let temporaryFilePath = temporaryFile.to_path_buf();
+		let rasterizedImage =
+		{
+			temporaryFilePath.createFileWithByteContents(&pngBytes).context(&temporaryFilePath)?;
+			temporaryFilePath.fileContentsAsImage(ImageInputFormat::PNG)?
+		};
+		temporaryFilePath.deleteOverridingPermissions().context(&temporaryFilePath)?;
+		temporaryFile.release();
+		
+		Ok(rasterizedImage)
+	}
+	
 	#[inline(always)]
 	fn parseSvg(svgString: &str) -> Result<::svgdom::Document, CordialError>
 	{
@@ -162,7 +350,7 @@ impl SvgPipeline
 		}
 	}
 
-	fn clean(document: ::svgdom::Document, svgString: String, cleaningSettings: &CleaningSettings) -> Result<Vec<u8>, CordialError>
+	fn clean(&self, document: ::svgdom::Document, svgString: String, cleaningSettings: &CleaningSettings, resourceUrl: &ResourceUrl, languageData: &LanguageData, configuration: &Configuration) -> Result<Vec<u8>, CordialError>
 	{
 		use ::svgcleaner::cleaner::clean_doc as svgDocumentCleaner;
 		use ::svgcleaner::cleaner::write_buffer;
@@ -191,12 +379,15 @@ impl SvgPipeline
 		{
 			return Err(CordialError::CouldNotCleanSvg(error));
 		}
+		
+		// Added after cleaning so that svgcleaner does not remove the title, desc or their (otherwise unreferenced) ids.
+		let hasAccessibleText = self.addAccessibleText(&document, resourceUrl, languageData, configuration)?;
 
 		let mut buffer = Vec::with_capacity(svgString.len());
 		write_buffer(&document, &MinifyingWriteOptions, &mut buffer);
 
-		// Write out the smaller of the original or cleaned
-		let result = if buffer.len() > svgString.len()
+		// Write out the smaller of the original or cleaned, unless only the cleaned has accessible text
+		let result = if buffer.len() > svgString.len() && !hasAccessibleText
 		{
 			svgString.as_bytes().to_owned()
 		}
@@ -208,6 +399,125 @@ impl SvgPipeline
 		Ok(result)
 	}
 
+	/// Adds `<title>` and `<desc>` from the image abstract (its `title` and `alt`), and `role`, `aria-labelledby` and `xml:lang` attributes to the root, replacing any existing `<title>` and `<desc>`.
+	/// If not language aware, every language's `<title>` and `<desc>` is added inside a `<switch>`, each language in a `<g>` with its own `systemLanguage` and `xml:lang` and the fallback language last without `systemLanguage`, so that user agents pick the user's language; `aria-labelledby` then names the fallback language's `<title>` and `<desc>`.
+	/// Ids are derived from the resource URL and language so that they remain unique when several SVGs are inlined into one page.
+	/// Returns false if there are no abstracts.
+	fn addAccessibleText(&self, document: &::svgdom::Document, resourceUrl: &ResourceUrl, languageData: &LanguageData, configuration: &Configuration) -> Result<bool, CordialError>
+	{
+		use ::svgdom::ElementId;
+		
+		if self.metadata.abstracts.is_empty()
+		{
+			return Ok(false);
+		}
+		
+		let fallbackIso639Dash1Alpha2Language = configuration.fallbackIso639Dash1Alpha2Language();
+		
+		let root = document.root();
+		
+		let existingTitlesAndDescriptions: Vec<::svgdom::Node> = root.children().filter(|node| node.is_tag_name(ElementId::Title) || node.is_tag_name(ElementId::Desc)).collect();
+		for node in existingTitlesAndDescriptions
+		{
+			node.detach();
+		}
+		
+		let idPrefix: String = resourceUrl.to_string().chars().map(|character| match character { 'a' ... 'z' | 'A' ... 'Z' | '0' ... '9' => character, _ => '-' }).collect();
+		
+		if self.language_aware
+		{
+			let iso639Dash1Alpha2Language = languageData.iso639Dash1Alpha2Language;
+			let imageAbstract = self.metadata.imageAbstractWithFallback(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?;
+			
+			let code = iso639Dash1Alpha2Language.to_iso_639_1_alpha_2_language_code();
+			let (nodes, labelledBy) = Self::titleAndDescription(document, imageAbstract, &idPrefix, code);
+			for node in nodes.iter().rev()
+			{
+				root.prepend(node);
+			}
+			
+			root.set_attribute("xml:lang", code);
+			if !labelledBy.is_empty()
+			{
+				root.set_attribute("aria-labelledby", labelledBy);
+			}
+		}
+		else
+		{
+			let mut languages: Vec<(&'static str, &Rc<ImageAbstract>)> = self.metadata.abstracts.iter().filter(|&(iso639Dash1Alpha2Language, _)| *iso639Dash1Alpha2Language != fallbackIso639Dash1Alpha2Language).map(|(iso639Dash1Alpha2Language, imageAbstract)| (iso639Dash1Alpha2Language.to_iso_639_1_alpha_2_language_code(), imageAbstract)).collect();
+			languages.sort_by_key(|&(code, _)| code);
+			
+			let switch = document.create_element(ElementId::Switch);
+			for &(code, imageAbstract) in languages.iter()
+			{
+				let (nodes, _) = Self::titleAndDescription(document, imageAbstract, &idPrefix, code);
+				let group = Self::languageGroup(document, nodes, code);
+				group.set_attribute("systemLanguage", code);
+				switch.append(&group);
+			}
+			
+			// Last and without `systemLanguage`, so that it is chosen when no other language matches.
+			let fallbackCode = fallbackIso639Dash1Alpha2Language.to_iso_639_1_alpha_2_language_code();
+			let (nodes, labelledBy) = Self::titleAndDescription(document, self.metadata.imageAbstract(fallbackIso639Dash1Alpha2Language)?, &idPrefix, fallbackCode);
+			switch.append(&Self::languageGroup(document, nodes, fallbackCode));
+			
+			root.prepend(&switch);
+			
+			root.set_attribute("xml:lang", fallbackCode);
+			if labelledBy.is_empty()
+			{
+				root.remove_attribute("aria-labelledby");
+			}
+			else
+			{
+				root.set_attribute("aria-labelledby", labelledBy);
+			}
+		}
+		
+		root.set_attribute("role", "img");
+		
+		Ok(true)
+	}
+	
+	fn languageGroup(document: &::svgdom::Document, nodes: Vec<::svgdom::Node>, code: &str) -> ::svgdom::Node
+	{
+		let group = document.create_element(::svgdom::ElementId::G);
+		group.set_attribute("xml:lang", code);
+		for node in nodes.iter()
+		{
+			group.append(node);
+		}
+		group
+	}
+	
+	/// Returns the nodes in document order and the value for `aria-labelledby`.
+	fn titleAndDescription(document: &::svgdom::Document, imageAbstract: &ImageAbstract, idPrefix: &str, code: &str) -> (Vec<::svgdom::Node>, String)
+	{
+		use ::svgdom::ElementId;
+		use ::svgdom::NodeType;
+		
+		let mut nodes = Vec::with_capacity(2);
+		let mut ids = Vec::with_capacity(2);
+		
+		for &(elementId, kind, text) in [(ElementId::Title, "title", &imageAbstract.title), (ElementId::Desc, "desc", &imageAbstract.alt)].iter()
+		{
+			if text.is_empty()
+			{
+				continue;
+			}
+			
+			let id = format!("{}-{}-{}", idPrefix, kind, code);
+			let element = document.create_element(elementId);
+			element.set_id(id.clone());
+			element.append(&document.create_node(NodeType::Text, text));
+			
+			nodes.push(element);
+			ids.push(id);
+		}
+		
+		(nodes, ids.join(" "))
+	}
+
 	fn svgDimensionInPixels(document: &::svgdom::Document, attributeName: &str) -> Option<u32>
 	{
 		use ::svgdom::AttributeValue;
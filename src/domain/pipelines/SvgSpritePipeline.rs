@@ -0,0 +1,220 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct SvgSpritePipeline
+{
+	#[serde(default = "max_age_in_seconds_long_default")] max_age_in_seconds: u32,
+	#[serde(default = "is_downloadable_false_default")] is_downloadable: bool,
+	#[serde(default = "is_versioned_true_default")] is_versioned: bool,
+	#[serde(default)] language_aware: bool,
+	#[serde(default)] input_format: Option<SvgSpriteInputFormat>,
+
+	#[serde(default)] symbols: OrderMap<String, ResourceUrl>,
+	#[serde(default)] folder: Option<ResourceUrl>,
+}
+
+impl Default for SvgSpritePipeline
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			max_age_in_seconds: max_age_in_seconds_long_default(),
+			is_downloadable: is_downloadable_false_default(),
+			is_versioned: is_versioned_true_default(),
+			language_aware: false,
+			input_format: None,
+			symbols: Default::default(),
+			folder: None,
+		}
+	}
+}
+
+impl Pipeline for SvgSpritePipeline
+{
+	#[inline(always)]
+	fn processingPriority(&self) -> ProcessingPriority
+	{
+		CompositesEgCssSpriteImages
+	}
+
+	#[inline(always)]
+	fn resourceInputContentFileNamesWithExtension(&self, resourceInputName: &str) -> Vec<String>
+	{
+		self.input_format.resourceInputContentFileNamesWithExtension(resourceInputName)
+	}
+
+	/// A base SVG, into which the symbols are inserted, is optional.
+	#[inline(always)]
+	fn requiresInputContentFile(&self) -> bool
+	{
+		false
+	}
+	
+	#[inline(always)]
+	fn is<'a>(&self) -> (bool, bool)
+	{
+		(self.is_versioned, self.language_aware)
+	}
+
+	#[inline(always)]
+	fn execute(&self, resources: &Resources, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		self.sprite(resources, Some(inputContentFilePath), resourceUrl, headerGenerator, languageData, configuration)
+	}
+	
+	#[inline(always)]
+	fn executeWithoutInputContentFile(&self, resources: &Resources, resourceUrl: &ResourceUrl, _handlebars: &HandlebarsWrapper, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration, _rssChannelsToRssItems: &mut HashMap<Rc<RssChannelName>, Vec<RssItem>>, _siteMapWebPages: &mut Vec<SiteMapWebPage>) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		self.sprite(resources, None, resourceUrl, headerGenerator, languageData, configuration)
+	}
+}
+
+impl SvgSpritePipeline
+{
+	fn sprite(&self, resources: &Resources, baseSvgFilePath: Option<&Path>, resourceUrl: &ResourceUrl, headerGenerator: &mut HeaderGenerator, languageData: &LanguageData, configuration: &Configuration) -> Result<Vec<PipelineResponse>, CordialError>
+	{
+		let url = resourceUrl.replaceFileNameExtension(".svg").url(languageData)?;
+		
+		let mut symbols = String::new();
+		for (symbolId, svgResourceUrl) in self.symbolResourceUrls(resources, resourceUrl)?.iter()
+		{
+			let resource = svgResourceUrl.resourceMandatory(resources)?;
+			let (viewBox, children) = resource.svgSymbol(svgResourceUrl, configuration, languageData.iso639Dash1Alpha2Language, symbolId)?;
+			symbols.push_str(&format!("<symbol id=\"{}\" viewBox=\"{}\">{}</symbol>", symbolId, viewBox, children));
+		}
+		
+		let baseSvg = match baseSvgFilePath
+		{
+			Some(baseSvgFilePath) => baseSvgFilePath.fileContentsAsString().context(baseSvgFilePath)?,
+			None => "<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>".to_owned(),
+		};
+		let body = match baseSvg.rfind("</svg>")
+		{
+			None => return Err(CordialError::Configuration(format!("SVG sprite '{}' does not have a closing </svg> tag to insert symbols before", resourceUrl))),
+			Some(index) =>
+			{
+				let mut body = String::with_capacity(baseSvg.len() + symbols.len());
+				body.push_str(&baseSvg[.. index]);
+				body.push_str(&symbols);
+				body.push_str(&baseSvg[index ..]);
+				body
+			}
+		};
+		
+		const CanBeCompressed: bool = true;
+		let headers = headerGenerator.generateHeadersForAsset(CanBeCompressed, self.max_age_in_seconds, self.is_downloadable, &url)?;
+		
+		let tags = hashmap!
+		{
+			default => Rc::new(UrlDataDetails::generic(body.as_bytes())),
+		};
+		
+		Ok(vec![(url, tags, StatusCode::Ok, content_type_image_svg_xml_utf8(), headers, ResponseBody::utf8(body.into_bytes()), None, CanBeCompressed)])
+	}
+	
+	/// A `<svg>` which `<use>`s a symbol in a sprite; it is hidden from assistive technologies unless it has a title.
+	/// `xlink:href` is included for browsers which do not yet support `href` on `<use>`, such as Safari.
+	pub(crate) fn useNode(spriteUrl: &str, symbolId: &str, class: Option<&str>, title: Option<&str>) -> UnattachedNode
+	{
+		let href = format!("{}#{}", spriteUrl, symbolId);
+		
+		Self::svgNode(class, title).with_child_element("use".with_attributes(vec!["href".string_attribute(href.clone()), "xlink:href".string_attribute(href)]))
+	}
+	
+	/// For AMP, which forbids `<use>` of another document, the symbol inlined as a `<svg>`.
+	pub(crate) fn inlineSymbolHtml(&self, resources: &Resources, spriteResourceUrl: &ResourceUrl, symbolId: &str, class: Option<&str>, title: Option<&str>, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<String, CordialError>
+	{
+		let symbolResourceUrls = self.symbolResourceUrls(resources, spriteResourceUrl)?;
+		let svgResourceUrl = match symbolResourceUrls.get(symbolId)
+		{
+			None => return Err(CordialError::Configuration(format!("SVG sprite '{}' does not have a symbol with the id '{}'", spriteResourceUrl, symbolId))),
+			Some(svgResourceUrl) => svgResourceUrl,
+		};
+		
+		let resource = svgResourceUrl.resourceMandatory(resources)?;
+		let (viewBox, children) = resource.svgSymbol(svgResourceUrl, configuration, iso639Dash1Alpha2Language, symbolId)?;
+		
+		let svgHtml = Self::svgNode(class, title).with_attribute("viewBox".string_attribute(viewBox)).to_html_fragment();
+		let index = svgHtml.rfind("</svg>").expect("a svg node always has an end tag");
+		
+		let mut inlineSymbolHtml = String::with_capacity(svgHtml.len() + children.len());
+		inlineSymbolHtml.push_str(&svgHtml[.. index]);
+		inlineSymbolHtml.push_str(&children);
+		inlineSymbolHtml.push_str(&svgHtml[index ..]);
+		Ok(inlineSymbolHtml)
+	}
+	
+	#[inline(always)]
+	fn svgNode(class: Option<&str>, title: Option<&str>) -> UnattachedNode
+	{
+		let svgNode = "svg".with_attributes(vec!["focusable".str_attribute("false")]);
+		
+		let svgNode = match class
+		{
+			None => svgNode,
+			Some(class) => svgNode.with_class(class),
+		};
+		
+		match title
+		{
+			None => svgNode.with_attribute("aria-hidden".str_attribute("true")),
+			Some(title) => svgNode.with_attribute("role".str_attribute("img")).with_child_element("title".with_child_text(title)),
+		}
+	}
+	
+	/// Symbols are those listed explicitly followed by every svg resource in `folder` (named by their file stem) not already listed.
+	fn symbolResourceUrls(&self, resources: &Resources, resourceUrl: &ResourceUrl) -> Result<OrderMap<String, ResourceUrl>, CordialError>
+	{
+		let mut symbols = self.symbols.clone();
+		
+		if let Some(ref folder) = self.folder
+		{
+			let prefix = if folder.ends_with('/')
+			{
+				folder.to_string()
+			}
+			else
+			{
+				format!("{}/", folder)
+			};
+			
+			for (svgResourceUrl, resource) in resources.iter()
+			{
+				if !svgResourceUrl.starts_with(&prefix) || svgResourceUrl.deref() == resourceUrl.deref()
+				{
+					continue;
+				}
+				
+				let isSvg = match resource.try_borrow()
+				{
+					Err(_) => false,
+					Ok(resource) => resource.isSvg(),
+				};
+				
+				if !isSvg || symbols.values().any(|listedResourceUrl| listedResourceUrl.deref() == svgResourceUrl.deref())
+				{
+					continue;
+				}
+				
+				let symbolId = match Path::new(svgResourceUrl.deref()).file_stem().and_then(|fileStem| fileStem.to_str())
+				{
+					None => return Err(CordialError::Configuration(format!("SVG resource URL '{}' does not have a file name to use as a symbol id", svgResourceUrl))),
+					Some(fileStem) => fileStem.to_owned(),
+				};
+				
+				if symbols.contains_key(&symbolId)
+				{
+					return Err(CordialError::Configuration(format!("SVG sprite '{}' has more than one symbol with the id '{}'", resourceUrl, symbolId)));
+				}
+				symbols.insert(symbolId, svgResourceUrl.clone());
+			}
+		}
+		
+		Ok(symbols)
+	}
+}
@@ -17,6 +17,8 @@ pub(crate) struct VideoPipeline
 	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) durationInSeconds: Cell<u64>,
 	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) mp4Url: RefCell<Option<Url>>,
 	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) webmUrl: RefCell<Option<Url>>,
+	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) mp4ContentType: RefCell<Option<ContentType>>,
+	#[serde(default, skip_deserializing, skip_serializing)] pub(crate) webmContentType: RefCell<Option<ContentType>>,
 }
 
 impl Default for VideoPipeline
@@ -37,6 +39,8 @@ impl Default for VideoPipeline
 			durationInSeconds: Default::default(),
 			mp4Url: Default::default(),
 			webmUrl: Default::default(),
+			mp4ContentType: Default::default(),
+			webmContentType: Default::default(),
 		}
 	}
 }
@@ -78,13 +82,25 @@ impl Pipeline for VideoPipeline
 			
 			let mp4Body = inputContentFilePath.fileContentsAsBytes().context(inputContentFilePath)?;
 			
-			let (width, height, durationInSeconds) = videoTrackDurationWidthAndHeight(&mp4Body)?;
+			let (width, height, durationInSeconds, mp4VideoCodec, mp4AudioCodec) = videoTrackDurationWidthHeightAndCodecs(&mp4Body)?;
 			self.dimensions.set((width, height));
 			self.durationInSeconds.set(durationInSeconds);
 			
+			let mp4ContentType = content_type_video_mp4(mp4VideoCodec, mp4AudioCodec);
+			*self.mp4ContentType.borrow_mut() = Some(mp4ContentType.clone());
+			
+			let webmInputContentFilePath = inputContentFilePath.with_extension("webm");
+			let webmBody = webmInputContentFilePath.fileContentsAsBytes().context(&webmInputContentFilePath)?;
+			let webm = WebM::parse(&webmBody).context(webmInputContentFilePath.as_path())?;
+			Self::validateWebMMatchesMp4(&webm, width, height, durationInSeconds, mp4AudioCodec.is_some(), &webmInputContentFilePath)?;
+			
+			let (webmVideoCodec, webmAudioCodec) = Self::webmVideoAndAudioCodecs(&webm, &webmInputContentFilePath)?;
+			let webmContentType = content_type_video_webm(webmVideoCodec, webmAudioCodec);
+			*self.webmContentType.borrow_mut() = Some(webmContentType.clone());
+			
 			self.metadata.createWebVttTracks(inputContentFilePath, resourceUrl, configuration, headerGenerator, &mut result, self.max_age_in_seconds)?;
-			self.metadata.createVideoMp4(width, height, durationInSeconds, self.mp4Url.borrow().as_ref().unwrap().clone(), headerGenerator, mp4Body, &mut result, self.max_age_in_seconds)?;
-			self.metadata.createWebm(width, height, durationInSeconds, self.mp4Url.borrow().as_ref().unwrap().clone(), headerGenerator, inputContentFilePath, &mut result, self.max_age_in_seconds)?;
+			self.metadata.createVideoMp4(width, height, durationInSeconds, self.mp4Url.borrow().as_ref().unwrap().clone(), mp4ContentType, headerGenerator, mp4Body, &mut result, self.max_age_in_seconds)?;
+			self.metadata.createWebm(width, height, durationInSeconds, self.webmUrl.borrow().as_ref().unwrap().clone(), webmContentType, headerGenerator, webmBody, &mut result, self.max_age_in_seconds)?;
 			
 			(width, height, durationInSeconds)
 		}
@@ -97,8 +113,10 @@ impl Pipeline for VideoPipeline
 		let videoNode =
 		{
 			let mp4UrlBorrow = self.mp4Url.borrow();
+			let mp4ContentTypeBorrow = self.mp4ContentType.borrow();
 			let webmUrlBorrow = self.webmUrl.borrow();
-			self.metadata.createVideoNode(resources, configuration, languageData, width, height, mp4UrlBorrow.as_ref().unwrap(), &webmUrlBorrow.as_ref().unwrap(), durationInSeconds, self.plays_inline)?
+			let webmContentTypeBorrow = self.webmContentType.borrow();
+			self.metadata.createVideoNode(resources, configuration, languageData, width, height, mp4UrlBorrow.as_ref().unwrap(), mp4ContentTypeBorrow.as_ref().unwrap(), &webmUrlBorrow.as_ref().unwrap(), webmContentTypeBorrow.as_ref().unwrap(), durationInSeconds, self.plays_inline)?
 		};
 		
 		AudioVideoMetaData::createIFramePlayer(resourceUrl, videoNode, width, languageData, headerGenerator, &mut result, self.max_age_in_seconds)?;
@@ -134,7 +152,7 @@ impl VideoPipeline
 	#[inline(always)]
 	pub(crate) fn twitterContentType(&self) -> ContentType
 	{
-		videoMp4TwitterContentType()
+		self.mp4ContentType.borrow().as_ref().unwrap().clone()
 	}
 	
 	#[inline(always)]
@@ -161,18 +179,74 @@ impl VideoPipeline
 		let (width, height) = self.dimensions.get();
 		
 		let mp4UrlBorrow = self.mp4Url.borrow();
+		let mp4ContentTypeBorrow = self.mp4ContentType.borrow();
 		
 		let webmUrlBorrow = self.webmUrl.borrow();
+		let webmContentTypeBorrow = self.webmContentType.borrow();
 		
 		let durationInSeconds = self.durationInSeconds.get();
 		
 		if isForAmp
 		{
-			self.metadata.createAmpVideoNode(resources, configuration, languageData, width, height, &mp4UrlBorrow.as_ref().unwrap(), &webmUrlBorrow.as_ref().unwrap(), durationInSeconds, self.plays_inline)
+			self.metadata.createAmpVideoNode(resources, configuration, languageData, width, height, &mp4UrlBorrow.as_ref().unwrap(), mp4ContentTypeBorrow.as_ref().unwrap(), &webmUrlBorrow.as_ref().unwrap(), webmContentTypeBorrow.as_ref().unwrap(), durationInSeconds, self.plays_inline)
+		}
+		else
+		{
+			self.metadata.createVideoNode(resources, configuration, languageData, width, height, &mp4UrlBorrow.as_ref().unwrap(), mp4ContentTypeBorrow.as_ref().unwrap(), &webmUrlBorrow.as_ref().unwrap(), webmContentTypeBorrow.as_ref().unwrap(), durationInSeconds, self.plays_inline)
+		}
+	}
+	
+	/// Durations are whole seconds, rounded down, so may differ by one.
+	#[inline(always)]
+	fn validateWebMMatchesMp4(webm: &WebM, width: u16, height: u16, durationInSeconds: u64, hasAudio: bool, webmInputContentFilePath: &Path) -> Result<(), CordialError>
+	{
+		if webm.width != width || webm.height != height
+		{
+			return Err(CordialError::Configuration(format!("WebM {:?} is {} x {} but its MP4 is {} x {}", webmInputContentFilePath, webm.width, webm.height, width, height)));
+		}
+		
+		let difference = if webm.durationInSeconds > durationInSeconds
+		{
+			webm.durationInSeconds - durationInSeconds
 		}
 		else
 		{
-			self.metadata.createVideoNode(resources, configuration, languageData, width, height, &mp4UrlBorrow.as_ref().unwrap(), &webmUrlBorrow.as_ref().unwrap(), durationInSeconds, self.plays_inline)
+			durationInSeconds - webm.durationInSeconds
+		};
+		if difference > 1
+		{
+			return Err(CordialError::Configuration(format!("WebM {:?} has a duration of {} seconds but its MP4 has a duration of {} seconds", webmInputContentFilePath, webm.durationInSeconds, durationInSeconds)));
+		}
+		
+		if webm.audioCodecId.is_some() != hasAudio
+		{
+			return Err(CordialError::Configuration(format!("WebM {:?} and its MP4 must either both have or both not have an audio track", webmInputContentFilePath)));
 		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn webmVideoAndAudioCodecs(webm: &WebM, webmInputContentFilePath: &Path) -> Result<(VideoCodec, Option<AudioCodec>), CordialError>
+	{
+		let videoCodec = match webm.videoCodecId.as_str()
+		{
+			"V_VP8" => VideoCodec::vp8,
+			"V_VP9" => VideoCodec::vp9,
+			videoCodecId => return Err(CordialError::Configuration(format!("WebM {:?} has an unsupported video codec '{}'; only VP8 and VP9 are supported", webmInputContentFilePath, videoCodecId))),
+		};
+		
+		let audioCodec = match webm.audioCodecId
+		{
+			None => None,
+			Some(ref audioCodecId) => match audioCodecId.as_str()
+			{
+				"A_VORBIS" => Some(AudioCodec::vorbis),
+				"A_OPUS" => Some(AudioCodec::opus),
+				audioCodecId => return Err(CordialError::Configuration(format!("WebM {:?} has an unsupported audio codec '{}'; only Vorbis and Opus are supported", webmInputContentFilePath, audioCodecId))),
+			},
+		};
+		
+		Ok((videoCodec, audioCodec))
 	}
 }
@@ -15,6 +15,8 @@ pub(crate) struct WebAppManifestPipeline
 	
 	#[serde(default)] pub(crate) abstracts: HashMap<Iso639Dash1Alpha2Language, WebAppManifestAbstract>,
 	#[serde(default)] pub(crate) icons: BTreeSet<WebAppManifestIcon>,
+	/// Adds the icon set's web app manifest and maskable icons to `icons`.
+	#[serde(default)] pub(crate) icon_set: Option<ResourceUrl>,
 	#[serde(default)] pub(crate) screenshots: BTreeSet<WebAppManifestScreenshot>,
 	#[serde(default)] pub(crate) categories: BTreeSet<WebAppManifestCategory>,
 	#[serde(default)] pub(crate) iarc_rating_id: Option<String>,
@@ -44,6 +46,7 @@ impl Default for WebAppManifestPipeline
 		
 			abstracts: Default::default(),
 			icons: Default::default(),
+			icon_set: None,
 			screenshots: Default::default(),
 			categories: Default::default(),
 			iarc_rating_id: None,
@@ -88,11 +91,18 @@ impl Pipeline for WebAppManifestPipeline
 		const CanBeCompressed: bool = true;
 		let headers = headerGenerator.generateHeadersForAsset(CanBeCompressed, self.max_age_in_seconds, self.is_downloadable, &url)?;
 		
+		let iconSetIcons = match self.icon_set
+		{
+			None => Vec::new(),
+			Some(ref iconSetResourceUrl) => iconSetResourceUrl.resourceMandatory(resources)?.iconSetPipeline()?.webAppManifestIcons(iconSetResourceUrl),
+		};
+		
 		let body = WebAppManifestJsonRoot
 		{
 			ourUrlToMinifyAgainst: &url,
 			languageData,
 			webAppManifestPipeline: self,
+			iconSetIcons,
 		}.to_json_bytes(resources, configuration.fallbackIso639Dash1Alpha2Language())?;
 		
 		Ok(vec![(url, hashmap! { default => Rc::new(UrlDataDetails::generic(&body)) }, StatusCode::Ok, content_type_application_manifest_json_utf8(), headers, ResponseBody::utf8(body), None, CanBeCompressed)])
@@ -6,6 +6,7 @@ use super::*;
 use super::svg::*;
 use self::ProcessingPriority::*;
 use self::ResourceTag::*;
+use ::image::GenericImage;
 use ::ordermap::OrderMap;
 use ::serde_json::Map as JsonMap;
 
@@ -16,13 +17,17 @@ include!("CssPipeline.rs");
 include!("GifAnimationPipeline.rs");
 include!("FontPipeline.rs");
 include!("HtmlPipeline.rs");
+include!("IconSetPipeline.rs");
 include!("is_downloadable_false_default.rs");
 include!("is_versioned_true_default.rs");
 include!("max_age_in_seconds_long_default.rs");
 include!("Pipeline.rs");
 include!("PipelineResponse.rs");
 include!("RasterImagePipeline.rs");
+include!("SocialCardPipeline.rs");
+include!("SpritePipeline.rs");
 include!("SvgPipeline.rs");
+include!("SvgSpritePipeline.rs");
 include!("TemplatePipeline.rs");
 include!("WebAppManifestPipeline.rs");
 include!("VideoPipeline.rs");
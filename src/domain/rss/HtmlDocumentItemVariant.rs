@@ -175,7 +175,7 @@ impl HtmlDocumentItemVariant
 						
 						eventWriter.writeTextElement(namespace, &[ "type".xml_str_attribute("plain") ], RssChannel::MediaNamespacePrefix.prefixes_xml_name("description"), largestImageMetaData.alt(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?)?;
 						
-						eventWriter.writeTextElement(namespace, &emptyAttributes, RssChannel::MediaNamespacePrefix.prefixes_xml_name("credit"), &largestImageMetaData.credit)?;
+						eventWriter.writeTextElement(namespace, &emptyAttributes, RssChannel::MediaNamespacePrefix.prefixes_xml_name("credit"), largestImageMetaData.credit(fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language))?;
 						
 						let (licenseUrl, licenseTitle) = largestImageMetaData.licenseUrlAndAnchorTitleAttribute(resources, fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?;
 						let licenseUrl = licenseUrl.as_ref().as_str();
@@ -16,14 +16,14 @@ impl SassFunction for DataUriSassFunction
 	#[inline(always)]
 	fn signature(&self) -> SassFunctionSignature
 	{
-		SassFunctionSignature::from_static_str("embed-data-uri($resourceUrl, $resourceTag)")
+		SassFunctionSignature::from_static_str("embed-data-uri($resourceUrl, $resourceTag, $variant: original)")
 	}
 	
 	fn callback(&mut self, arguments: ListSassValue, _compiler: SassCompiler) -> Result<SassValue, SassValueError>
 	{
-		if arguments.length() != 2
+		if arguments.length() != 3
 		{
-			return SassValueError::function_failed_from_static_str("Must have two arguments, $resourceUrl and $resourceTag");
+			return SassValueError::function_failed_from_static_str("Must have three arguments, $resourceUrl, $resourceTag and $variant");
 		}
 		
 		let zeroth = unsafe { arguments.get_value_unchecked(0) };
@@ -44,6 +44,25 @@ impl SassFunction for DataUriSassFunction
 			Ok(urlData) => urlData,
 		};
 		
+		let second = unsafe { arguments.get_value_unchecked(2) };
+		let variant = second.as_string()?.value();
+		match variant.to_str()?
+		{
+			"original" => (),
+			
+			"placeholder" =>
+			{
+				let dataUri = match urlData.placeholder().and_then(|placeholder| placeholder.dataUri())
+				{
+					None => return SassValueError::function_failed_from_static_str("Resource does not have a placeholder data URI"),
+					Some(dataUri) => dataUri,
+				};
+				return Ok(SassValue::new_unquoted_string(&CString::new(dataUri.as_bytes())?))
+			}
+			
+			_ => return SassValueError::function_failed_from_static_str("$variant must be either 'original' or 'placeholder'"),
+		}
+		
 		let dataUri = match self.newResponses.find(urlData.url().as_ref())
 		{
 			None => return SassValueError::function_failed_from_static_str("Response not yet created (processing priority is set incorrectly)"),
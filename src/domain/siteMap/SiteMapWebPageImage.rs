@@ -7,6 +7,7 @@ pub(crate) struct SiteMapWebPageImage
 {
 	pub(crate) url: ResourceReference,
 	pub(crate) licenseUrl: ResourceReference,
+	pub(crate) externalLicenseUrl: Option<Rc<Url>>,
 	pub(crate) imageAbstract: Rc<ImageAbstract>,
 }
 
@@ -28,7 +29,11 @@ impl SiteMapWebPageImage
 				eventWriter.writePrefixedTextElement(namespace, emptyAttributes, Self::ImageNamespacePrefix, "geo_location", geographicLocation)?;
 			}
 			eventWriter.writePrefixedTextElement(namespace, emptyAttributes, Self::ImageNamespacePrefix, "title", &self.imageAbstract.title)?;
-			eventWriter.writePrefixedTextElement(namespace, emptyAttributes, Self::ImageNamespacePrefix, "license_url", self.licenseUrl.urlMandatory(resources, fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?.as_str())
+			match self.externalLicenseUrl
+			{
+				Some(ref externalLicenseUrl) => eventWriter.writePrefixedTextElement(namespace, emptyAttributes, Self::ImageNamespacePrefix, "license_url", externalLicenseUrl.as_str()),
+				None => eventWriter.writePrefixedTextElement(namespace, emptyAttributes, Self::ImageNamespacePrefix, "license_url", self.licenseUrl.urlMandatory(resources, fallbackIso639Dash1Alpha2Language, iso639Dash1Alpha2Language)?.as_str()),
+			}
 		})
 	}
 }
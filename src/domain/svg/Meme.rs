@@ -15,12 +15,18 @@ impl Meme
 	#[inline(always)]
 	pub(crate) fn svgString(&self, inputContentFilePath: &Path) -> Result<String, CordialError>
 	{
-		use ::memenhancer::to_svg;
-		
 		let content = inputContentFilePath.fileContentsAsString().context(inputContentFilePath)?;
 		
-		let svgDocument = to_svg(&content, self.text_width, self.text_height);
-		Ok(format!("{}", svgDocument))
+		Ok(self.svgStringFromStr(&content))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn svgStringFromStr(&self, content: &str) -> String
+	{
+		use ::memenhancer::to_svg;
+		
+		let svgDocument = to_svg(content, self.text_width, self.text_height);
+		format!("{}", svgDocument)
 	}
 	
 	#[inline(always)]
@@ -41,6 +41,15 @@ impl MonArtist
 {
 	#[inline(always)]
 	pub(crate) fn svgString(&self, inputContentFilePath: &Path, resourceUrl: &ResourceUrl, configuration: &Configuration) -> Result<String, CordialError>
+	{
+		let content = inputContentFilePath.fileContentsAsString().context(inputContentFilePath)?;
+		
+		self.svgStringFromStr(&content, resourceUrl.withoutFileNameExtension(), configuration)
+	}
+	
+	/// `defaultName` is used if no `name` is configured.
+	#[inline(always)]
+	pub(crate) fn svgStringFromStr(&self, content: &str, defaultName: &str, configuration: &Configuration) -> Result<String, CordialError>
 	{
 		let table = match self.table
 		{
@@ -48,8 +57,6 @@ impl MonArtist
 			Some(ref tableName) => Self::findTable(configuration, tableName)?,
 		};
 		
-		let content = inputContentFilePath.fileContentsAsString().context(inputContentFilePath)?;
-		
 		let scene = content.parse::<Grid>()?.into_scene(&table, Some(SceneOpts { text_infer_id: self.text_infer_id, .. Default::default()}));
 		
 		let svgRender = SvgRender
@@ -62,7 +69,7 @@ impl MonArtist
 			infer_rect_elements: self.infer_rect_elements,
 			name: match self.name
 			{
-				None => resourceUrl.withoutFileNameExtension().to_owned(),
+				None => defaultName.to_owned(),
 				Some(ref name) => name.as_str().to_owned(),
 			},
 			format_table: table,
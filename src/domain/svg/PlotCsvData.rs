@@ -0,0 +1,105 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+#[derive(Debug, Clone)]
+pub(crate) struct PlotCsvData
+{
+	headers: Vec<String>,
+	records: Vec<Vec<String>>,
+}
+
+impl PlotCsvData
+{
+	/// The first row of the CSV file must be a header row naming the columns.
+	#[inline(always)]
+	pub(crate) fn load(csvFilePath: &Path) -> Result<Self, CordialError>
+	{
+		let couldNotReadCsv = |error: ::csv::Error| CordialError::CouldNotReadCsv(csvFilePath.to_path_buf(), error.to_string());
+		
+		let mut reader = CsvReader::from_file(csvFilePath).map_err(&couldNotReadCsv)?.has_headers(true);
+		
+		let headers = reader.headers().map_err(&couldNotReadCsv)?;
+		
+		let mut records = Vec::new();
+		for record in reader.records()
+		{
+			records.push(record.map_err(&couldNotReadCsv)?);
+		}
+		
+		Ok
+		(
+			Self
+			{
+				headers,
+				records,
+			}
+		)
+	}
+	
+	/// Empty cells are skipped.
+	#[inline(always)]
+	pub(crate) fn column(&self, name: &str) -> Result<Vec<f64>, CordialError>
+	{
+		let index = self.columnIndex(name)?;
+		
+		let mut values = Vec::with_capacity(self.records.len());
+		for record in self.records.iter()
+		{
+			if let Some(value) = Self::cell(record, index, name)?
+			{
+				values.push(value);
+			}
+		}
+		Ok(values)
+	}
+	
+	/// Read in one pass over the rows; a row is skipped if either of its cells is empty, so that x and y values stay paired.
+	#[inline(always)]
+	pub(crate) fn points(&self, xName: &str, yName: &str) -> Result<Vec<(f64, f64)>, CordialError>
+	{
+		let xIndex = self.columnIndex(xName)?;
+		let yIndex = self.columnIndex(yName)?;
+		
+		let mut points = Vec::with_capacity(self.records.len());
+		for record in self.records.iter()
+		{
+			if let (Some(x), Some(y)) = (Self::cell(record, xIndex, xName)?, Self::cell(record, yIndex, yName)?)
+			{
+				points.push((x, y));
+			}
+		}
+		Ok(points)
+	}
+	
+	#[inline(always)]
+	fn columnIndex(&self, name: &str) -> Result<usize, CordialError>
+	{
+		match self.headers.iter().position(|header| header.trim() == name)
+		{
+			None => Err(CordialError::Configuration(format!("Plot CSV data does not have a column '{}'", name))),
+			Some(index) => Ok(index),
+		}
+	}
+	
+	#[inline(always)]
+	fn cell(record: &[String], index: usize, name: &str) -> Result<Option<f64>, CordialError>
+	{
+		let cell = match record.get(index)
+		{
+			None => return Ok(None),
+			Some(cell) => cell.trim(),
+		};
+		
+		if cell.is_empty()
+		{
+			return Ok(None);
+		}
+		
+		match cell.parse::<f64>()
+		{
+			Err(_) => Err(CordialError::Configuration(format!("Plot CSV data column '{}' has a value '{}' which is not a number", name, cell))),
+			Ok(value) => Ok(Some(value)),
+		}
+	}
+}
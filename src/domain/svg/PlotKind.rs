@@ -9,24 +9,91 @@ pub(crate) enum PlotKind
 	Histogram
 	{
 		#[serde(default)] data: Vec<f64>,
+		#[serde(default)] column: Option<String>,
 		#[serde(default = "PlotKind::number_of_bins_default")] number_of_bins: u32,
 	},
 	
 	Scatter
 	{
 		#[serde(default)] data: Vec<(f64, f64)>,
+		#[serde(default)] x_column: Option<String>,
+		#[serde(default)] y_column: Option<String>,
 		#[serde(default)] marker: PlotMarker,
 		#[serde(default)] color: HexadecimalColor,
 	},
+	
+	Line
+	{
+		#[serde(default)] data: Vec<(f64, f64)>,
+		#[serde(default)] x_column: Option<String>,
+		#[serde(default)] y_column: Option<String>,
+		#[serde(default)] color: HexadecimalColor,
+		#[serde(default = "PlotKind::stroke_width_default")] stroke_width: f64,
+	},
+	
+	Bar
+	{
+		#[serde(default)] data: Vec<f64>,
+		#[serde(default)] column: Option<String>,
+		#[serde(default)] color: HexadecimalColor,
+		#[serde(default = "PlotKind::bar_width_default")] bar_width: f64,
+	},
 }
 
 impl PlotKind
 {
+	/// Data is taken from `column` (or `x_column` and `y_column`) of the CSV data file if specified, otherwise from `data`.
+	#[inline(always)]
+	pub(crate) fn values(data: &Vec<f64>, column: &Option<String>, csvData: Option<&PlotCsvData>) -> Result<Vec<f64>, CordialError>
+	{
+		match *column
+		{
+			None => Ok(data.clone()),
+			Some(ref column) => Self::csvData(csvData)?.column(column),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn points(data: &Vec<(f64, f64)>, x_column: &Option<String>, y_column: &Option<String>, csvData: Option<&PlotCsvData>) -> Result<Vec<(f64, f64)>, CordialError>
+	{
+		match (x_column, y_column)
+		{
+			(&None, &None) => Ok(data.clone()),
+			(&Some(ref x_column), &Some(ref y_column)) =>
+			{
+				Self::csvData(csvData)?.points(x_column, y_column)
+			}
+			_ => Err(CordialError::Configuration("Plots need both an x_column and a y_column or neither".to_owned())),
+		}
+	}
+	
+	#[inline(always)]
+	fn csvData(csvData: Option<&PlotCsvData>) -> Result<&PlotCsvData, CordialError>
+	{
+		match csvData
+		{
+			None => Err(CordialError::Configuration("Plots which use a column need a csv_data_file".to_owned())),
+			Some(csvData) => Ok(csvData),
+		}
+	}
+	
 	#[inline(always)]
 	fn number_of_bins_default() -> u32
 	{
 		8
 	}
+	
+	#[inline(always)]
+	fn stroke_width_default() -> f64
+	{
+		2.0
+	}
+	
+	#[inline(always)]
+	fn bar_width_default() -> f64
+	{
+		0.8
+	}
 }
 
 impl Default for PlotKind
@@ -37,6 +104,7 @@ impl Default for PlotKind
 		PlotKind::Histogram
 		{
 			data: Default::default(),
+			column: None,
 			number_of_bins: Self::number_of_bins_default(),
 		}
 	}
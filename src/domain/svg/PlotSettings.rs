@@ -3,13 +3,14 @@
 
 
 #[serde(deny_unknown_fields)]
-#[derive(Serialize, Deserialize, Debug, Clone, PartialOrd, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub(crate) struct PlotSettings
 {
 	#[serde(default)] viewbox_x_minimum: u32,
 	#[serde(default)] viewbox_y_minimum: u32,
 	#[serde(default = "PlotSettings::viewbox_x_maximum_default")] viewbox_x_maximum: u32,
 	#[serde(default = "PlotSettings::viewbox_y_maximum_default")] viewbox_y_maximum: u32,
+	#[serde(default)] csv_data_file: Option<PathBuf>,
 	#[serde(default)] views: Vec<PlotViewSettings>,
 }
 
@@ -24,6 +25,7 @@ impl Default for PlotSettings
 			viewbox_y_minimum: 0,
 			viewbox_x_maximum: Self::viewbox_x_maximum_default(),
 			viewbox_y_maximum: Self::viewbox_y_maximum_default(),
+			csv_data_file: None,
 			views: Default::default(),
 		}
 	}
@@ -32,12 +34,19 @@ impl Default for PlotSettings
 impl PlotSettings
 {
 	#[inline(always)]
-	pub(crate) fn svgString(&self) -> Result<String, CordialError>
+	pub(crate) fn svgString(&self, configuration: &Configuration, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<String, CordialError>
 	{
+		// Relative to the site's input folder, so that the same file can be used by resources and markdown.
+		let csvData = match self.csv_data_file
+		{
+			None => None,
+			Some(ref csvDataFile) => Some(PlotCsvData::load(&configuration.inputFolderPath.join(csvDataFile))?),
+		};
+		
 		let mut document = PlotSvgDocument::new().set("viewBox", (self.viewbox_x_minimum, self.viewbox_y_minimum, self.viewbox_x_maximum, self.viewbox_y_maximum));
 		for view in self.views.iter()
 		{
-			view.render(&mut document)
+			view.render(&mut document, csvData.as_ref(), iso639Dash1Alpha2Language, configuration.fallbackIso639Dash1Alpha2Language())?
 		}
 		Ok(format!("{}", document))
 	}
@@ -3,7 +3,7 @@
 
 
 #[serde(deny_unknown_fields)]
-#[derive(Serialize, Deserialize, Debug, Clone, PartialOrd, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub(crate) struct PlotViewSettings
 {
 	x_minimum: f64,
@@ -18,27 +18,33 @@ pub(crate) struct PlotViewSettings
 	#[serde(default = "PlotViewSettings::translate_x_default")] translate_x: u32,
 	#[serde(default = "PlotViewSettings::translate_y_default")] translate_y: u32,
 	
+	#[serde(default)] x_axis_label: HashMap<Iso639Dash1Alpha2Language, String>,
+	#[serde(default)] y_axis_label: HashMap<Iso639Dash1Alpha2Language, String>,
+	
 	#[serde(default)] plots: Vec<PlotKind>,
 }
 
 impl PlotViewSettings
 {
 	#[inline(always)]
-	fn render(&self, document: &mut PlotSvgDocument)
+	fn render(&self, document: &mut PlotSvgDocument, csvData: Option<&PlotCsvData>, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Result<(), CordialError>
 	{
 		// This ghastly code is because of a really poor API decision in plotlib: View.add(&self, &'a Representation) is not generic.
 		
 		let mut histogramsToMaintainLifetimesHack = Vec::with_capacity(self.plots.len());
 		let mut scattersToMaintainLifetimesHack = Vec::with_capacity(self.plots.len());
-		let mut view = View::new().x_range(self.x_minimum, self.x_maximum).y_range(self.y_minimum, self.y_maximum);
+		let (xMinimum, xMaximum) = Self::range(self.x_minimum, self.x_maximum, "x")?;
+		let (yMinimum, yMaximum) = Self::range(self.y_minimum, self.y_maximum, "y")?;
+		
+		let mut view = View::new().x_range(xMinimum, xMaximum).y_range(yMinimum, yMaximum);
 		
 		for histogramPlotKind in self.plots.iter()
 		{
 			match *histogramPlotKind
 			{
-				PlotKind::Histogram { ref data, number_of_bins } =>
+				PlotKind::Histogram { ref data, ref column, number_of_bins } =>
 				{
-					let histogram = Histogram::from_vec(&data, number_of_bins);
+					let histogram = Histogram::from_vec(&PlotKind::values(data, column, csvData)?, number_of_bins);
 					histogramsToMaintainLifetimesHack.push(histogram);
 				}
 				_ => (),
@@ -53,10 +59,10 @@ impl PlotViewSettings
 		{
 			match *scatterPlotKind
 			{
-				PlotKind::Scatter { ref data, ref marker, ref color } =>
+				PlotKind::Scatter { ref data, ref x_column, ref y_column, ref marker, ref color } =>
 				{
 					let style = Style::new().marker(marker.toMarker()).colour(color.toStringWithHashPrefix());
-					scattersToMaintainLifetimesHack.push(Scatter::from_vec(&data).style(style));
+					scattersToMaintainLifetimesHack.push(Scatter::from_vec(&PlotKind::points(data, x_column, y_column, csvData)?).style(style));
 				}
 				_ => (),
 			};
@@ -66,9 +72,116 @@ impl PlotViewSettings
 			view = view.add(scatter);
 		}
 		
-		let gElement = view.to_svg(self.face_width, self.face_height).set("transform", format!("translate({}, {})", self.translate_x, self.translate_y));
+		let mut gElement = view.to_svg(self.face_width, self.face_height).set("transform", format!("translate({}, {})", self.translate_x, self.translate_y));
+		
+		// plotlib does not support lines or bars, so they are drawn on to its face, which has the origin at the bottom left and y increasing upwards.
+		for plotKind in self.plots.iter()
+		{
+			match *plotKind
+			{
+				PlotKind::Line { ref data, ref x_column, ref y_column, ref color, stroke_width } =>
+				{
+					let mut pathData = PlotSvgPathData::new();
+					let mut isFirst = true;
+					for &(x, y) in PlotKind::points(data, x_column, y_column, csvData)?.iter()
+					{
+						let point = (self.faceX(x, xMinimum, xMaximum), self.faceY(y, yMinimum, yMaximum));
+						pathData = if isFirst
+						{
+							pathData.move_to(point)
+						}
+						else
+						{
+							pathData.line_to(point)
+						};
+						isFirst = false;
+					}
+					
+					gElement = gElement.add(PlotSvgPath::new().set("fill", "none").set("stroke", color.toStringWithHashPrefix()).set("stroke-width", stroke_width).set("d", pathData));
+				}
+				
+				PlotKind::Bar { ref data, ref column, ref color, bar_width } =>
+				{
+					// Bars are at x = 0, 1, 2, etc.
+					let baseline = yMinimum.max(0.0);
+					let mut x = 0.0;
+					for y in PlotKind::values(data, column, csvData)?
+					{
+						let left = self.faceX(x - bar_width / 2.0, xMinimum, xMaximum);
+						let right = self.faceX(x + bar_width / 2.0, xMinimum, xMaximum);
+						let top = self.faceY(y.max(baseline), yMinimum, yMaximum);
+						let bottom = self.faceY(y.min(baseline), yMinimum, yMaximum);
+						
+						gElement = gElement.add(PlotSvgRectangle::new().set("fill", color.toStringWithHashPrefix()).set("x", left).set("y", top).set("width", right - left).set("height", bottom - top));
+						
+						x += 1.0;
+					}
+				}
+				
+				_ => (),
+			}
+		}
+		
+		gElement = self.addAxisLabels(gElement, iso639Dash1Alpha2Language, fallbackIso639Dash1Alpha2Language);
+		
 		use ::svg::Node;
 		document.append(gElement);
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn addAxisLabels(&self, mut gElement: PlotSvgGroup, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> PlotSvgGroup
+	{
+		const LabelOffset: f64 = 45.0;
+		
+		if let Some(label) = Self::label(&self.x_axis_label, iso639Dash1Alpha2Language, fallbackIso639Dash1Alpha2Language)
+		{
+			gElement = gElement.add(PlotSvgText::new().set("x", self.face_width / 2.0).set("y", LabelOffset).set("text-anchor", "middle").add(PlotSvgTextNode::new(label)));
+		}
+		
+		if let Some(label) = Self::label(&self.y_axis_label, iso639Dash1Alpha2Language, fallbackIso639Dash1Alpha2Language)
+		{
+			gElement = gElement.add(PlotSvgText::new().set("x", self.face_height / 2.0).set("y", -LabelOffset).set("text-anchor", "middle").set("transform", "rotate(-90)").add(PlotSvgTextNode::new(label)));
+		}
+		
+		gElement
+	}
+	
+	#[inline(always)]
+	fn label(labels: &HashMap<Iso639Dash1Alpha2Language, String>, iso639Dash1Alpha2Language: Iso639Dash1Alpha2Language, fallbackIso639Dash1Alpha2Language: Iso639Dash1Alpha2Language) -> Option<String>
+	{
+		labels.get(&iso639Dash1Alpha2Language).or_else(|| labels.get(&fallbackIso639Dash1Alpha2Language)).map(|label| label.to_owned())
+	}
+	
+	/// A range with the same minimum and maximum is widened by one either side, so that points can be scaled by it.
+	#[inline(always)]
+	fn range(minimum: f64, maximum: f64, axis: &str) -> Result<(f64, f64), CordialError>
+	{
+		if !minimum.is_finite() || !maximum.is_finite() || maximum < minimum
+		{
+			Err(CordialError::Configuration(format!("Plot {}_minimum '{}' must be finite and no more than {}_maximum '{}'", axis, minimum, axis, maximum)))
+		}
+		else if maximum == minimum
+		{
+			Ok((minimum - 1.0, maximum + 1.0))
+		}
+		else
+		{
+			Ok((minimum, maximum))
+		}
+	}
+	
+	#[inline(always)]
+	fn faceX(&self, x: f64, xMinimum: f64, xMaximum: f64) -> f64
+	{
+		(x - xMinimum) / (xMaximum - xMinimum) * self.face_width
+	}
+	
+	#[inline(always)]
+	fn faceY(&self, y: f64, yMinimum: f64, yMaximum: f64) -> f64
+	{
+		-(y - yMinimum) / (yMaximum - yMinimum) * self.face_height
 	}
 	
 	#[inline(always)]
@@ -3,6 +3,7 @@
 
 
 use super::*;
+use ::csv::Reader as CsvReader;
 use ::mon_artist::render::svg::SvgRender;
 use ::mon_artist::render::RenderS;
 use ::mon_artist::grid::Grid;
@@ -18,6 +19,12 @@ use ::qrcode::render::svg::Color as SvgColor;
 use ::qrcode::types::EcLevel;
 use ::qrcode::types::Version;
 use ::svg::Document as PlotSvgDocument;
+use ::svg::node::Text as PlotSvgTextNode;
+use ::svg::node::element::Group as PlotSvgGroup;
+use ::svg::node::element::Path as PlotSvgPath;
+use ::svg::node::element::Rectangle as PlotSvgRectangle;
+use ::svg::node::element::Text as PlotSvgText;
+use ::svg::node::element::path::Data as PlotSvgPathData;
 use ::svgcleaner::CleaningOptions as SvgCleanOptions;
 
 
@@ -28,6 +35,7 @@ include!("CleaningSettingsPrecision.rs");
 include!("CleaningSettingsStyleJoinMode.rs");
 include!("Meme.rs");
 include!("MonArtist.rs");
+include!("PlotCsvData.rs");
 include!("PlotKind.rs");
 include!("PlotMarker.rs");
 include!("PlotSettings.rs");
@@ -11,6 +11,20 @@ pub(crate) struct WebAppManifestIcon
 	platform: Option<WebAppManifestPlatform>,
 }
 
+impl WebAppManifestIcon
+{
+	#[inline(always)]
+	pub(crate) fn new(icon: ResourceReference, purpose: WebAppManifestIconPurpose) -> Self
+	{
+		Self
+		{
+			icon,
+			purposes: btreeset! { purpose },
+			platform: None,
+		}
+	}
+}
+
 impl Serialize for WebAppManifestIcon
 {
 	#[inline(always)]
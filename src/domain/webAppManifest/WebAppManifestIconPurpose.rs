@@ -8,6 +8,7 @@ pub(crate) enum WebAppManifestIconPurpose
 {
 	badge,
 	any,
+	maskable,
 }
 
 impl Default for WebAppManifestIconPurpose
@@ -30,6 +31,7 @@ impl WebAppManifestIconPurpose
 		{
 			badge => "badge",
 			any => "any",
+			maskable => "maskable",
 		}
 	}
 }
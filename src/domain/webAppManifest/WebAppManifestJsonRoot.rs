@@ -8,6 +8,7 @@ pub(crate) struct WebAppManifestJsonRoot<'a>
 	pub(crate) ourUrlToMinifyAgainst: &'a Url,
 	pub(crate) languageData: &'a LanguageData<'a>,
 	pub(crate) webAppManifestPipeline: &'a WebAppManifestPipeline,
+	pub(crate) iconSetIcons: Vec<WebAppManifestIcon>,
 }
 
 impl<'a> Serialize for WebAppManifestJsonRoot<'a>
@@ -61,7 +62,8 @@ impl<'a> Serialize for WebAppManifestJsonRoot<'a>
 			state.serialize_field("name", &webAppManifestAbstract.name)?;
 			state.serialize_field("short_name", &webAppManifestAbstract.short_name)?;
 			state.serialize_field("description", &webAppManifestAbstract.description)?;
-			state.serialize_field("icons", &self.webAppManifestPipeline.icons)?;
+			let icons: Vec<&WebAppManifestIcon> = self.webAppManifestPipeline.icons.iter().chain(self.iconSetIcons.iter()).collect();
+			state.serialize_field("icons", &icons)?;
 			state.serialize_field("screenshots", &self.webAppManifestPipeline.screenshots)?;
 			state.serialize_field("categories", &self.webAppManifestPipeline.categories)?;
 			if let Some(ref iarc_rating_id) = self.webAppManifestPipeline.iarc_rating_id
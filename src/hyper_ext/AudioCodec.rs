@@ -6,7 +6,10 @@
 pub(crate) enum AudioCodec
 {
 	mp4a_40_2,
+	mp4a_40_5,
+	mp4a_40_29,
 	vorbis,
+	opus,
 }
 
 impl AudioCodec
@@ -17,7 +20,10 @@ impl AudioCodec
 		match *self
 		{
 			mp4a_40_2 => "mp4a.40.2",
+			mp4a_40_5 => "mp4a.40.5",
+			mp4a_40_29 => "mp4a.40.29",
 			vorbis => "vorbis",
+			opus => "opus",
 		}
 	}
 }
@@ -5,19 +5,27 @@
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub(crate) enum VideoCodec
 {
-	avc1_42E01E,
+	/// H.264; the values are those of the `avcC` AVC decoder configuration record, eg `avc1.42E01E` is Constrained Baseline Profile, Level 3.
+	avc1
+	{
+		profile: u8,
+		constraints: u8,
+		level: u8,
+	},
 	vp8,
+	vp9,
 }
 
 impl VideoCodec
 {
 	#[inline(always)]
-	pub(crate) fn to_str(&self) -> &'static str
+	pub(crate) fn to_str(&self) -> Cow<'static, str>
 	{
 		match *self
 		{
-			avc1_42E01E => "avc1.42E01E",
-			vp8 => "vp8",
+			avc1 { profile, constraints, level } => Cow::Owned(format!("avc1.{:02X}{:02X}{:02X}", profile, constraints, level)),
+			vp8 => Cow::Borrowed("vp8"),
+			vp9 => Cow::Borrowed("vp9"),
 		}
 	}
 }
@@ -3,7 +3,7 @@
 
 
 #[inline(always)]
-pub(crate) fn webm8ContentType() -> ContentType
+pub(crate) fn content_type_audio_flac() -> ContentType
 {
-	content_type_video_webm(vp8, vorbis)
+	ContentType(mimeType("audio/flac"))
 }
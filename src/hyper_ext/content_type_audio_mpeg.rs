@@ -3,7 +3,7 @@
 
 
 #[inline(always)]
-pub(crate) fn videoMp4TwitterContentType() -> ContentType
+pub(crate) fn content_type_audio_mpeg() -> ContentType
 {
-	content_type_video_mp4(avc1_42E01E, mp4a_40_2)
+	ContentType(mimeType("audio/mpeg"))
 }
@@ -3,7 +3,12 @@
 
 
 #[inline(always)]
-pub(crate) fn content_type_video_mp4(videoCodec: VideoCodec, audioCodec: AudioCodec) -> ContentType
+pub(crate) fn content_type_video_mp4(videoCodec: VideoCodec, audioCodec: Option<AudioCodec>) -> ContentType
 {
-	ContentType(mimeType(&format!("audio/mp4;codecs=\"{},{}\"", videoCodec.to_str(), audioCodec.to_str())))
+	let mimeTypeString = match audioCodec
+	{
+		None => format!("video/mp4;codecs=\"{}\"", videoCodec.to_str()),
+		Some(audioCodec) => format!("video/mp4;codecs=\"{},{}\"", videoCodec.to_str(), audioCodec.to_str()),
+	};
+	ContentType(mimeType(&mimeTypeString))
 }
@@ -14,15 +14,21 @@ include!("audioMp4TwitterContentType.rs");
 include!("content_type_application_font_sfnt.rs");
 include!("content_type_application_manifest_json_utf8.rs");
 include!("content_type_application_xml_utf8.rs");
+include!("content_type_audio_flac.rs");
 include!("content_type_audio_mp4.rs");
+include!("content_type_audio_mpeg.rs");
+include!("content_type_audio_ogg.rs");
 include!("content_type_font_sfnt.rs");
 include!("content_type_font_ttf.rs");
 include!("content_type_font_woff.rs");
 include!("content_type_font_woff2.rs");
+include!("content_type_image_avif.rs");
 include!("content_type_image_gif.rs");
 include!("content_type_image_jpeg.rs");
 include!("content_type_image_png.rs");
 include!("content_type_image_svg_xml_utf8.rs");
+include!("content_type_image_webp.rs");
+include!("content_type_image_x_icon.rs");
 include!("content_type_text_css_utf8.rs");
 include!("content_type_text_html_utf8.rs");
 include!("content_type_text_plain_utf8.rs");
@@ -32,5 +38,3 @@ include!("content_type_video_mp4.rs");
 include!("content_type_video_webm.rs");
 include!("mimeType.rs");
 include!("VideoCodec.rs");
-include!("videoMp4TwitterContentType.rs");
-include!("webm8ContentType.rs");
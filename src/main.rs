@@ -18,6 +18,7 @@ extern crate brotli2;
 extern crate chardet;
 extern crate chrono;
 extern crate clap;
+extern crate claxon;
 extern crate comrak;
 extern crate css_autoprefix;
 extern crate css_purify;
@@ -26,14 +27,21 @@ extern crate daemonize;
 #[cfg(any(target_os = "android", target_os = "linux"))] extern crate dpdk_unix;
 extern crate either;
 extern crate engiffen;
+extern crate exif;
+extern crate flate2;
 extern crate futures;
 extern crate gif;
 extern crate guetzli_sys;
 extern crate handlebars;
+extern crate hb_subset;
 extern crate hlua;
 extern crate hyper;
 extern crate image;
+extern crate imagequant;
+extern crate imgref;
+extern crate lcms2;
 extern crate libc;
+extern crate libwebp_sys;
 #[macro_use] extern crate log;
 extern crate malloc_buf;
 #[macro_use] extern crate maplit;
@@ -42,20 +50,27 @@ extern crate mktemp;
 extern crate mime_guess;
 extern crate mime_multipart;
 extern crate mon_artist;
+extern crate mozjpeg;
 extern crate mp3_metadata;
 extern crate mp4parse;
 extern crate net2;
 #[cfg(unix)] extern crate nix;
 extern crate num_cpus;
+extern crate ogg;
 extern crate ordermap;
 extern crate oxipng;
 extern crate plotlib;
 extern crate phf;
+extern crate png;
 extern crate qrcode;
 #[macro_use] extern crate quick_error;
 extern crate radix_trie;
+extern crate ravif;
+extern crate resvg;
+extern crate rgb;
 extern crate ring;
 extern crate rustls;
+extern crate rusttype;
 extern crate sass_alt;
 extern crate serde;
 #[macro_use] extern crate serde_derive;
@@ -77,6 +92,7 @@ extern crate unicase;
 extern crate url;
 extern crate url_serde;
 extern crate woff2_sys;
+extern crate wuff;
 extern crate xml;
 extern crate zero85;
 extern crate zopfli;
@@ -107,6 +123,7 @@ use ::rustls::Certificate;
 use ::rustls::PrivateKey;
 use ::serde_json::Value as JsonValue;
 use ::std::borrow::Cow;
+use ::std::cmp::max;
 use ::std::cmp::min;
 use ::std::collections::BTreeMap;
 use ::std::ffi::OsStr;
@@ -144,6 +161,8 @@ pub(crate) mod html5;
 pub(crate) mod hyper_ext;
 pub(crate) mod mp4;
 pub(crate) mod serde_with;
+pub(crate) mod webm;
+pub(crate) mod webp;
 pub(crate) mod webserver;
 pub(crate) mod woff;
 pub(crate) mod xmlExtra;
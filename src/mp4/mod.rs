@@ -3,13 +3,18 @@
 
 
 use super::*;
+use ::mp4parse::AudioCodecSpecific;
+use ::mp4parse::AudioSampleEntry;
+use ::mp4parse::CodecType;
 use ::mp4parse::MediaContext;
 use ::mp4parse::read_mp4;
 use ::mp4parse::SampleEntry;
 use ::mp4parse::Track;
+use ::mp4parse::VideoCodecSpecific;
 use ::mp4parse::VideoSampleEntry;
 use ::std::io::Cursor;
 
 
-include!("videoTrackDurationWidthAndHeight.rs");
 include!("onlyAudioTrackDuration.rs");
+include!("videoAndAudioCodecs.rs");
+include!("videoTrackDurationWidthHeightAndCodecs.rs");
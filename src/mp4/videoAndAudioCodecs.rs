@@ -0,0 +1,77 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// The codecs of the video track and, if present, the audio track, for the `codecs` parameter of a `<source>` type.
+fn videoAndAudioCodecs(context: &MediaContext) -> Result<(VideoCodec, Option<AudioCodec>), CordialError>
+{
+	fn detectVideoCodec(track: &Track, video: &VideoSampleEntry) -> Result<VideoCodec, CordialError>
+	{
+		match track.codec_type
+		{
+			// An AVC decoder configuration record starts with configurationVersion, AVCProfileIndication, profile_compatibility and AVCLevelIndication.
+			CodecType::H264 => match video.codec_specific
+			{
+				VideoCodecSpecific::AVCConfig(ref avcDecoderConfigurationRecord) if avcDecoderConfigurationRecord.len() >= 4 => Ok
+				(
+					VideoCodec::avc1
+					{
+						profile: avcDecoderConfigurationRecord[1],
+						constraints: avcDecoderConfigurationRecord[2],
+						level: avcDecoderConfigurationRecord[3],
+					}
+				),
+				
+				_ => Err(CordialError::Configuration("The H.264 video track in a video/mp4 is missing its AVC decoder configuration record".to_owned())),
+			},
+			
+			CodecType::VP8 => Ok(VideoCodec::vp8),
+			
+			CodecType::VP9 => Ok(VideoCodec::vp9),
+			
+			_ => Err(CordialError::Configuration("The video track in a video/mp4 must be H.264, VP8 or VP9".to_owned())),
+		}
+	}
+	
+	fn detectAudioCodec(track: &Track, audio: &AudioSampleEntry) -> Result<AudioCodec, CordialError>
+	{
+		match track.codec_type
+		{
+			CodecType::AAC => match audio.codec_specific
+			{
+				AudioCodecSpecific::ES_Descriptor(ref esDescriptor) => match esDescriptor.audio_object_type
+				{
+					Some(5) => Ok(AudioCodec::mp4a_40_5),
+					Some(29) => Ok(AudioCodec::mp4a_40_29),
+					_ => Ok(AudioCodec::mp4a_40_2),
+				},
+				
+				_ => Ok(AudioCodec::mp4a_40_2),
+			},
+			
+			CodecType::Opus => Ok(AudioCodec::opus),
+			
+			_ => Err(CordialError::Configuration("The audio track in a video/mp4 must be AAC or Opus".to_owned())),
+		}
+	}
+	
+	let mut videoCodec = None;
+	let mut audioCodec = None;
+	for track in context.tracks.iter()
+	{
+		match track.data
+		{
+			Some(SampleEntry::Video(ref video)) => videoCodec = Some(detectVideoCodec(track, video)?),
+			
+			Some(SampleEntry::Audio(ref audio)) => audioCodec = Some(detectAudioCodec(track, audio)?),
+			
+			_ => return Err(CordialError::Configuration("There must be only audio and video tracks in a video/mp4; strip it".to_owned())),
+		}
+	}
+	
+	match videoCodec
+	{
+		None => Err(CordialError::Configuration("There must be a video track in a video/mp4".to_owned())),
+		Some(videoCodec) => Ok((videoCodec, audioCodec)),
+	}
+}
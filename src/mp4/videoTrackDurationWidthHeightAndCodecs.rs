@@ -2,7 +2,8 @@
 // Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
 
 
-pub(crate) fn videoTrackDurationWidthAndHeight(bytes: &[u8]) -> Result<(u16, u16, u64), CordialError>
+/// Width, height, duration in seconds and codecs, from one parse of the MP4.
+pub(crate) fn videoTrackDurationWidthHeightAndCodecs(bytes: &[u8]) -> Result<(u16, u16, u64, VideoCodec, Option<AudioCodec>), CordialError>
 {
 	fn processVideoTrack(context: &MediaContext, track: &Track, video: &VideoSampleEntry) -> Result<(u16, u16, u64), CordialError>
 	{
@@ -41,23 +42,35 @@ pub(crate) fn videoTrackDurationWidthAndHeight(bytes: &[u8]) -> Result<(u16, u16
 		return Err(CordialError::Configuration("There must be only one or two tracks in a video/mp4; strip it".to_owned()));
 	}
 	
-	let firstTrack = context.tracks.get(0).unwrap();
-	
-	match firstTrack.data
+	let (width, height, durationInSeconds) =
 	{
-		Some(SampleEntry::Video(ref video)) => return processVideoTrack(&context, firstTrack, video),
-		
-		Some(SampleEntry::Audio(_)) => (),
+		let firstTrack = context.tracks.get(0).unwrap();
 		
-		_ => return Err(CordialError::Configuration("There must be only audio and video tracks in a video/mp4; strip it".to_owned()))
-	}
+		match firstTrack.data
+		{
+			Some(SampleEntry::Video(ref video)) => processVideoTrack(&context, firstTrack, video)?,
+			
+			Some(SampleEntry::Audio(_)) =>
+			{
+				let secondTrack = match context.tracks.get(1)
+				{
+					None => return Err(CordialError::Configuration("There must be a video track in a video/mp4".to_owned())),
+					Some(secondTrack) => secondTrack,
+				};
+				
+				match secondTrack.data
+				{
+					Some(SampleEntry::Video(ref video)) => processVideoTrack(&context, secondTrack, video)?,
+					
+					_ => return Err(CordialError::Configuration("There must be only audio and video tracks in a video/mp4; strip it".to_owned()))
+				}
+			}
+			
+			_ => return Err(CordialError::Configuration("There must be only audio and video tracks in a video/mp4; strip it".to_owned()))
+		}
+	};
 	
-	let secondTrack = context.tracks.get(1).unwrap();
+	let (videoCodec, audioCodec) = videoAndAudioCodecs(&context)?;
 	
-	match secondTrack.data
-	{
-		Some(SampleEntry::Video(ref video)) => processVideoTrack(&context, firstTrack, video),
-		
-		_ => Err(CordialError::Configuration("There must be only audio and video tracks in a video/mp4; strip it".to_owned()))
-	}
+	Ok((width, height, durationInSeconds, videoCodec, audioCodec))
 }
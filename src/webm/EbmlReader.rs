@@ -0,0 +1,146 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// A minimal reader of EBML (Extensible Binary Meta Language) elements, the encoding underlying Matroska and WebM.
+#[derive(Debug, Clone)]
+pub(crate) struct EbmlReader<'a>
+{
+	bytes: &'a [u8],
+}
+
+impl<'a> EbmlReader<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(bytes: &'a [u8]) -> Self
+	{
+		Self
+		{
+			bytes,
+		}
+	}
+	
+	/// Returns the next element's identifier (including its length marker bits, as it is written in the Matroska specification) and data.
+	/// Elements of unknown size (eg live-streamed Segments and Clusters) extend to the end of their parent.
+	pub(crate) fn nextElement(&mut self) -> Result<Option<(u32, &'a [u8])>, WebMError>
+	{
+		if self.bytes.is_empty()
+		{
+			return Ok(None);
+		}
+		
+		let (identifier, identifierLength) = Self::identifier(self.bytes)?;
+		let (size, sizeLength) = Self::size(&self.bytes[identifierLength..])?;
+		
+		let dataStartsAt = identifierLength + sizeLength;
+		let remaining = self.bytes.len() - dataStartsAt;
+		let dataLength = match size
+		{
+			None => remaining,
+			Some(size) => if size > remaining as u64
+			{
+				return Err(WebMError::Truncated);
+			}
+			else
+			{
+				size as usize
+			},
+		};
+		
+		let dataEndsAt = dataStartsAt + dataLength;
+		let data = &self.bytes[dataStartsAt .. dataEndsAt];
+		self.bytes = &self.bytes[dataEndsAt..];
+		
+		Ok(Some((identifier, data)))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn unsignedInteger(data: &[u8]) -> Result<u64, WebMError>
+	{
+		if data.len() > 8
+		{
+			return Err(WebMError::InvalidUnsignedInteger);
+		}
+		
+		Ok(data.iter().fold(0, |value, &byte| (value << 8) | byte as u64))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn float(data: &[u8]) -> Result<f64, WebMError>
+	{
+		match data.len()
+		{
+			0 => Ok(0.0),
+			4 => Ok(f32::from_bits(Self::unsignedInteger(data)? as u32) as f64),
+			8 => Ok(f64::from_bits(Self::unsignedInteger(data)?)),
+			_ => Err(WebMError::InvalidFloat),
+		}
+	}
+	
+	/// Strings may be padded with trailing NULs.
+	#[inline(always)]
+	pub(crate) fn string(data: &[u8]) -> String
+	{
+		String::from_utf8_lossy(data).trim_right_matches('\0').to_owned()
+	}
+	
+	#[inline(always)]
+	fn identifier(bytes: &[u8]) -> Result<(u32, usize), WebMError>
+	{
+		let length = Self::variableLengthIntegerLength(bytes)?;
+		if length > 4
+		{
+			return Err(WebMError::InvalidVariableLengthInteger);
+		}
+		
+		let identifier = bytes[.. length].iter().fold(0, |identifier, &byte| (identifier << 8) | byte as u32);
+		Ok((identifier, length))
+	}
+	
+	/// A size with all its value bits set is 'unknown', and returned as `None`.
+	#[inline(always)]
+	fn size(bytes: &[u8]) -> Result<(Option<u64>, usize), WebMError>
+	{
+		let length = Self::variableLengthIntegerLength(bytes)?;
+		
+		let valueBitsOfFirstByte = (0x80u8 >> (length - 1)) - 1;
+		let mut size = (bytes[0] & valueBitsOfFirstByte) as u64;
+		let mut isUnknown = size == valueBitsOfFirstByte as u64;
+		for &byte in bytes[1 .. length].iter()
+		{
+			size = (size << 8) | byte as u64;
+			isUnknown = isUnknown && byte == 0xFF;
+		}
+		
+		if isUnknown
+		{
+			Ok((None, length))
+		}
+		else
+		{
+			Ok((Some(size), length))
+		}
+	}
+	
+	#[inline(always)]
+	fn variableLengthIntegerLength(bytes: &[u8]) -> Result<usize, WebMError>
+	{
+		let firstByte = match bytes.first()
+		{
+			None => return Err(WebMError::Truncated),
+			Some(&firstByte) => firstByte,
+		};
+		
+		if firstByte == 0
+		{
+			return Err(WebMError::InvalidVariableLengthInteger);
+		}
+		
+		let length = firstByte.leading_zeros() as usize + 1;
+		if bytes.len() < length
+		{
+			return Err(WebMError::Truncated);
+		}
+		Ok(length)
+	}
+}
@@ -0,0 +1,242 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+const EbmlId: u32 = 0x1A45DFA3;
+const DocTypeId: u32 = 0x4282;
+const SegmentId: u32 = 0x18538067;
+const InfoId: u32 = 0x1549A966;
+const TimecodeScaleId: u32 = 0x2AD7B1;
+const DurationId: u32 = 0x4489;
+const TracksId: u32 = 0x1654AE6B;
+const TrackEntryId: u32 = 0xAE;
+const TrackTypeId: u32 = 0x83;
+const CodecIdId: u32 = 0x86;
+const VideoId: u32 = 0xE0;
+const PixelWidthId: u32 = 0xB0;
+const PixelHeightId: u32 = 0xBA;
+const ClusterId: u32 = 0x1F43B675;
+
+const VideoTrackType: u64 = 1;
+const AudioTrackType: u64 = 2;
+
+const DefaultTimecodeScaleInNanoseconds: u64 = 1_000_000;
+
+/// Those parts of a WebM's Segment Information and Tracks that need to agree with an equivalent MP4.
+#[derive(Debug, Clone)]
+pub(crate) struct WebM
+{
+	pub(crate) durationInSeconds: u64,
+	pub(crate) width: u16,
+	pub(crate) height: u16,
+	
+	/// eg `V_VP9`.
+	pub(crate) videoCodecId: String,
+	
+	/// eg `A_OPUS`.
+	pub(crate) audioCodecId: Option<String>,
+}
+
+impl WebM
+{
+	pub(crate) fn parse(bytes: &[u8]) -> Result<Self, WebMError>
+	{
+		let mut reader = EbmlReader::new(bytes);
+		
+		match reader.nextElement()?
+		{
+			Some((EbmlId, ebmlHeader)) => Self::validateEbmlHeader(ebmlHeader)?,
+			_ => return Err(WebMError::NotWebM),
+		}
+		
+		while let Some((identifier, data)) = reader.nextElement()?
+		{
+			if identifier == SegmentId
+			{
+				return Self::parseSegment(data);
+			}
+		}
+		
+		Err(WebMError::MissingSegmentInformation)
+	}
+	
+	#[inline(always)]
+	fn validateEbmlHeader(ebmlHeader: &[u8]) -> Result<(), WebMError>
+	{
+		let mut reader = EbmlReader::new(ebmlHeader);
+		while let Some((identifier, data)) = reader.nextElement()?
+		{
+			if identifier == DocTypeId
+			{
+				return if EbmlReader::string(data) == "webm"
+				{
+					Ok(())
+				}
+				else
+				{
+					Err(WebMError::NotWebM)
+				}
+			}
+		}
+		Err(WebMError::NotWebM)
+	}
+	
+	#[inline(always)]
+	fn parseSegment(segment: &[u8]) -> Result<Self, WebMError>
+	{
+		let mut durationInSeconds = None;
+		let mut tracks = None;
+		
+		let mut reader = EbmlReader::new(segment);
+		while let Some((identifier, data)) = reader.nextElement()?
+		{
+			match identifier
+			{
+				InfoId => durationInSeconds = Some(Self::parseInfo(data)?),
+				
+				TracksId => tracks = Some(Self::parseTracks(data)?),
+				
+				// Media data; Information and Tracks always come before the first Cluster, which may also be of unknown size.
+				ClusterId => break,
+				
+				_ => (),
+			}
+			
+			if durationInSeconds.is_some() && tracks.is_some()
+			{
+				break;
+			}
+		}
+		
+		let durationInSeconds = match durationInSeconds
+		{
+			None => return Err(WebMError::MissingSegmentInformation),
+			Some(durationInSeconds) => durationInSeconds,
+		};
+		
+		let (width, height, videoCodecId, audioCodecId) = match tracks
+		{
+			None => return Err(WebMError::MissingTracks),
+			Some(tracks) => tracks,
+		};
+		
+		Ok
+		(
+			Self
+			{
+				durationInSeconds,
+				width,
+				height,
+				videoCodecId,
+				audioCodecId,
+			}
+		)
+	}
+	
+	/// Duration is a float in units of the TimecodeScale (nanoseconds), which may come after it.
+	#[inline(always)]
+	fn parseInfo(info: &[u8]) -> Result<u64, WebMError>
+	{
+		let mut timecodeScaleInNanoseconds = DefaultTimecodeScaleInNanoseconds;
+		let mut duration = None;
+		
+		let mut reader = EbmlReader::new(info);
+		while let Some((identifier, data)) = reader.nextElement()?
+		{
+			match identifier
+			{
+				TimecodeScaleId => timecodeScaleInNanoseconds = EbmlReader::unsignedInteger(data)?,
+				DurationId => duration = Some(EbmlReader::float(data)?),
+				_ => (),
+			}
+		}
+		
+		match duration
+		{
+			None => Err(WebMError::MissingSegmentInformation),
+			Some(duration) => Ok((duration * timecodeScaleInNanoseconds as f64 / 1_000_000_000.0) as u64),
+		}
+	}
+	
+	#[inline(always)]
+	fn parseTracks(tracks: &[u8]) -> Result<(u16, u16, String, Option<String>), WebMError>
+	{
+		let mut video = None;
+		let mut audioCodecId = None;
+		
+		let mut reader = EbmlReader::new(tracks);
+		while let Some((identifier, data)) = reader.nextElement()?
+		{
+			if identifier != TrackEntryId
+			{
+				continue;
+			}
+			
+			let (trackType, codecId, dimensions) = Self::parseTrackEntry(data)?;
+			match trackType
+			{
+				VideoTrackType if video.is_none() => match dimensions
+				{
+					None => return Err(WebMError::MissingVideoPixelDimensions),
+					Some((width, height)) => video = Some((width, height, codecId)),
+				},
+				
+				AudioTrackType if audioCodecId.is_none() => audioCodecId = Some(codecId),
+				
+				_ => return Err(WebMError::OnlyOneVideoTrackAndAtMostOneAudioTrackAreSupported),
+			}
+		}
+		
+		match video
+		{
+			None => Err(WebMError::OnlyOneVideoTrackAndAtMostOneAudioTrackAreSupported),
+			Some((width, height, videoCodecId)) => Ok((width, height, videoCodecId, audioCodecId)),
+		}
+	}
+	
+	#[inline(always)]
+	fn parseTrackEntry(trackEntry: &[u8]) -> Result<(u64, String, Option<(u16, u16)>), WebMError>
+	{
+		let mut trackType = 0;
+		let mut codecId = String::new();
+		let mut dimensions = None;
+		
+		let mut reader = EbmlReader::new(trackEntry);
+		while let Some((identifier, data)) = reader.nextElement()?
+		{
+			match identifier
+			{
+				TrackTypeId => trackType = EbmlReader::unsignedInteger(data)?,
+				CodecIdId => codecId = EbmlReader::string(data),
+				VideoId => dimensions = Self::parseVideo(data)?,
+				_ => (),
+			}
+		}
+		
+		Ok((trackType, codecId, dimensions))
+	}
+	
+	#[inline(always)]
+	fn parseVideo(video: &[u8]) -> Result<Option<(u16, u16)>, WebMError>
+	{
+		let mut width = None;
+		let mut height = None;
+		
+		let mut reader = EbmlReader::new(video);
+		while let Some((identifier, data)) = reader.nextElement()?
+		{
+			match identifier
+			{
+				PixelWidthId => width = Some(EbmlReader::unsignedInteger(data)? as u16),
+				PixelHeightId => height = Some(EbmlReader::unsignedInteger(data)? as u16),
+				_ => (),
+			}
+		}
+		
+		match (width, height)
+		{
+			(Some(width), Some(height)) => Ok(Some((width, height))),
+			_ => Ok(None),
+		}
+	}
+}
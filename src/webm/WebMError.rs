@@ -0,0 +1,64 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+quick_error!
+{
+	#[derive(Debug)]
+	pub enum WebMError
+	{
+		Truncated
+		{
+			description("Truncated EBML element")
+			display("Truncated EBML element")
+		}
+		
+		InvalidVariableLengthInteger
+		{
+			description("Invalid EBML variable length integer (eg identifier or size)")
+			display("Invalid EBML variable length integer")
+		}
+		
+		InvalidUnsignedInteger
+		{
+			description("Invalid EBML unsigned integer (must be at most 8 bytes long)")
+			display("Invalid EBML unsigned integer")
+		}
+		
+		InvalidFloat
+		{
+			description("Invalid EBML float (must be 4 or 8 bytes long)")
+			display("Invalid EBML float")
+		}
+		
+		NotWebM
+		{
+			description("Not a WebM file (the EBML DocType is not 'webm')")
+			display("Not a WebM file")
+		}
+		
+		MissingSegmentInformation
+		{
+			description("Missing Segment Information or Duration")
+			display("Missing Segment Information or Duration")
+		}
+		
+		MissingTracks
+		{
+			description("Missing Tracks")
+			display("Missing Tracks")
+		}
+		
+		OnlyOneVideoTrackAndAtMostOneAudioTrackAreSupported
+		{
+			description("There must be only one video track and at most one audio track; strip it")
+			display("There must be only one video track and at most one audio track; strip it")
+		}
+		
+		MissingVideoPixelDimensions
+		{
+			description("Missing video PixelWidth or PixelHeight")
+			display("Missing video PixelWidth or PixelHeight")
+		}
+	}
+}
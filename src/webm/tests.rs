@@ -0,0 +1,190 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+use super::*;
+
+
+const UnknownSize: [u8; 8] = [0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+
+/// Identifiers are written as they are in the Matroska specification, ie with their length marker bits.
+fn identifier(identifier: u32) -> Vec<u8>
+{
+	let bytes = [(identifier >> 24) as u8, (identifier >> 16) as u8, (identifier >> 8) as u8, identifier as u8];
+	let firstNonZeroByte = bytes.iter().position(|&byte| byte != 0).unwrap();
+	bytes[firstNonZeroByte..].to_vec()
+}
+
+fn elementWithSize(identifierValue: u32, size: &[u8], data: &[u8]) -> Vec<u8>
+{
+	let mut element = identifier(identifierValue);
+	element.extend_from_slice(size);
+	element.extend_from_slice(data);
+	element
+}
+
+fn element(identifier: u32, data: &[u8]) -> Vec<u8>
+{
+	assert!(data.len() < 0x7F, "test elements are small");
+	elementWithSize(identifier, &[0x80 | data.len() as u8], data)
+}
+
+fn unsignedInteger(identifier: u32, value: u64) -> Vec<u8>
+{
+	let bytes = [(value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8, value as u8];
+	element(identifier, &bytes)
+}
+
+fn float(identifier: u32, value: f32) -> Vec<u8>
+{
+	let bits = value.to_bits();
+	element(identifier, &[(bits >> 24) as u8, (bits >> 16) as u8, (bits >> 8) as u8, bits as u8])
+}
+
+fn concatenate(elements: &[Vec<u8>]) -> Vec<u8>
+{
+	elements.iter().fold(Vec::new(), |mut bytes, element|
+	{
+		bytes.extend_from_slice(element);
+		bytes
+	})
+}
+
+fn ebmlHeader(docType: &str) -> Vec<u8>
+{
+	element(EbmlId, &element(DocTypeId, docType.as_bytes()))
+}
+
+fn info(durationInMilliseconds: f32) -> Vec<u8>
+{
+	element(InfoId, &concatenate(&[unsignedInteger(TimecodeScaleId, DefaultTimecodeScaleInNanoseconds), float(DurationId, durationInMilliseconds)]))
+}
+
+fn vp9AndOpusTracks(width: u64, height: u64) -> Vec<u8>
+{
+	let video = element(VideoId, &concatenate(&[unsignedInteger(PixelWidthId, width), unsignedInteger(PixelHeightId, height)]));
+	let videoTrackEntry = element(TrackEntryId, &concatenate(&[unsignedInteger(TrackTypeId, VideoTrackType), element(CodecIdId, b"V_VP9"), video]));
+	let audioTrackEntry = element(TrackEntryId, &concatenate(&[unsignedInteger(TrackTypeId, AudioTrackType), element(CodecIdId, b"A_OPUS\0\0")]));
+	element(TracksId, &concatenate(&[videoTrackEntry, audioTrackEntry]))
+}
+
+fn assertIsVp9AndOpus(webM: WebM)
+{
+	assert_eq!(webM.durationInSeconds, 5);
+	assert_eq!(webM.width, 640);
+	assert_eq!(webM.height, 360);
+	assert_eq!(webM.videoCodecId, "V_VP9");
+	assert_eq!(webM.audioCodecId, Some("A_OPUS".to_owned()));
+}
+
+#[test]
+fn minimalVp9AndOpus()
+{
+	let segment = element(SegmentId, &concatenate(&[info(5_000.0), vp9AndOpusTracks(640, 360), element(ClusterId, &[])]));
+	let bytes = concatenate(&[ebmlHeader("webm"), segment]);
+	
+	assertIsVp9AndOpus(WebM::parse(&bytes).unwrap());
+}
+
+#[test]
+fn unknownSizedSegmentAndClusterExtendToTheEnd()
+{
+	let cluster = elementWithSize(ClusterId, &UnknownSize, &[0xA3, 0x81, 0x00]);
+	let segment = elementWithSize(SegmentId, &UnknownSize, &concatenate(&[info(5_000.0), vp9AndOpusTracks(640, 360), cluster]));
+	let bytes = concatenate(&[ebmlHeader("webm"), segment]);
+	
+	assertIsVp9AndOpus(WebM::parse(&bytes).unwrap());
+	
+	let mut reader = EbmlReader::new(&bytes[ebmlHeader("webm").len()..]);
+	let (identifier, data) = reader.nextElement().unwrap().unwrap();
+	assert_eq!(identifier, SegmentId);
+	assert_eq!(data.len(), bytes.len() - ebmlHeader("webm").len() - 4 - UnknownSize.len());
+	assert!(reader.nextElement().unwrap().is_none());
+}
+
+#[test]
+fn eightByteSize()
+{
+	let data = [0x01, 0x02, 0x03];
+	let bytes = concatenate(&[elementWithSize(PixelWidthId, &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03], &data), element(PixelHeightId, &[0x04])]);
+	
+	let mut reader = EbmlReader::new(&bytes);
+	assert_eq!(reader.nextElement().unwrap(), Some((PixelWidthId, &data[..])));
+	assert_eq!(reader.nextElement().unwrap(), Some((PixelHeightId, &[0x04][..])));
+	assert_eq!(reader.nextElement().unwrap(), None);
+}
+
+#[test]
+fn truncatedElementIsAnError()
+{
+	let mut segment = element(SegmentId, &concatenate(&[info(5_000.0), vp9AndOpusTracks(640, 360)]));
+	let length = segment.len();
+	segment.truncate(length - 1);
+	let bytes = concatenate(&[ebmlHeader("webm"), segment]);
+	
+	match WebM::parse(&bytes)
+	{
+		Err(WebMError::Truncated) => (),
+		result => panic!("expected a truncated element, not {:?}", result),
+	}
+}
+
+#[test]
+fn truncatedSizeIsAnError()
+{
+	let mut reader = EbmlReader::new(&[0xB0, 0x40]);
+	
+	match reader.nextElement()
+	{
+		Err(WebMError::Truncated) => (),
+		result => panic!("expected a truncated size, not {:?}", result),
+	}
+}
+
+#[test]
+fn zeroFirstByteIsNotAVariableLengthInteger()
+{
+	let mut reader = EbmlReader::new(&[0x00, 0x81, 0x00]);
+	
+	match reader.nextElement()
+	{
+		Err(WebMError::InvalidVariableLengthInteger) => (),
+		result => panic!("expected an invalid variable length integer, not {:?}", result),
+	}
+}
+
+#[test]
+fn durationIsScaledByATimecodeScaleThatComesAfterIt()
+{
+	let info = element(InfoId, &concatenate(&[float(DurationId, 5.0), unsignedInteger(TimecodeScaleId, 1_000_000_000)]));
+	let segment = element(SegmentId, &concatenate(&[info, vp9AndOpusTracks(640, 360)]));
+	let bytes = concatenate(&[ebmlHeader("webm"), segment]);
+	
+	assertIsVp9AndOpus(WebM::parse(&bytes).unwrap());
+}
+
+#[test]
+fn matroskaIsNotWebM()
+{
+	let segment = element(SegmentId, &concatenate(&[info(5_000.0), vp9AndOpusTracks(640, 360)]));
+	let bytes = concatenate(&[ebmlHeader("matroska"), segment]);
+	
+	match WebM::parse(&bytes)
+	{
+		Err(WebMError::NotWebM) => (),
+		result => panic!("expected not WebM, not {:?}", result),
+	}
+}
+
+#[test]
+fn numbers()
+{
+	assert_eq!(EbmlReader::unsignedInteger(&[]).unwrap(), 0);
+	assert_eq!(EbmlReader::unsignedInteger(&[0x01, 0x00]).unwrap(), 256);
+	assert!(EbmlReader::unsignedInteger(&[0; 9]).is_err());
+	
+	assert_eq!(EbmlReader::float(&[0x40, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]).unwrap(), 5.0);
+	assert!(EbmlReader::float(&[0x00, 0x00]).is_err());
+	
+	assert_eq!(EbmlReader::string(b"webm\0\0"), "webm");
+}
@@ -0,0 +1,229 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Each frame is `(image, left offset, top offset, duration in milliseconds, dispose to background)`, with the frame blended over the canvas as a GIF is.
+/// A `loopCount` of zero loops forever.
+/// Quality is ignored if lossless is true.
+///
+/// Frames are composited on to a canvas and only the rectangle that changed since the previous frame is encoded, so WebP's requirement that frame offsets be even never moves a frame; the rectangle is widened using the canvas's own pixels instead.
+/// Frames that change nothing extend the duration of the previous frame.
+pub(crate) fn encodeAnimatedWebP(canvasWidth: u32, canvasHeight: u32, frames: &[(::image::DynamicImage, u32, u32, u32, bool)], loopCount: u16, quality: u8, lossless: bool) -> Option<Vec<u8>>
+{
+	const RiffHeaderSize: usize = 12;
+	
+	const AlphaFlag: u8 = 0x10;
+	const AnimationFlag: u8 = 0x02;
+	const DoNotBlendFlag: u8 = 0x02;
+	
+	const MaximumDuration: u32 = 0xFF_FFFF;
+	
+	fn pushU24(bytes: &mut Vec<u8>, value: u32)
+	{
+		bytes.push(value as u8);
+		bytes.push((value >> 8) as u8);
+		bytes.push((value >> 16) as u8);
+	}
+	
+	fn pushU32(bytes: &mut Vec<u8>, value: u32)
+	{
+		pushU24(bytes, value);
+		bytes.push((value >> 24) as u8);
+	}
+	
+	fn pushChunk(bytes: &mut Vec<u8>, fourCC: &[u8; 4], payload: &[u8])
+	{
+		bytes.extend_from_slice(fourCC);
+		pushU32(bytes, payload.len() as u32);
+		bytes.extend_from_slice(payload);
+		if payload.len() % 2 == 1
+		{
+			bytes.push(0);
+		}
+	}
+	
+	/// Source-over compositing of non-premultiplied RGBA.
+	fn blend(destination: &mut ::image::Rgba<u8>, source: &::image::Rgba<u8>)
+	{
+		let sourceAlpha = source[3] as u32;
+		match sourceAlpha
+		{
+			0 => (),
+			0xFF => *destination = *source,
+			_ =>
+			{
+				let destinationAlpha = destination[3] as u32 * (0xFF - sourceAlpha) / 0xFF;
+				let alpha = sourceAlpha + destinationAlpha;
+				for channel in 0 .. 3
+				{
+					destination[channel] = ((source[channel] as u32 * sourceAlpha + destination[channel] as u32 * destinationAlpha) / alpha) as u8;
+				}
+				destination[3] = alpha as u8;
+			}
+		}
+	}
+	
+	struct AnimationFrame
+	{
+		left: u32,
+		top: u32,
+		image: ::image::DynamicImage,
+		durationInMilliseconds: u32,
+	}
+	
+	if canvasWidth == 0 || canvasHeight == 0 || canvasWidth > MaximumWebPDimension || canvasHeight > MaximumWebPDimension || frames.is_empty()
+	{
+		return None;
+	}
+	
+	let mut canvas = ::image::RgbaImage::new(canvasWidth, canvasHeight);
+	let mut displayed = canvas.clone();
+	
+	let mut hasAlpha = false;
+	let mut animationFrames: Vec<AnimationFrame> = Vec::with_capacity(frames.len());
+	for &(ref image, leftOffset, topOffset, durationInMilliseconds, disposeToBackground) in frames.iter()
+	{
+		let durationInMilliseconds = min(durationInMilliseconds, MaximumDuration);
+		
+		let frame = image.to_rgba();
+		let right = min(leftOffset.saturating_add(frame.width()), canvasWidth);
+		let bottom = min(topOffset.saturating_add(frame.height()), canvasHeight);
+		for y in topOffset .. bottom
+		{
+			for x in leftOffset .. right
+			{
+				blend(canvas.get_pixel_mut(x, y), frame.get_pixel(x - leftOffset, y - topOffset));
+			}
+		}
+		
+		// The first frame is always the whole canvas; thereafter, only the rectangle that differs from what is displayed.
+		let changed = if animationFrames.is_empty()
+		{
+			Some((0, 0, canvasWidth, canvasHeight))
+		}
+		else
+		{
+			let mut changed: Option<(u32, u32, u32, u32)> = None;
+			for (x, y, pixel) in canvas.enumerate_pixels()
+			{
+				if pixel != displayed.get_pixel(x, y)
+				{
+					changed = Some(match changed
+					{
+						None => (x, y, x + 1, y + 1),
+						Some((left, top, right, bottom)) => (min(left, x), min(top, y), max(right, x + 1), max(bottom, y + 1)),
+					});
+				}
+			}
+			changed
+		};
+		
+		match changed
+		{
+			None =>
+			{
+				let merged =
+				{
+					let previous = animationFrames.last_mut().unwrap();
+					let merged = previous.durationInMilliseconds + durationInMilliseconds <= MaximumDuration;
+					if merged
+					{
+						previous.durationInMilliseconds += durationInMilliseconds;
+					}
+					merged
+				};
+				if !merged
+				{
+					let image = ::image::DynamicImage::ImageRgba8(canvas.sub_image(0, 0, 1, 1).to_image());
+					animationFrames.push(AnimationFrame { left: 0, top: 0, image, durationInMilliseconds });
+				}
+			}
+			
+			Some((left, top, right, bottom)) =>
+			{
+				let left = left & !1;
+				let top = top & !1;
+				let width = right - left;
+				let height = bottom - top;
+				
+				let subImage = canvas.sub_image(left, top, width, height).to_image();
+				if !hasAlpha
+				{
+					hasAlpha = subImage.pixels().any(|pixel| pixel[3] != 0xFF);
+				}
+				animationFrames.push(AnimationFrame { left, top, image: ::image::DynamicImage::ImageRgba8(subImage), durationInMilliseconds });
+				
+				displayed = canvas.clone();
+			}
+		}
+		
+		if disposeToBackground
+		{
+			for y in topOffset .. bottom
+			{
+				for x in leftOffset .. right
+				{
+					canvas.put_pixel(x, y, ::image::Rgba([0, 0, 0, 0]));
+				}
+			}
+		}
+	}
+	
+	let mut animationFrameChunks = Vec::new();
+	for animationFrame in animationFrames.iter()
+	{
+		let (width, height) = animationFrame.image.dimensions();
+		
+		let stillWebP = encodeWebP(&animationFrame.image, quality, lossless)?;
+		if stillWebP.len() < RiffHeaderSize
+		{
+			return None;
+		}
+		
+		let mut animationFrameChunk = Vec::with_capacity(16 + stillWebP.len());
+		pushU24(&mut animationFrameChunk, animationFrame.left / 2);
+		pushU24(&mut animationFrameChunk, animationFrame.top / 2);
+		pushU24(&mut animationFrameChunk, width - 1);
+		pushU24(&mut animationFrameChunk, height - 1);
+		pushU24(&mut animationFrameChunk, animationFrame.durationInMilliseconds);
+		animationFrameChunk.push(DoNotBlendFlag);
+		
+		// A still WebP's chunks, less any VP8X chunk, are the frame data.
+		let mut chunks = &stillWebP[RiffHeaderSize..];
+		while chunks.len() >= 8
+		{
+			let payloadSize = (chunks[4] as usize) | (chunks[5] as usize) << 8 | (chunks[6] as usize) << 16 | (chunks[7] as usize) << 24;
+			let chunkSize = min(8 + payloadSize + (payloadSize % 2), chunks.len());
+			if &chunks[0 .. 4] != b"VP8X"
+			{
+				animationFrameChunk.extend_from_slice(&chunks[0 .. chunkSize]);
+			}
+			chunks = &chunks[chunkSize..];
+		}
+		
+		pushChunk(&mut animationFrameChunks, b"ANMF", &animationFrameChunk);
+	}
+	
+	let mut chunks = Vec::with_capacity(48 + animationFrameChunks.len());
+	
+	let mut extendedHeader = Vec::with_capacity(10);
+	extendedHeader.push(if hasAlpha { AlphaFlag | AnimationFlag } else { AnimationFlag });
+	pushU24(&mut extendedHeader, 0);
+	pushU24(&mut extendedHeader, canvasWidth - 1);
+	pushU24(&mut extendedHeader, canvasHeight - 1);
+	pushChunk(&mut chunks, b"VP8X", &extendedHeader);
+	
+	// Background color is transparent black, in BGRA order, followed by the loop count.
+	let animation = [0, 0, 0, 0, loopCount as u8, (loopCount >> 8) as u8];
+	pushChunk(&mut chunks, b"ANIM", &animation);
+	
+	chunks.extend_from_slice(&animationFrameChunks);
+	
+	let mut webPBytes = Vec::with_capacity(RiffHeaderSize + chunks.len());
+	webPBytes.extend_from_slice(b"RIFF");
+	pushU32(&mut webPBytes, (4 + chunks.len()) as u32);
+	webPBytes.extend_from_slice(b"WEBP");
+	webPBytes.extend_from_slice(&chunks);
+	
+	Some(webPBytes)
+}
@@ -0,0 +1,38 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+/// Quality is ignored if lossless is true.
+pub(crate) fn encodeWebP(image: &::image::DynamicImage, quality: u8, lossless: bool) -> Option<Vec<u8>>
+{
+	debug_assert!(quality <= 100, "quality must be less than or equal to 100, not '{}'", quality);
+	
+	let (width, height) = image.dimensions();
+	if width > MaximumWebPDimension || height > MaximumWebPDimension
+	{
+		return None;
+	}
+	
+	let rgba = image.to_rgba();
+	let stride = (width * 4) as c_int;
+	
+	let mut webPPointer: *mut u8 = null_mut();
+	let webPLength = if lossless
+	{
+		unsafe { ::libwebp_sys::WebPEncodeLosslessRGBA(rgba.as_ptr(), width as c_int, height as c_int, stride, &mut webPPointer) }
+	}
+	else
+	{
+		unsafe { ::libwebp_sys::WebPEncodeRGBA(rgba.as_ptr(), width as c_int, height as c_int, stride, quality as f32, &mut webPPointer) }
+	};
+	
+	if webPLength == 0 || webPPointer.is_null()
+	{
+		return None;
+	}
+	
+	let webPBytes = unsafe { from_raw_parts(webPPointer, webPLength) }.to_vec();
+	unsafe { ::libwebp_sys::WebPFree(webPPointer as *mut c_void) };
+	
+	Some(webPBytes)
+}
@@ -0,0 +1,24 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+use ::image::GenericImage;
+use ::libc::c_int;
+use ::libc::c_void;
+use ::std::cmp::max;
+use ::std::cmp::min;
+use ::std::ptr::null_mut;
+use ::std::slice::from_raw_parts;
+
+
+include!("encodeAnimatedWebP.rs");
+include!("encodeWebP.rs");
+
+
+#[cfg(test)] mod tests;
+
+
+pub const DefaultWebPQuality: u8 = 80;
+
+/// WebP images can not exceed 16,383 x 16,383 pixels.
+pub const MaximumWebPDimension: u32 = 16_383;
@@ -0,0 +1,191 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+use super::*;
+use ::image::DynamicImage;
+use ::image::Rgba;
+use ::image::RgbaImage;
+
+
+const Opaque: Rgba<u8> = Rgba { data: [0x00, 0x00, 0xFF, 0xFF] };
+
+const Red: Rgba<u8> = Rgba { data: [0xFF, 0x00, 0x00, 0xFF] };
+
+const Transparent: Rgba<u8> = Rgba { data: [0x00, 0x00, 0x00, 0x00] };
+
+#[derive(Debug, PartialEq)]
+struct AnimationFrame
+{
+	left: u32,
+	top: u32,
+	width: u32,
+	height: u32,
+	durationInMilliseconds: u32,
+	flags: u8,
+	frameChunks: Vec<([u8; 4], Vec<u8>)>,
+}
+
+fn u24(bytes: &[u8]) -> u32
+{
+	(bytes[0] as u32) | (bytes[1] as u32) << 8 | (bytes[2] as u32) << 16
+}
+
+fn u32(bytes: &[u8]) -> u32
+{
+	u24(bytes) | (bytes[3] as u32) << 24
+}
+
+fn chunks(mut bytes: &[u8]) -> Vec<([u8; 4], Vec<u8>)>
+{
+	let mut chunks = Vec::new();
+	while !bytes.is_empty()
+	{
+		assert!(bytes.len() >= 8, "truncated chunk header");
+		let mut fourCC = [0; 4];
+		fourCC.copy_from_slice(&bytes[0 .. 4]);
+		let payloadSize = u32(&bytes[4 .. 8]) as usize;
+		assert!(bytes.len() >= 8 + payloadSize, "truncated chunk payload");
+		chunks.push((fourCC, bytes[8 .. 8 + payloadSize].to_vec()));
+		let paddedSize = 8 + payloadSize + (payloadSize % 2);
+		bytes = &bytes[min(paddedSize, bytes.len())..];
+	}
+	chunks
+}
+
+/// Returns the VP8X flags, canvas width and height, loop count and frames.
+fn parse(webP: &[u8]) -> (u8, u32, u32, u16, Vec<AnimationFrame>)
+{
+	assert_eq!(&webP[0 .. 4], b"RIFF");
+	assert_eq!(u32(&webP[4 .. 8]) as usize, webP.len() - 8);
+	assert_eq!(&webP[8 .. 12], b"WEBP");
+	
+	let riffChunks = chunks(&webP[12..]);
+	assert!(riffChunks.len() >= 3);
+	
+	let (ref extendedHeaderFourCC, ref extendedHeader) = riffChunks[0];
+	assert_eq!(extendedHeaderFourCC, b"VP8X");
+	assert_eq!(extendedHeader.len(), 10);
+	
+	let (ref animationFourCC, ref animation) = riffChunks[1];
+	assert_eq!(animationFourCC, b"ANIM");
+	assert_eq!(animation.len(), 6);
+	
+	let frames = riffChunks[2..].iter().map(|&(ref fourCC, ref payload)|
+	{
+		assert_eq!(fourCC, b"ANMF");
+		AnimationFrame
+		{
+			left: u24(&payload[0 .. 3]) * 2,
+			top: u24(&payload[3 .. 6]) * 2,
+			width: u24(&payload[6 .. 9]) + 1,
+			height: u24(&payload[9 .. 12]) + 1,
+			durationInMilliseconds: u24(&payload[12 .. 15]),
+			flags: payload[15],
+			frameChunks: chunks(&payload[16..]),
+		}
+	}).collect();
+	
+	(extendedHeader[0], u24(&extendedHeader[4 .. 7]) + 1, u24(&extendedHeader[7 .. 10]) + 1, (animation[4] as u16) | (animation[5] as u16) << 8, frames)
+}
+
+fn image(width: u32, height: u32, pixel: Rgba<u8>) -> DynamicImage
+{
+	DynamicImage::ImageRgba8(RgbaImage::from_pixel(width, height, pixel))
+}
+
+fn losslessFrameDimensions(frame: &AnimationFrame) -> (u32, u32)
+{
+	let &(ref fourCC, ref bitstream) = frame.frameChunks.last().unwrap();
+	assert_eq!(fourCC, b"VP8L");
+	
+	let mut width: c_int = 0;
+	let mut height: c_int = 0;
+	let valid = unsafe { ::libwebp_sys::WebPGetInfo(bitstream.as_ptr(), bitstream.len(), &mut width, &mut height) };
+	assert_ne!(valid, 0, "frame bitstream is not valid");
+	(width as u32, height as u32)
+}
+
+#[test]
+fn singleOpaqueFrameIsTheWholeCanvas()
+{
+	let webP = encodeAnimatedWebP(6, 4, &[(image(6, 4, Opaque), 0, 0, 100, false)], 3, DefaultWebPQuality, true).unwrap();
+	
+	let (flags, canvasWidth, canvasHeight, loopCount, frames) = parse(&webP);
+	assert_eq!(flags, 0x02);
+	assert_eq!((canvasWidth, canvasHeight), (6, 4));
+	assert_eq!(loopCount, 3);
+	assert_eq!(frames.len(), 1);
+	
+	let frame = &frames[0];
+	assert_eq!((frame.left, frame.top, frame.width, frame.height), (0, 0, 6, 4));
+	assert_eq!(frame.durationInMilliseconds, 100);
+	assert_eq!(frame.flags, 0x02);
+	assert_eq!(losslessFrameDimensions(frame), (6, 4));
+}
+
+#[test]
+fn oddOffsetIsWidenedRatherThanMoved()
+{
+	let frames =
+	[
+		(image(8, 8, Opaque), 0, 0, 40, false),
+		(image(2, 2, Red), 3, 5, 40, false),
+	];
+	let webP = encodeAnimatedWebP(8, 8, &frames, 0, DefaultWebPQuality, true).unwrap();
+	
+	let (_, _, _, loopCount, frames) = parse(&webP);
+	assert_eq!(loopCount, 0);
+	assert_eq!(frames.len(), 2);
+	
+	let frame = &frames[1];
+	assert_eq!((frame.left, frame.top, frame.width, frame.height), (2, 4, 3, 3));
+	assert_eq!(losslessFrameDimensions(frame), (3, 3));
+}
+
+#[test]
+fn unchangedFrameExtendsThePreviousFrame()
+{
+	let frames =
+	[
+		(image(4, 4, Opaque), 0, 0, 40, false),
+		(image(4, 4, Opaque), 0, 0, 60, false),
+	];
+	let webP = encodeAnimatedWebP(4, 4, &frames, 0, DefaultWebPQuality, true).unwrap();
+	
+	let (_, _, _, _, frames) = parse(&webP);
+	assert_eq!(frames.len(), 1);
+	assert_eq!(frames[0].durationInMilliseconds, 100);
+}
+
+#[test]
+fn transparentPixelsInLaterFramesSetTheAlphaFlag()
+{
+	let frames =
+	[
+		(image(4, 4, Opaque), 0, 0, 40, true),
+		(image(2, 2, Transparent), 0, 0, 40, false),
+	];
+	let webP = encodeAnimatedWebP(4, 4, &frames, 0, DefaultWebPQuality, true).unwrap();
+	
+	let (flags, _, _, _, frames) = parse(&webP);
+	assert_eq!(flags, 0x12);
+	assert_eq!(frames.len(), 2);
+	assert_eq!((frames[1].left, frames[1].top, frames[1].width, frames[1].height), (0, 0, 4, 4));
+}
+
+#[test]
+fn lossyFramesHaveAValidBitstream()
+{
+	let webP = encodeAnimatedWebP(16, 16, &[(image(16, 16, Opaque), 0, 0, 40, false)], 0, DefaultWebPQuality, false).unwrap();
+	
+	let (_, _, _, _, frames) = parse(&webP);
+	let &(ref fourCC, _) = frames[0].frameChunks.last().unwrap();
+	assert_eq!(fourCC, b"VP8 ");
+}
+
+#[test]
+fn canvasLargerThanTheMaximumIsRejected()
+{
+	assert!(encodeAnimatedWebP(MaximumWebPDimension + 1, 1, &[(image(1, 1, Opaque), 0, 0, 40, false)], 0, DefaultWebPQuality, true).is_none());
+}
@@ -6,9 +6,10 @@
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub(crate) enum RegularAndPjaxStaticResponse
 {
+	/// The response is shared when it is also an alternative of another response.
 	Regular
 	{
-		response: StaticResponse,
+		response: Arc<StaticResponse>,
 		entityTag: String,
 	},
 	
@@ -23,6 +24,14 @@ pub(crate) enum RegularAndPjaxStaticResponse
 	{
 		response: StaticResponse,
 		entityTag: String,
+	},
+	
+	/// Alternatives are (mime type, entity tag, response) in order of preference.
+	WithAlternativeImageFormats
+	{
+		response: StaticResponse,
+		entityTag: String,
+		alternatives: Vec<(String, String, Arc<StaticResponse>)>,
 	}
 }
 
@@ -30,6 +39,12 @@ impl RegularAndPjaxStaticResponse
 {
 	#[inline(always)]
 	pub(crate) fn regular(response: StaticResponse) -> Self
+	{
+		Self::shared(Arc::new(response))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn shared(response: Arc<StaticResponse>) -> Self
 	{
 		RegularAndPjaxStaticResponse::Regular
 		{
@@ -56,12 +71,28 @@ impl RegularAndPjaxStaticResponse
 		{
 			RegularAndPjaxStaticResponse::Regular
 			{
-				response,
+				response: Arc::new(response),
 				entityTag,
 			}
 		}
 	}
 	
+	#[inline(always)]
+	pub(crate) fn withAlternativeImageFormats(response: StaticResponse, alternatives: Vec<(String, Arc<StaticResponse>)>) -> Self
+	{
+		if alternatives.is_empty()
+		{
+			return Self::regular(response);
+		}
+		
+		RegularAndPjaxStaticResponse::WithAlternativeImageFormats
+		{
+			entityTag: response.entityTag(),
+			response,
+			alternatives: alternatives.into_iter().map(|(mimeType, alternative)| (mimeType, alternative.entityTag(), alternative)).collect(),
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn unadorned(response: StaticResponse) -> Self
 	{
@@ -82,6 +113,7 @@ impl RegularAndPjaxStaticResponse
 			Regular { ref entityTag, .. } => entityTag,
 			WithPjax { ref entityTag, .. } => entityTag,
 			Unadorned { ref entityTag, .. } => entityTag,
+			WithAlternativeImageFormats { ref entityTag, .. } => entityTag,
 		}
 	}
 	
@@ -154,6 +186,7 @@ impl RegularAndPjaxStaticResponse
 			Regular { ref response, .. } => response,
 			WithPjax { ref response, .. } => response,
 			Unadorned { ref response, .. } => response,
+			WithAlternativeImageFormats { ref response, .. } => response,
 		}
 	}
 
@@ -164,7 +197,7 @@ impl RegularAndPjaxStaticResponse
 	}
 	
 	#[inline(always)]
-	fn staticResponse(&self, isHead: bool, isPjax: bool, preferredEncoding: PreferredCompression, lastModified: HttpDate, ifMatch: Option<&IfMatch>, ifUnmodifiedSince: Option<&IfUnmodifiedSince>, ifNoneMatch: Option<&IfNoneMatch>, ifModifiedSince: Option<&IfModifiedSince>, ifRange: Option<&IfRange>, range: Option<&Range>) -> Response
+	fn staticResponse(&self, isHead: bool, isPjax: bool, preferredEncoding: PreferredCompression, accept: Option<&Accept>, lastModified: HttpDate, ifMatch: Option<&IfMatch>, ifUnmodifiedSince: Option<&IfUnmodifiedSince>, ifNoneMatch: Option<&IfNoneMatch>, ifModifiedSince: Option<&IfModifiedSince>, ifRange: Option<&IfRange>, range: Option<&Range>) -> Response
 	{
 		use self::RegularAndPjaxStaticResponse::*;
 		
@@ -182,6 +215,111 @@ impl RegularAndPjaxStaticResponse
 			},
 			
 			Unadorned { ref response, .. } => response.rawResponse(isHead),
+			
+			WithAlternativeImageFormats { ref response, ref entityTag, ref alternatives } => match Self::preferredAlternative(accept, &response.contentType.0, alternatives)
+			{
+				None => response.respondAssumingResourceIs200Ok(isHead, preferredEncoding, entityTag, lastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range),
+				Some(&(_, ref alternativeEntityTag, ref alternative)) => alternative.respondAssumingResourceIs200Ok(isHead, preferredEncoding, alternativeEntityTag, lastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range),
+			},
+		}
+	}
+	
+	/// Only mime types listed explicitly (eg `image/webp` but not `image/*` or `*/*`) select an alternative, and not if weighted `q=0`.
+	/// The alternative with the greatest quality weight is chosen, in order of preference if tied, unless the original's quality weight is greater still.
+	#[inline(always)]
+	fn preferredAlternative<'a>(accept: Option<&Accept>, originalMimeType: &Mime, alternatives: &'a [(String, String, Arc<StaticResponse>)]) -> Option<&'a (String, String, Arc<StaticResponse>)>
+	{
+		let accept = match accept
+		{
+			None => return None,
+			Some(accept) => accept,
+		};
+		
+		let mut preferred: Option<(&'a (String, String, Arc<StaticResponse>), Quality)> = None;
+		for alternative in alternatives.iter()
+		{
+			if let Some(quality) = Self::explicitQuality(accept, &alternative.0)
+			{
+				let isBetter = match preferred
+				{
+					None => quality > q(0),
+					Some((_, preferredQuality)) => quality > preferredQuality,
+				};
+				
+				if isBetter
+				{
+					preferred = Some((alternative, quality));
+				}
+			}
+		}
+		
+		match preferred
+		{
+			None => None,
+			Some((alternative, quality)) => if quality >= Self::originalQuality(accept, originalMimeType)
+			{
+				Some(alternative)
+			}
+			else
+			{
+				None
+			},
+		}
+	}
+	
+	#[inline(always)]
+	fn explicitQuality(accept: &Accept, mimeType: &str) -> Option<Quality>
+	{
+		accept.0.iter().find(|qualityItem| qualityItem.item.subtype() != STAR && format!("{}/{}", qualityItem.item.type_(), qualityItem.item.subtype()) == mimeType).map(|qualityItem| qualityItem.quality)
+	}
+	
+	/// The most specific matching media range applies (RFC 7231 Section 5.3.2); an original that does not match at all is not acceptable.
+	#[inline(always)]
+	fn originalQuality(accept: &Accept, originalMimeType: &Mime) -> Quality
+	{
+		let mut mostSpecific: Option<(u8, Quality)> = None;
+		
+		for qualityItem in accept.0.iter()
+		{
+			let mimeType = &qualityItem.item;
+			
+			let specificity = if mimeType.type_() == STAR
+			{
+				0
+			}
+			else if mimeType.type_() != originalMimeType.type_()
+			{
+				continue
+			}
+			else if mimeType.subtype() == STAR
+			{
+				1
+			}
+			else if mimeType.subtype() == originalMimeType.subtype()
+			{
+				2
+			}
+			else
+			{
+				continue
+			};
+			
+			let isMoreSpecific = match mostSpecific
+			{
+				None => true,
+				Some((mostSpecificSpecificity, _)) => specificity > mostSpecificSpecificity,
+			};
+			
+			if isMoreSpecific
+			{
+				mostSpecific = Some((specificity, qualityItem.quality));
+			}
+		}
+		
+		match mostSpecific
+		{
+			None => q(0),
+			Some((_, quality)) => quality,
 		}
 	}
 }
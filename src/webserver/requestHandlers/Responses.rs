@@ -228,7 +228,7 @@ impl Responses
 					let isPjax = requestHeaders.get_raw("X-PJAX").is_some();
 					let preferredEncoding = PreferredCompression::preferredEncoding(requestHeaders.get::<AcceptEncoding>());
 					
-					let response = staticResponseVersions.staticResponse(isHead, isPjax, preferredEncoding, query, requestHeaders.get::<IfMatch>(), requestHeaders.get::<IfUnmodifiedSince>(), requestHeaders.get::<IfNoneMatch>(), requestHeaders.get::<IfModifiedSince>(), requestHeaders.get::<IfRange>(), requestHeaders.get::<Range>());
+					let response = staticResponseVersions.staticResponse(isHead, isPjax, preferredEncoding, requestHeaders.get::<Accept>(), query, requestHeaders.get::<IfMatch>(), requestHeaders.get::<IfUnmodifiedSince>(), requestHeaders.get::<IfNoneMatch>(), requestHeaders.get::<IfModifiedSince>(), requestHeaders.get::<IfRange>(), requestHeaders.get::<Range>());
 					
 					if let Some(ourOrigin) = ourOrigin
 					{
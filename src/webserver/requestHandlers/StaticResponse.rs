@@ -29,18 +29,24 @@ impl StaticResponse
 	
 	pub(crate) fn entityTag(&self) -> String
 	{
-		let mut responseHeaders = Headers::with_capacity(1 + self.headers.len());
+		Self::entityTagOf(&self.contentType, &self.headers, &self.uncompressedBody)
+	}
+	
+	/// The entity tag a response with this content type, headers and uncompressed body would have, without having to construct it.
+	pub(crate) fn entityTagOf(contentType: &ContentType, headers: &ResponseHeaders, uncompressedBody: &ResponseBody) -> String
+	{
+		let mut responseHeaders = Headers::with_capacity(1 + headers.len());
 		
-		responseHeaders.set(self.contentType.clone());
+		responseHeaders.set(contentType.clone());
 		
-		for &(ref name, ref value) in self.headers.iter()
+		for &(ref name, ref value) in headers.iter()
 		{
 			responseHeaders.set_raw(name.to_owned(), value.to_owned())
 		}
 		
 		let mut context = Context::new(&SHA256);
 		context.update(format!("{}", responseHeaders).as_bytes());
-		context.update(&self.uncompressedBody);
+		context.update(uncompressedBody);
 		let digest = context.finish();
 		let bytes = digest.as_ref();
 		
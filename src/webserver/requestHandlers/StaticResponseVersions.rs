@@ -78,7 +78,7 @@ impl StaticResponseVersions
 	}
 	
 	#[inline(always)]
-	fn staticResponse<'a>(&self, isHead: bool, isPjax: bool, preferredEncoding: PreferredCompression, query: Option<Cow<'a, str>>, ifMatch: Option<&IfMatch>, ifUnmodifiedSince: Option<&IfUnmodifiedSince>, ifNoneMatch: Option<&IfNoneMatch>, ifModifiedSince: Option<&IfModifiedSince>, ifRange: Option<&IfRange>, range: Option<&Range>) -> Response
+	fn staticResponse<'a>(&self, isHead: bool, isPjax: bool, preferredEncoding: PreferredCompression, accept: Option<&Accept>, query: Option<Cow<'a, str>>, ifMatch: Option<&IfMatch>, ifUnmodifiedSince: Option<&IfUnmodifiedSince>, ifNoneMatch: Option<&IfNoneMatch>, ifModifiedSince: Option<&IfModifiedSince>, ifRange: Option<&IfRange>, range: Option<&Range>) -> Response
 	{
 		use self::StaticResponseVersions::*;
 		
@@ -88,7 +88,7 @@ impl StaticResponseVersions
 			{
 				if query.is_none()
 				{
-					currentResponse.staticResponse(isHead, isPjax, preferredEncoding, currentLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
+					currentResponse.staticResponse(isHead, isPjax, preferredEncoding, accept, currentLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
 				}
 				else
 				{
@@ -106,7 +106,7 @@ impl StaticResponseVersions
 				let unwrapped = query.unwrap();
 				if unwrapped.as_ref() == currentVersionAsQuery.as_str()
 				{
-					currentResponse.staticResponse(isHead, isPjax, preferredEncoding, currentLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
+					currentResponse.staticResponse(isHead, isPjax, preferredEncoding, accept, currentLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
 				}
 				else
 				{
@@ -119,11 +119,11 @@ impl StaticResponseVersions
 				let unwrapped = query.unwrap();
 				if unwrapped.as_ref() == currentVersionAsQuery.as_str()
 				{
-					currentResponse.staticResponse(isHead, isPjax, preferredEncoding, currentLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
+					currentResponse.staticResponse(isHead, isPjax, preferredEncoding, accept, currentLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
 				}
 				else if unwrapped.as_ref() == previousVersionAsQuery.as_str()
 				{
-					previousResponse.staticResponse(isHead, isPjax, preferredEncoding, previousLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
+					previousResponse.staticResponse(isHead, isPjax, preferredEncoding, accept, previousLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
 				}
 				else
 				{
@@ -137,7 +137,7 @@ impl StaticResponseVersions
 				{
 					if query.is_none()
 					{
-						previousResponse.staticResponse(isHead, isPjax, preferredEncoding, previousLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
+						previousResponse.staticResponse(isHead, isPjax, preferredEncoding, accept, previousLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
 					}
 					else
 					{
@@ -154,7 +154,7 @@ impl StaticResponseVersions
 					let unwrapped = query.unwrap();
 					if unwrapped.as_ref() == previousVersionAsQuery.as_ref().unwrap()
 					{
-						previousResponse.staticResponse(isHead, isPjax, preferredEncoding, previousLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
+						previousResponse.staticResponse(isHead, isPjax, preferredEncoding, accept, previousLastModified, ifMatch, ifUnmodifiedSince, ifNoneMatch, ifModifiedSince, ifRange, range)
 					}
 					else
 					{
@@ -9,6 +9,7 @@ use ::futures::future::Future;
 use ::futures::future::FutureResult;
 use ::hyper::Body;
 use ::hyper::Method;
+use ::hyper::header::Accept;
 use ::hyper::header::AcceptEncoding;
 use ::hyper::header::AccessControlAllowOrigin;
 use ::hyper::header::AccessControlRequestHeaders;
@@ -26,6 +27,8 @@ use ::hyper::header::IfNoneMatch;
 use ::hyper::header::IfRange;
 use ::hyper::header::IfUnmodifiedSince;
 use ::hyper::header::Origin;
+use ::hyper::header::q;
+use ::hyper::header::Quality;
 use ::hyper::header::Range;
 use ::hyper::header::RangeUnit;
 use ::hyper::mime::*;
@@ -0,0 +1,18 @@
+// This file is part of cordial. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of cordial. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/cordial/master/COPYRIGHT.
+
+
+pub(crate) fn decodeWoff(woffData: &[u8]) -> Result<Malloc<[u8]>, WoffError>
+{
+	debug_assert!((woffData.len() as u64) < (u32::max_value() as u64), "woffData can not exceed 4Gb");
+	
+	let mut sfntLength = unsafe { uninitialized() };
+	let mut status = unsafe { uninitialized() };
+	let sfntPointer = unsafe { ::sfnt2woff_zopfli_sys::woffDecode(woffData.as_ptr(), woffData.len() as u32, &mut sfntLength, &mut status) };
+	if sfntPointer.is_null()
+	{
+		return Err(WoffError::process(status));
+	}
+	
+	Ok(unsafe { Malloc::from_array(sfntPointer as *mut _, sfntLength as usize) })
+}
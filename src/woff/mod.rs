@@ -8,6 +8,7 @@ use ::libc::c_void;
 use ::std::mem::uninitialized;
 
 
+include!("decodeWoff.rs");
 include!("encodeWoff.rs");
 include!("WoffError.rs");
 